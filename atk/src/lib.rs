@@ -20,6 +20,7 @@ mod auto;
 pub use crate::auto::*;
 
 pub mod prelude;
+pub mod subclass;
 
 pub use crate::attribute::Attribute;
 pub use crate::attribute_set::AttributeSet;
@@ -28,5 +29,7 @@ pub use crate::text_rectangle::TextRectangle;
 mod attribute;
 mod attribute_set;
 mod editable_text;
+mod relation_set;
+mod state_set;
 mod table;
 mod text_rectangle;
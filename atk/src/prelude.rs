@@ -6,6 +6,8 @@
 
 pub use crate::auto::traits::*;
 pub use crate::editable_text::EditableTextExtManual;
+pub use crate::relation_set::RelationSetExtManual;
+pub use crate::state_set::StateSetExtManual;
 pub use crate::table::TableExtManual;
 #[doc(hidden)]
 pub use glib::prelude::*;
@@ -0,0 +1,20 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::{Relation, RelationSet, RelationSetExt};
+use glib::object::IsA;
+
+pub trait RelationSetExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Returns all the relations of the set.
+    fn relations(&self) -> Vec<Relation>;
+}
+
+impl<O: IsA<RelationSet>> RelationSetExtManual for O {
+    fn relations(&self) -> Vec<Relation> {
+        (0..self.get_n_relations())
+            .filter_map(|i| self.get_relation(i))
+            .collect()
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::{StateSet, StateSetExt, StateType};
+use glib::object::IsA;
+use glib::translate::*;
+
+impl StateSet {
+    // rustdoc-stripper-ignore-next
+    /// Creates a state set containing `types`.
+    pub fn with_states(types: &[StateType]) -> StateSet {
+        let set = StateSet::new();
+        set.add_states(types);
+        set
+    }
+}
+
+pub trait StateSetExtManual: 'static {
+    #[doc(alias = "atk_state_set_add_states")]
+    fn add_states(&self, types: &[StateType]);
+
+    // rustdoc-stripper-ignore-next
+    /// Returns whether the set contains all of `types`.
+    #[doc(alias = "atk_state_set_contains_states")]
+    fn contains_states(&self, types: &[StateType]) -> bool;
+
+    // rustdoc-stripper-ignore-next
+    /// Adds `type_` to the set if `setting` is `true`, removes it otherwise.
+    fn set_state(&self, type_: StateType, setting: bool);
+}
+
+impl<O: IsA<StateSet>> StateSetExtManual for O {
+    fn add_states(&self, types: &[StateType]) {
+        let mut types = types.iter().map(|t| t.to_glib()).collect::<Vec<_>>();
+        unsafe {
+            ffi::atk_state_set_add_states(
+                self.as_ref().to_glib_none().0,
+                types.as_mut_ptr(),
+                types.len() as i32,
+            );
+        }
+    }
+
+    fn contains_states(&self, types: &[StateType]) -> bool {
+        let mut types = types.iter().map(|t| t.to_glib()).collect::<Vec<_>>();
+        unsafe {
+            from_glib(ffi::atk_state_set_contains_states(
+                self.as_ref().to_glib_none().0,
+                types.as_mut_ptr(),
+                types.len() as i32,
+            ))
+        }
+    }
+
+    fn set_state(&self, type_: StateType, setting: bool) {
+        if setting {
+            self.add_state(type_);
+        } else {
+            self.remove_state(type_);
+        }
+    }
+}
@@ -0,0 +1,236 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use glib::subclass::prelude::*;
+use glib::translate::*;
+use glib::{Cast, GString, IsA};
+use libc::c_char;
+
+use super::object::keep_string;
+use crate::Action;
+
+// rustdoc-stripper-ignore-next
+/// Implementation of the `atk::Action` interface, listing the actions a screen reader can
+/// trigger on the object, like "click" or "press".
+///
+/// The actions are identified by their index, from `0` to `get_n_actions() - 1`.
+pub trait ActionImpl: ObjectImpl {
+    fn do_action(&self, action: &Self::Type, i: i32) -> bool;
+
+    fn get_n_actions(&self, action: &Self::Type) -> i32;
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the non-localized name of the action, e.g. `"click"`.
+    fn get_name(&self, action: &Self::Type, i: i32) -> Option<GString>;
+
+    fn get_localized_name(&self, action: &Self::Type, i: i32) -> Option<GString> {
+        self.get_name(action, i)
+    }
+
+    fn get_description(&self, _action: &Self::Type, _i: i32) -> Option<GString> {
+        None
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the keybindings of the action, in the format described in the ATK documentation
+    /// of `atk_action_get_keybinding`.
+    fn get_keybinding(&self, _action: &Self::Type, _i: i32) -> Option<GString> {
+        None
+    }
+
+    fn set_description(&self, _action: &Self::Type, _i: i32, _description: &str) -> bool {
+        false
+    }
+}
+
+unsafe impl<T: ActionImpl> IsImplementable<T> for Action
+where
+    <T as ObjectSubclass>::Type: IsA<glib::Object>,
+{
+    unsafe extern "C" fn interface_init(
+        iface: glib::ffi::gpointer,
+        _iface_data: glib::ffi::gpointer,
+    ) {
+        let action_iface = &mut *(iface as *mut ffi::AtkActionIface);
+
+        action_iface.do_action = Some(action_do_action::<T>);
+        action_iface.get_n_actions = Some(action_get_n_actions::<T>);
+        action_iface.get_name = Some(action_get_name::<T>);
+        action_iface.get_localized_name = Some(action_get_localized_name::<T>);
+        action_iface.get_description = Some(action_get_description::<T>);
+        action_iface.get_keybinding = Some(action_get_keybinding::<T>);
+        action_iface.set_description = Some(action_set_description::<T>);
+    }
+}
+
+unsafe extern "C" fn action_do_action<T: ActionImpl>(
+    action: *mut ffi::AtkAction,
+    i: i32,
+) -> glib::ffi::gboolean {
+    let instance = &*(action as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Action> = from_glib_borrow(action);
+
+    imp.do_action(wrap.unsafe_cast_ref(), i).to_glib()
+}
+
+unsafe extern "C" fn action_get_n_actions<T: ActionImpl>(action: *mut ffi::AtkAction) -> i32 {
+    let instance = &*(action as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Action> = from_glib_borrow(action);
+
+    imp.get_n_actions(wrap.unsafe_cast_ref())
+}
+
+unsafe extern "C" fn action_get_name<T: ActionImpl>(
+    action: *mut ffi::AtkAction,
+    i: i32,
+) -> *const c_char {
+    let instance = &*(action as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Action> = from_glib_borrow(action);
+
+    keep_string(
+        wrap.upcast_ref(),
+        &format!("gtk-rs-subclass-atk-action-name-{}", i),
+        imp.get_name(wrap.unsafe_cast_ref(), i),
+    )
+}
+
+unsafe extern "C" fn action_get_localized_name<T: ActionImpl>(
+    action: *mut ffi::AtkAction,
+    i: i32,
+) -> *const c_char {
+    let instance = &*(action as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Action> = from_glib_borrow(action);
+
+    keep_string(
+        wrap.upcast_ref(),
+        &format!("gtk-rs-subclass-atk-action-localized-name-{}", i),
+        imp.get_localized_name(wrap.unsafe_cast_ref(), i),
+    )
+}
+
+unsafe extern "C" fn action_get_description<T: ActionImpl>(
+    action: *mut ffi::AtkAction,
+    i: i32,
+) -> *const c_char {
+    let instance = &*(action as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Action> = from_glib_borrow(action);
+
+    keep_string(
+        wrap.upcast_ref(),
+        &format!("gtk-rs-subclass-atk-action-description-{}", i),
+        imp.get_description(wrap.unsafe_cast_ref(), i),
+    )
+}
+
+unsafe extern "C" fn action_get_keybinding<T: ActionImpl>(
+    action: *mut ffi::AtkAction,
+    i: i32,
+) -> *const c_char {
+    let instance = &*(action as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Action> = from_glib_borrow(action);
+
+    keep_string(
+        wrap.upcast_ref(),
+        &format!("gtk-rs-subclass-atk-action-keybinding-{}", i),
+        imp.get_keybinding(wrap.unsafe_cast_ref(), i),
+    )
+}
+
+unsafe extern "C" fn action_set_description<T: ActionImpl>(
+    action: *mut ffi::AtkAction,
+    i: i32,
+    description: *const c_char,
+) -> glib::ffi::gboolean {
+    let instance = &*(action as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Action> = from_glib_borrow(action);
+
+    imp.set_description(
+        wrap.unsafe_cast_ref(),
+        i,
+        &GString::from_glib_borrow(description),
+    )
+    .to_glib()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subclass::prelude::*;
+    use glib::subclass;
+    use glib::StaticType;
+    use std::ffi::CStr;
+
+    mod imp {
+        use super::*;
+
+        pub struct SimpleAction;
+
+        impl ObjectSubclass for SimpleAction {
+            const NAME: &'static str = "SimpleAtkAction";
+            type Type = super::SimpleAction;
+            type ParentType = crate::Object;
+            type Instance = subclass::simple::InstanceStruct<Self>;
+            type Class = subclass::simple::ClassStruct<Self>;
+
+            glib::glib_object_subclass!();
+
+            fn new() -> Self {
+                Self
+            }
+
+            fn type_init(type_: &mut subclass::InitializingType<Self>) {
+                type_.add_interface::<Action>();
+            }
+        }
+
+        impl ObjectImpl for SimpleAction {}
+
+        impl AtkObjectImpl for SimpleAction {}
+
+        impl ActionImpl for SimpleAction {
+            fn do_action(&self, _action: &Self::Type, _i: i32) -> bool {
+                true
+            }
+
+            fn get_n_actions(&self, _action: &Self::Type) -> i32 {
+                2
+            }
+
+            fn get_name(&self, _action: &Self::Type, i: i32) -> Option<GString> {
+                Some(format!("action{}", i).into())
+            }
+        }
+    }
+
+    glib::glib_wrapper! {
+        pub struct SimpleAction(ObjectSubclass<imp::SimpleAction>)
+            @extends crate::Object, @implements Action;
+    }
+
+    #[test]
+    fn test_names_stay_valid() {
+        let action = glib::Object::new(SimpleAction::static_type(), &[])
+            .unwrap()
+            .downcast::<SimpleAction>()
+            .unwrap();
+        let action: &Action = action.upcast_ref();
+
+        unsafe {
+            // Both strings are held at once, like ATK does when listing the actions.
+            let name0 = ffi::atk_action_get_name(action.to_glib_none().0, 0);
+            let name1 = ffi::atk_action_get_name(action.to_glib_none().0, 1);
+            let name0_again = ffi::atk_action_get_name(action.to_glib_none().0, 0);
+            assert_eq!(CStr::from_ptr(name0).to_str(), Ok("action0"));
+            assert_eq!(CStr::from_ptr(name1).to_str(), Ok("action1"));
+            assert_eq!(name0, name0_again);
+        }
+    }
+}
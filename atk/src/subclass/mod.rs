@@ -0,0 +1,16 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+mod action;
+mod object;
+mod text;
+
+pub use self::prelude::*;
+
+pub mod prelude {
+    pub use super::action::ActionImpl;
+    pub use super::object::{AtkObjectImpl, AtkObjectImplExt};
+    pub use super::text::TextImpl;
+    pub use glib::subclass::prelude::*;
+}
@@ -0,0 +1,240 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use glib::subclass::prelude::*;
+use glib::translate::*;
+use glib::{Cast, GString, ObjectExt, Quark};
+use libc::c_char;
+use std::ptr;
+
+use crate::{Object, Role, StateSet};
+
+// rustdoc-stripper-ignore-next
+/// Implementation of the virtual methods of `atk::Object`.
+///
+/// The default implementations return what was set with the `AtkObjectExt` setters, so
+/// subclasses usually only override the methods describing their children and state.
+pub trait AtkObjectImpl: ObjectImpl + AtkObjectImplExt {
+    fn get_name(&self, obj: &Self::Type) -> Option<GString> {
+        self.parent_get_name(obj)
+    }
+
+    fn get_description(&self, obj: &Self::Type) -> Option<GString> {
+        self.parent_get_description(obj)
+    }
+
+    fn get_n_children(&self, obj: &Self::Type) -> i32 {
+        self.parent_get_n_children(obj)
+    }
+
+    fn ref_child(&self, obj: &Self::Type, i: i32) -> Option<Object> {
+        self.parent_ref_child(obj, i)
+    }
+
+    fn get_index_in_parent(&self, obj: &Self::Type) -> i32 {
+        self.parent_get_index_in_parent(obj)
+    }
+
+    fn get_role(&self, obj: &Self::Type) -> Role {
+        self.parent_get_role(obj)
+    }
+
+    fn ref_state_set(&self, obj: &Self::Type) -> Option<StateSet> {
+        self.parent_ref_state_set(obj)
+    }
+}
+
+pub trait AtkObjectImplExt: ObjectSubclass {
+    fn parent_get_name(&self, obj: &Self::Type) -> Option<GString>;
+    fn parent_get_description(&self, obj: &Self::Type) -> Option<GString>;
+    fn parent_get_n_children(&self, obj: &Self::Type) -> i32;
+    fn parent_ref_child(&self, obj: &Self::Type, i: i32) -> Option<Object>;
+    fn parent_get_index_in_parent(&self, obj: &Self::Type) -> i32;
+    fn parent_get_role(&self, obj: &Self::Type) -> Role;
+    fn parent_ref_state_set(&self, obj: &Self::Type) -> Option<StateSet>;
+}
+
+impl<T: AtkObjectImpl> AtkObjectImplExt for T {
+    fn parent_get_name(&self, obj: &Self::Type) -> Option<GString> {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::AtkObjectClass;
+            let f = (*parent_class).get_name?;
+            from_glib_none(f(obj.unsafe_cast_ref::<Object>().to_glib_none().0))
+        }
+    }
+
+    fn parent_get_description(&self, obj: &Self::Type) -> Option<GString> {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::AtkObjectClass;
+            let f = (*parent_class).get_description?;
+            from_glib_none(f(obj.unsafe_cast_ref::<Object>().to_glib_none().0))
+        }
+    }
+
+    fn parent_get_n_children(&self, obj: &Self::Type) -> i32 {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::AtkObjectClass;
+            match (*parent_class).get_n_children {
+                Some(f) => f(obj.unsafe_cast_ref::<Object>().to_glib_none().0),
+                None => 0,
+            }
+        }
+    }
+
+    fn parent_ref_child(&self, obj: &Self::Type, i: i32) -> Option<Object> {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::AtkObjectClass;
+            let f = (*parent_class).ref_child?;
+            from_glib_full(f(obj.unsafe_cast_ref::<Object>().to_glib_none().0, i))
+        }
+    }
+
+    fn parent_get_index_in_parent(&self, obj: &Self::Type) -> i32 {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::AtkObjectClass;
+            match (*parent_class).get_index_in_parent {
+                Some(f) => f(obj.unsafe_cast_ref::<Object>().to_glib_none().0),
+                None => -1,
+            }
+        }
+    }
+
+    fn parent_get_role(&self, obj: &Self::Type) -> Role {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::AtkObjectClass;
+            match (*parent_class).get_role {
+                Some(f) => from_glib(f(obj.unsafe_cast_ref::<Object>().to_glib_none().0)),
+                None => Role::Unknown,
+            }
+        }
+    }
+
+    fn parent_ref_state_set(&self, obj: &Self::Type) -> Option<StateSet> {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::AtkObjectClass;
+            let f = (*parent_class).ref_state_set?;
+            from_glib_full(f(obj.unsafe_cast_ref::<Object>().to_glib_none().0))
+        }
+    }
+}
+
+unsafe impl<T: AtkObjectImpl> IsSubclassable<T> for Object {
+    fn override_vfuncs(class: &mut ::glib::Class<Self>) {
+        <glib::Object as IsSubclassable<T>>::override_vfuncs(class);
+
+        let klass = class.as_mut();
+        klass.get_name = Some(object_get_name::<T>);
+        klass.get_description = Some(object_get_description::<T>);
+        klass.get_n_children = Some(object_get_n_children::<T>);
+        klass.ref_child = Some(object_ref_child::<T>);
+        klass.get_index_in_parent = Some(object_get_index_in_parent::<T>);
+        klass.get_role = Some(object_get_role::<T>);
+        klass.ref_state_set = Some(object_ref_state_set::<T>);
+    }
+}
+
+// The strings returned by some virtual methods stay owned by the object. The last one returned
+// for `key` is kept alive in the qdata of the object, callers copy it before the next call for
+// the same key, so methods taking an index need a key per index. An unchanged string keeps its
+// pointer.
+pub(super) unsafe fn keep_string(
+    obj: &glib::Object,
+    key: &str,
+    string: Option<GString>,
+) -> *const c_char {
+    let quark = Quark::from_string(key);
+    match string {
+        Some(string) => {
+            if obj.get_qdata::<GString>(quark).map(|kept| kept.as_str()) != Some(string.as_str()) {
+                obj.set_qdata(quark, string);
+            }
+            obj.get_qdata::<GString>(quark)
+                .expect("string stored right before")
+                .to_glib_none()
+                .0
+        }
+        None => {
+            obj.steal_qdata::<GString>(quark);
+            ptr::null()
+        }
+    }
+}
+
+unsafe extern "C" fn object_get_name<T: AtkObjectImpl>(ptr: *mut ffi::AtkObject) -> *const c_char {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Object> = from_glib_borrow(ptr);
+
+    keep_string(
+        wrap.upcast_ref(),
+        "gtk-rs-subclass-atk-object-name",
+        imp.get_name(wrap.unsafe_cast_ref()),
+    )
+}
+
+unsafe extern "C" fn object_get_description<T: AtkObjectImpl>(
+    ptr: *mut ffi::AtkObject,
+) -> *const c_char {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Object> = from_glib_borrow(ptr);
+
+    keep_string(
+        wrap.upcast_ref(),
+        "gtk-rs-subclass-atk-object-description",
+        imp.get_description(wrap.unsafe_cast_ref()),
+    )
+}
+
+unsafe extern "C" fn object_get_n_children<T: AtkObjectImpl>(ptr: *mut ffi::AtkObject) -> i32 {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Object> = from_glib_borrow(ptr);
+
+    imp.get_n_children(wrap.unsafe_cast_ref())
+}
+
+unsafe extern "C" fn object_ref_child<T: AtkObjectImpl>(
+    ptr: *mut ffi::AtkObject,
+    i: i32,
+) -> *mut ffi::AtkObject {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Object> = from_glib_borrow(ptr);
+
+    imp.ref_child(wrap.unsafe_cast_ref(), i).to_glib_full()
+}
+
+unsafe extern "C" fn object_get_index_in_parent<T: AtkObjectImpl>(ptr: *mut ffi::AtkObject) -> i32 {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Object> = from_glib_borrow(ptr);
+
+    imp.get_index_in_parent(wrap.unsafe_cast_ref())
+}
+
+unsafe extern "C" fn object_get_role<T: AtkObjectImpl>(ptr: *mut ffi::AtkObject) -> ffi::AtkRole {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Object> = from_glib_borrow(ptr);
+
+    imp.get_role(wrap.unsafe_cast_ref()).to_glib()
+}
+
+unsafe extern "C" fn object_ref_state_set<T: AtkObjectImpl>(
+    ptr: *mut ffi::AtkObject,
+) -> *mut ffi::AtkStateSet {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Object> = from_glib_borrow(ptr);
+
+    imp.ref_state_set(wrap.unsafe_cast_ref()).to_glib_full()
+}
@@ -0,0 +1,304 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use glib::subclass::prelude::*;
+use glib::translate::*;
+use glib::{Cast, GString, IsA};
+use libc::c_char;
+use std::ptr;
+
+use crate::{CoordType, Text, TextGranularity};
+
+// rustdoc-stripper-ignore-next
+/// Implementation of the `atk::Text` interface, giving screen readers access to the text
+/// shown by the object.
+///
+/// All the offsets are in characters, not bytes. An end offset of `-1` stands for the end of
+/// the text.
+pub trait TextImpl: ObjectImpl {
+    fn get_text(&self, text: &Self::Type, start_offset: i32, end_offset: i32) -> GString;
+
+    fn get_character_count(&self, text: &Self::Type) -> i32;
+
+    fn get_caret_offset(&self, text: &Self::Type) -> i32;
+
+    fn set_caret_offset(&self, _text: &Self::Type, _offset: i32) -> bool {
+        false
+    }
+
+    fn get_character_at_offset(&self, text: &Self::Type, offset: i32) -> char {
+        self.get_text(text, offset, offset + 1)
+            .chars()
+            .next()
+            .unwrap_or('\0')
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the text around `offset` for the given granularity, with its start and end
+    /// offsets.
+    fn get_string_at_offset(
+        &self,
+        _text: &Self::Type,
+        _offset: i32,
+        _granularity: TextGranularity,
+    ) -> Option<(GString, i32, i32)> {
+        None
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the `(x, y, width, height)` extents of the character at `offset`.
+    fn get_character_extents(
+        &self,
+        _text: &Self::Type,
+        _offset: i32,
+        _coords: CoordType,
+    ) -> Option<(i32, i32, i32, i32)> {
+        None
+    }
+
+    fn get_offset_at_point(&self, _text: &Self::Type, _x: i32, _y: i32, _coords: CoordType) -> i32 {
+        -1
+    }
+
+    fn get_n_selections(&self, _text: &Self::Type) -> i32 {
+        0
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the text of the selection number `selection_num`, with its start and end
+    /// offsets.
+    fn get_selection(
+        &self,
+        _text: &Self::Type,
+        _selection_num: i32,
+    ) -> Option<(GString, i32, i32)> {
+        None
+    }
+
+    fn add_selection(&self, _text: &Self::Type, _start_offset: i32, _end_offset: i32) -> bool {
+        false
+    }
+
+    fn remove_selection(&self, _text: &Self::Type, _selection_num: i32) -> bool {
+        false
+    }
+
+    fn set_selection(
+        &self,
+        _text: &Self::Type,
+        _selection_num: i32,
+        _start_offset: i32,
+        _end_offset: i32,
+    ) -> bool {
+        false
+    }
+}
+
+unsafe impl<T: TextImpl> IsImplementable<T> for Text
+where
+    <T as ObjectSubclass>::Type: IsA<glib::Object>,
+{
+    unsafe extern "C" fn interface_init(
+        iface: glib::ffi::gpointer,
+        _iface_data: glib::ffi::gpointer,
+    ) {
+        let text_iface = &mut *(iface as *mut ffi::AtkTextIface);
+
+        text_iface.get_text = Some(text_get_text::<T>);
+        text_iface.get_character_count = Some(text_get_character_count::<T>);
+        text_iface.get_caret_offset = Some(text_get_caret_offset::<T>);
+        text_iface.set_caret_offset = Some(text_set_caret_offset::<T>);
+        text_iface.get_character_at_offset = Some(text_get_character_at_offset::<T>);
+        text_iface.get_string_at_offset = Some(text_get_string_at_offset::<T>);
+        text_iface.get_character_extents = Some(text_get_character_extents::<T>);
+        text_iface.get_offset_at_point = Some(text_get_offset_at_point::<T>);
+        text_iface.get_n_selections = Some(text_get_n_selections::<T>);
+        text_iface.get_selection = Some(text_get_selection::<T>);
+        text_iface.add_selection = Some(text_add_selection::<T>);
+        text_iface.remove_selection = Some(text_remove_selection::<T>);
+        text_iface.set_selection = Some(text_set_selection::<T>);
+    }
+}
+
+unsafe extern "C" fn text_get_text<T: TextImpl>(
+    text: *mut ffi::AtkText,
+    start_offset: i32,
+    end_offset: i32,
+) -> *mut c_char {
+    let instance = &*(text as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Text> = from_glib_borrow(text);
+
+    imp.get_text(wrap.unsafe_cast_ref(), start_offset, end_offset)
+        .to_glib_full()
+}
+
+unsafe extern "C" fn text_get_character_count<T: TextImpl>(text: *mut ffi::AtkText) -> i32 {
+    let instance = &*(text as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Text> = from_glib_borrow(text);
+
+    imp.get_character_count(wrap.unsafe_cast_ref())
+}
+
+unsafe extern "C" fn text_get_caret_offset<T: TextImpl>(text: *mut ffi::AtkText) -> i32 {
+    let instance = &*(text as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Text> = from_glib_borrow(text);
+
+    imp.get_caret_offset(wrap.unsafe_cast_ref())
+}
+
+unsafe extern "C" fn text_set_caret_offset<T: TextImpl>(
+    text: *mut ffi::AtkText,
+    offset: i32,
+) -> glib::ffi::gboolean {
+    let instance = &*(text as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Text> = from_glib_borrow(text);
+
+    imp.set_caret_offset(wrap.unsafe_cast_ref(), offset)
+        .to_glib()
+}
+
+unsafe extern "C" fn text_get_character_at_offset<T: TextImpl>(
+    text: *mut ffi::AtkText,
+    offset: i32,
+) -> u32 {
+    let instance = &*(text as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Text> = from_glib_borrow(text);
+
+    imp.get_character_at_offset(wrap.unsafe_cast_ref(), offset) as u32
+}
+
+unsafe extern "C" fn text_get_string_at_offset<T: TextImpl>(
+    text: *mut ffi::AtkText,
+    offset: i32,
+    granularity: ffi::AtkTextGranularity,
+    start_offset: *mut i32,
+    end_offset: *mut i32,
+) -> *mut c_char {
+    let instance = &*(text as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Text> = from_glib_borrow(text);
+
+    match imp.get_string_at_offset(wrap.unsafe_cast_ref(), offset, from_glib(granularity)) {
+        Some((string, start, end)) => {
+            *start_offset = start;
+            *end_offset = end;
+            string.to_glib_full()
+        }
+        None => ptr::null_mut(),
+    }
+}
+
+unsafe extern "C" fn text_get_character_extents<T: TextImpl>(
+    text: *mut ffi::AtkText,
+    offset: i32,
+    x: *mut i32,
+    y: *mut i32,
+    width: *mut i32,
+    height: *mut i32,
+    coords: ffi::AtkCoordType,
+) {
+    let instance = &*(text as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Text> = from_glib_borrow(text);
+
+    // `atk_text_get_character_extents` always passes valid pointers, set to `0` beforehand.
+    if let Some(extents) =
+        imp.get_character_extents(wrap.unsafe_cast_ref(), offset, from_glib(coords))
+    {
+        *x = extents.0;
+        *y = extents.1;
+        *width = extents.2;
+        *height = extents.3;
+    }
+}
+
+unsafe extern "C" fn text_get_offset_at_point<T: TextImpl>(
+    text: *mut ffi::AtkText,
+    x: i32,
+    y: i32,
+    coords: ffi::AtkCoordType,
+) -> i32 {
+    let instance = &*(text as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Text> = from_glib_borrow(text);
+
+    imp.get_offset_at_point(wrap.unsafe_cast_ref(), x, y, from_glib(coords))
+}
+
+unsafe extern "C" fn text_get_n_selections<T: TextImpl>(text: *mut ffi::AtkText) -> i32 {
+    let instance = &*(text as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Text> = from_glib_borrow(text);
+
+    imp.get_n_selections(wrap.unsafe_cast_ref())
+}
+
+unsafe extern "C" fn text_get_selection<T: TextImpl>(
+    text: *mut ffi::AtkText,
+    selection_num: i32,
+    start_offset: *mut i32,
+    end_offset: *mut i32,
+) -> *mut c_char {
+    let instance = &*(text as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Text> = from_glib_borrow(text);
+
+    match imp.get_selection(wrap.unsafe_cast_ref(), selection_num) {
+        Some((string, start, end)) => {
+            *start_offset = start;
+            *end_offset = end;
+            string.to_glib_full()
+        }
+        None => ptr::null_mut(),
+    }
+}
+
+unsafe extern "C" fn text_add_selection<T: TextImpl>(
+    text: *mut ffi::AtkText,
+    start_offset: i32,
+    end_offset: i32,
+) -> glib::ffi::gboolean {
+    let instance = &*(text as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Text> = from_glib_borrow(text);
+
+    imp.add_selection(wrap.unsafe_cast_ref(), start_offset, end_offset)
+        .to_glib()
+}
+
+unsafe extern "C" fn text_remove_selection<T: TextImpl>(
+    text: *mut ffi::AtkText,
+    selection_num: i32,
+) -> glib::ffi::gboolean {
+    let instance = &*(text as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Text> = from_glib_borrow(text);
+
+    imp.remove_selection(wrap.unsafe_cast_ref(), selection_num)
+        .to_glib()
+}
+
+unsafe extern "C" fn text_set_selection<T: TextImpl>(
+    text: *mut ffi::AtkText,
+    selection_num: i32,
+    start_offset: i32,
+    end_offset: i32,
+) -> glib::ffi::gboolean {
+    let instance = &*(text as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Text> = from_glib_borrow(text);
+
+    imp.set_selection(
+        wrap.unsafe_cast_ref(),
+        selection_num,
+        start_offset,
+        end_offset,
+    )
+    .to_glib()
+}
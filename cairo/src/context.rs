@@ -175,6 +175,25 @@ impl Context {
         self.status().expect("Failed to restore")
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Saves the graphics state and returns a guard restoring it when dropped.
+    ///
+    /// The guard dereferences to the context, so it can be used for drawing in its place.
+    pub fn save_guard(&self) -> SaveGuard<'_> {
+        self.save();
+        SaveGuard { context: self }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns a guard resetting the current transformation matrix to its present value when
+    /// dropped, leaving the rest of the graphics state (source, clip, ...) untouched.
+    pub fn transform_guard(&self) -> TransformGuard<'_> {
+        TransformGuard {
+            context: self,
+            matrix: self.get_matrix(),
+        }
+    }
+
     pub fn get_target(&self) -> Surface {
         unsafe { Surface::from_raw_none(ffi::cairo_get_target(self.0.as_ptr())) }
     }
@@ -791,6 +810,47 @@ impl fmt::Display for Context {
     }
 }
 
+#[derive(Debug)]
+#[must_use = "the state is restored as soon as the guard is dropped"]
+pub struct SaveGuard<'a> {
+    context: &'a Context,
+}
+
+impl<'a> ops::Deref for SaveGuard<'a> {
+    type Target = Context;
+
+    fn deref(&self) -> &Context {
+        self.context
+    }
+}
+
+impl<'a> Drop for SaveGuard<'a> {
+    fn drop(&mut self) {
+        self.context.restore();
+    }
+}
+
+#[derive(Debug)]
+#[must_use = "the transformation is reset as soon as the guard is dropped"]
+pub struct TransformGuard<'a> {
+    context: &'a Context,
+    matrix: Matrix,
+}
+
+impl<'a> ops::Deref for TransformGuard<'a> {
+    type Target = Context;
+
+    fn deref(&self) -> &Context {
+        self.context
+    }
+}
+
+impl<'a> Drop for TransformGuard<'a> {
+    fn drop(&mut self) {
+        self.context.set_matrix(self.matrix);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -826,4 +886,30 @@ mod tests {
         );
         assert_eq!(rect.to_string(), "RectangleList");
     }
+
+    #[test]
+    fn save_guard_restores() {
+        let ctx = create_ctx();
+        ctx.set_line_width(2.);
+        {
+            let ctx = ctx.save_guard();
+            ctx.set_line_width(5.);
+            ctx.translate(3., 4.);
+            assert_eq!(ctx.get_line_width(), 5.);
+        }
+        assert_eq!(ctx.get_line_width(), 2.);
+        assert_eq!(ctx.get_matrix(), Matrix::identity());
+    }
+
+    #[test]
+    fn transform_guard_restores_matrix_only() {
+        let ctx = create_ctx();
+        {
+            let ctx = ctx.transform_guard();
+            ctx.scale(2., 2.);
+            ctx.set_line_width(5.);
+        }
+        assert_eq!(ctx.get_matrix(), Matrix::identity());
+        assert_eq!(ctx.get_line_width(), 5.);
+    }
 }
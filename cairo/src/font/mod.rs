@@ -5,6 +5,7 @@
 mod font_face;
 mod font_options;
 mod scaled_font;
+mod user_fonts;
 
 pub use crate::enums::{
     Antialias, FontSlant, FontType, FontWeight, HintMetrics, HintStyle, SubpixelOrder,
@@ -42,3 +43,4 @@ impl TextCluster {
 pub use self::font_face::FontFace;
 pub use self::font_options::FontOptions;
 pub use self::scaled_font::ScaledFont;
+pub use self::user_fonts::UserFontFace;
@@ -0,0 +1,233 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use libc::c_ulong;
+use std::fmt;
+use std::ops::Deref;
+use std::rc::Rc;
+
+use crate::context::Context;
+use crate::error::Error;
+use crate::ffi::{FontExtents, TextExtents};
+use crate::utils::status_to_result;
+use crate::UserDataKey;
+
+use super::{FontFace, ScaledFont};
+
+type InitFunc = Box<dyn Fn(&ScaledFont, &Context, &mut FontExtents) -> Result<(), Error>>;
+type RenderGlyphFunc =
+    Box<dyn Fn(&ScaledFont, c_ulong, &Context, &mut TextExtents) -> Result<(), Error>>;
+type UnicodeToGlyphFunc = Box<dyn Fn(&ScaledFont, c_ulong) -> Result<c_ulong, Error>>;
+
+static INIT_FUNC: UserDataKey<InitFunc> = UserDataKey::new();
+static RENDER_GLYPH_FUNC: UserDataKey<RenderGlyphFunc> = UserDataKey::new();
+static UNICODE_TO_GLYPH_FUNC: UserDataKey<UnicodeToGlyphFunc> = UserDataKey::new();
+
+// rustdoc-stripper-ignore-next
+/// A font face whose glyphs are drawn by Rust closures.
+///
+/// The callbacks have to be set before the font face is first used, cairo
+/// ignores them afterwards.
+#[derive(Debug, Clone)]
+pub struct UserFontFace(FontFace);
+
+impl UserFontFace {
+    pub fn create() -> Result<UserFontFace, Error> {
+        let font_face = unsafe { FontFace::from_raw_full(ffi::cairo_user_font_face_create()) };
+        let status = unsafe { ffi::cairo_font_face_status(font_face.to_raw_none()) };
+        status_to_result(status)?;
+        Ok(UserFontFace(font_face))
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Sets the function called once for every new scaled font, to fill in its extents.
+    pub fn set_init_func<F>(&self, func: F)
+    where
+        F: Fn(&ScaledFont, &Context, &mut FontExtents) -> Result<(), Error> + 'static,
+    {
+        unsafe extern "C" fn init_trampoline(
+            scaled_font: *mut ffi::cairo_scaled_font_t,
+            cr: *mut ffi::cairo_t,
+            extents: *mut FontExtents,
+        ) -> ffi::cairo_status_t {
+            let font_face = get_font_face(scaled_font);
+            let func = match font_face.get_user_data_ptr(&INIT_FUNC) {
+                Some(func) => func,
+                None => return ffi::STATUS_USER_FONT_ERROR,
+            };
+            let scaled_font = ScaledFont::from_raw_none(scaled_font);
+            let cr = Context::from_raw_borrow(cr);
+            match (*func.as_ptr())(&scaled_font, &cr, &mut *extents) {
+                Ok(()) => ffi::STATUS_SUCCESS,
+                Err(err) => err.into(),
+            }
+        }
+
+        self.0
+            .set_user_data(&INIT_FUNC, Rc::new(Box::new(func) as InitFunc));
+        unsafe {
+            ffi::cairo_user_font_face_set_init_func(self.to_raw_none(), Some(init_trampoline));
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Sets the function drawing a glyph onto the given context and filling in its
+    /// advance in the `TextExtents`.
+    ///
+    /// The context is set up so that one unit corresponds to the font size.
+    pub fn set_render_glyph_func<F>(&self, func: F)
+    where
+        F: Fn(&ScaledFont, c_ulong, &Context, &mut TextExtents) -> Result<(), Error> + 'static,
+    {
+        unsafe extern "C" fn render_glyph_trampoline(
+            scaled_font: *mut ffi::cairo_scaled_font_t,
+            glyph: c_ulong,
+            cr: *mut ffi::cairo_t,
+            extents: *mut TextExtents,
+        ) -> ffi::cairo_status_t {
+            let font_face = get_font_face(scaled_font);
+            let func = match font_face.get_user_data_ptr(&RENDER_GLYPH_FUNC) {
+                Some(func) => func,
+                None => return ffi::STATUS_USER_FONT_ERROR,
+            };
+            let scaled_font = ScaledFont::from_raw_none(scaled_font);
+            let cr = Context::from_raw_borrow(cr);
+            match (*func.as_ptr())(&scaled_font, glyph, &cr, &mut *extents) {
+                Ok(()) => ffi::STATUS_SUCCESS,
+                Err(err) => err.into(),
+            }
+        }
+
+        self.0.set_user_data(
+            &RENDER_GLYPH_FUNC,
+            Rc::new(Box::new(func) as RenderGlyphFunc),
+        );
+        unsafe {
+            ffi::cairo_user_font_face_set_render_glyph_func(
+                self.to_raw_none(),
+                Some(render_glyph_trampoline),
+            );
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Sets the function mapping a unicode character to a glyph index.
+    ///
+    /// Without it, the character code is used as the glyph index.
+    pub fn set_unicode_to_glyph_func<F>(&self, func: F)
+    where
+        F: Fn(&ScaledFont, c_ulong) -> Result<c_ulong, Error> + 'static,
+    {
+        unsafe extern "C" fn unicode_to_glyph_trampoline(
+            scaled_font: *mut ffi::cairo_scaled_font_t,
+            unicode: c_ulong,
+            glyph_index: *mut c_ulong,
+        ) -> ffi::cairo_status_t {
+            let font_face = get_font_face(scaled_font);
+            let func = match font_face.get_user_data_ptr(&UNICODE_TO_GLYPH_FUNC) {
+                Some(func) => func,
+                None => return ffi::STATUS_USER_FONT_ERROR,
+            };
+            let scaled_font = ScaledFont::from_raw_none(scaled_font);
+            match (*func.as_ptr())(&scaled_font, unicode) {
+                Ok(glyph) => {
+                    *glyph_index = glyph;
+                    ffi::STATUS_SUCCESS
+                }
+                Err(err) => err.into(),
+            }
+        }
+
+        self.0.set_user_data(
+            &UNICODE_TO_GLYPH_FUNC,
+            Rc::new(Box::new(func) as UnicodeToGlyphFunc),
+        );
+        unsafe {
+            ffi::cairo_user_font_face_set_unicode_to_glyph_func(
+                self.to_raw_none(),
+                Some(unicode_to_glyph_trampoline),
+            );
+        }
+    }
+}
+
+unsafe fn get_font_face(scaled_font: *mut ffi::cairo_scaled_font_t) -> FontFace {
+    FontFace::from_raw_none(ffi::cairo_scaled_font_get_font_face(scaled_font))
+}
+
+impl Deref for UserFontFace {
+    type Target = FontFace;
+
+    fn deref(&self) -> &FontFace {
+        &self.0
+    }
+}
+
+impl From<UserFontFace> for FontFace {
+    fn from(font_face: UserFontFace) -> FontFace {
+        font_face.0
+    }
+}
+
+impl fmt::Display for UserFontFace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "UserFontFace")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::Format;
+    use crate::image_surface::ImageSurface;
+
+    fn square_font() -> UserFontFace {
+        let font_face = UserFontFace::create().unwrap();
+        font_face.set_unicode_to_glyph_func(|_, unicode| match unicode {
+            0x41 => Ok(1),
+            _ => Ok(0),
+        });
+        font_face.set_render_glyph_func(|_, glyph, cr, extents| {
+            if glyph == 1 {
+                cr.rectangle(0., -1., 1., 1.);
+                cr.fill();
+            }
+            extents.x_advance = 1.;
+            Ok(())
+        });
+        font_face
+    }
+
+    #[test]
+    fn user_font_type() {
+        let font_face = square_font();
+        assert_eq!(font_face.get_type(), crate::enums::FontType::FontTypeUser);
+    }
+
+    #[test]
+    fn render_glyphs() {
+        let font_face = square_font();
+        let surface = ImageSurface::create(Format::A8, 40, 20).unwrap();
+        let cr = Context::new(&surface);
+        cr.set_font_face(&font_face);
+        cr.set_font_size(10.);
+
+        let extents = cr.text_extents("AB");
+        assert!((extents.x_advance - 20.).abs() < f64::EPSILON);
+
+        cr.move_to(0., 10.);
+        cr.show_text("AB");
+        drop(cr);
+
+        let stride = surface.get_stride() as usize;
+        surface
+            .with_data(|data| {
+                // Inside the square drawn for "A".
+                assert_eq!(data[5 * stride + 5], 0xff);
+                // "B" maps to the empty glyph.
+                assert_eq!(data[5 * stride + 15], 0);
+            })
+            .unwrap();
+    }
+}
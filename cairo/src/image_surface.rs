@@ -104,6 +104,31 @@ impl ImageSurface {
         }
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Takes exclusive ownership of the surface's pixel data.
+    ///
+    /// The returned `ImageSurfaceDataOwned` can be read and written like a byte slice, and
+    /// turned back into the surface with `ImageSurfaceDataOwned::into_inner`, which marks the
+    /// surface dirty. Fails if the surface is referenced elsewhere.
+    pub fn take_data(self) -> Result<ImageSurfaceDataOwned, BorrowError> {
+        unsafe {
+            if ffi::cairo_surface_get_reference_count(self.to_raw_none()) > 1 {
+                return Err(BorrowError::NonExclusive);
+            }
+
+            self.flush();
+            let status = ffi::cairo_surface_status(self.to_raw_none());
+            if let Some(err) = status_to_result(status).err() {
+                return Err(BorrowError::from(err));
+            }
+            if ffi::cairo_image_surface_get_data(self.to_raw_none()).is_null() || is_finished(&self)
+            {
+                return Err(BorrowError::from(Error::SurfaceFinished));
+            }
+            Ok(ImageSurfaceDataOwned { surface: self })
+        }
+    }
+
     pub fn with_data<F: FnOnce(&[u8])>(&self, f: F) -> Result<(), BorrowError> {
         self.flush();
         unsafe {
@@ -189,6 +214,54 @@ impl<'a> fmt::Display for ImageSurfaceData<'a> {
     }
 }
 
+#[derive(Debug)]
+pub struct ImageSurfaceDataOwned {
+    surface: ImageSurface,
+}
+
+impl ImageSurfaceDataOwned {
+    pub fn into_inner(self) -> ImageSurface {
+        self.surface.mark_dirty();
+        self.surface
+    }
+}
+
+impl AsRef<[u8]> for ImageSurfaceDataOwned {
+    fn as_ref(&self) -> &[u8] {
+        let len = (self.surface.get_stride() as usize) * (self.surface.get_height() as usize);
+        unsafe {
+            let ptr = ffi::cairo_image_surface_get_data(self.surface.to_raw_none());
+            debug_assert!(!ptr.is_null());
+            slice::from_raw_parts(ptr, len)
+        }
+    }
+}
+
+impl AsMut<[u8]> for ImageSurfaceDataOwned {
+    fn as_mut(&mut self) -> &mut [u8] {
+        let len = (self.surface.get_stride() as usize) * (self.surface.get_height() as usize);
+        unsafe {
+            let ptr = ffi::cairo_image_surface_get_data(self.surface.to_raw_none());
+            debug_assert!(!ptr.is_null());
+            slice::from_raw_parts_mut(ptr, len)
+        }
+    }
+}
+
+impl Deref for ImageSurfaceDataOwned {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.as_ref()
+    }
+}
+
+impl DerefMut for ImageSurfaceDataOwned {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        self.as_mut()
+    }
+}
+
 // Workaround for cairo not having a direct way to check if the surface is finished.
 // See: https://gitlab.freedesktop.org/cairo/cairo/-/issues/406
 fn is_finished(surface: &ImageSurface) -> bool {
@@ -230,4 +303,25 @@ mod tests {
 
         assert!(surf.get_data().is_err());
     }
+
+    #[test]
+    fn take_data_round_trip() {
+        let surf = ImageSurface::create(Format::ARgb32, 4, 4).unwrap();
+
+        let mut data = surf.take_data().unwrap();
+        data[0] = 0xff;
+        let surf = data.into_inner();
+
+        let mut surf_data = None;
+        surf.with_data(|d| surf_data = Some(d[0])).unwrap();
+        assert_eq!(surf_data, Some(0xff));
+    }
+
+    #[test]
+    fn take_data_non_exclusive() {
+        let surf = ImageSurface::create(Format::ARgb32, 4, 4).unwrap();
+        let _other = surf.clone();
+
+        assert!(surf.take_data().is_err());
+    }
 }
@@ -95,7 +95,7 @@ macro_rules! gvalue_impl {
 
 pub use crate::user_data::UserDataKey;
 
-pub use crate::context::{Context, RectangleList};
+pub use crate::context::{Context, RectangleList, SaveGuard, TransformGuard};
 
 pub use crate::paths::{Path, PathSegment, PathSegments};
 
@@ -111,7 +111,7 @@ pub use crate::patterns::{
 
 pub use crate::font::{
     FontExtents, FontFace, FontOptions, FontSlant, FontType, FontWeight, Glyph, ScaledFont,
-    TextCluster, TextExtents,
+    TextCluster, TextExtents, UserFontFace,
 };
 
 pub use crate::matrices::Matrix;
@@ -124,7 +124,7 @@ pub use crate::region::Region;
 
 pub use crate::surface::{MappedImageSurface, Surface};
 
-pub use crate::image_surface::{ImageSurface, ImageSurfaceData};
+pub use crate::image_surface::{ImageSurface, ImageSurfaceData, ImageSurfaceDataOwned};
 
 #[cfg(any(feature = "pdf", feature = "svg", feature = "ps", feature = "dox"))]
 pub use stream::StreamWithError;
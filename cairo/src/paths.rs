@@ -21,7 +21,7 @@ impl Path {
         Path(ptr::NonNull::new_unchecked(pointer))
     }
 
-    pub fn iter(&self) -> PathSegments {
+    pub fn iter(&self) -> PathSegments<'_> {
         use std::slice;
 
         unsafe {
@@ -43,6 +43,15 @@ impl Path {
     }
 }
 
+impl<'a> IntoIterator for &'a Path {
+    type Item = PathSegment;
+    type IntoIter = PathSegments<'a>;
+
+    fn into_iter(self) -> PathSegments<'a> {
+        self.iter()
+    }
+}
+
 impl Drop for Path {
     fn drop(&mut self) {
         unsafe {
@@ -239,4 +248,24 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn flat_path_has_no_curves() {
+        let cr = make_cr();
+
+        cr.move_to(0.0, 0.0);
+        cr.curve_to(10.0, 0.0, 10.0, 10.0, 0.0, 10.0);
+
+        let path = cr.copy_path_flat();
+        let mut segments = path.into_iter();
+
+        assert_eq!(segments.next(), Some(PathSegment::MoveTo((0.0, 0.0))));
+        assert!(segments.all(|segment| match segment {
+            PathSegment::LineTo(_) => true,
+            _ => false,
+        }));
+
+        let last = path.iter().last().unwrap();
+        assert_eq!(last, PathSegment::LineTo((0.0, 10.0)));
+    }
 }
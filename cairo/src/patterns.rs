@@ -233,6 +233,14 @@ impl Gradient {
             (offset, red, green, blue, alpha)
         }
     }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns all color stops as `(offset, red, green, blue, alpha)`, ordered by offset.
+    pub fn get_color_stops(&self) -> Vec<(f64, f64, f64, f64, f64)> {
+        (0..self.get_color_stop_count())
+            .map(|index| self.get_color_stop_rgba(index))
+            .collect()
+    }
 }
 
 macro_rules! gradient_type {
@@ -256,6 +264,40 @@ macro_rules! gradient_type {
 
         convert!(Pattern => $gradient_type = $gradient_type (Gradient));
         convert!(Gradient => $gradient_type = $gradient_type);
+
+        impl $gradient_type {
+            pub fn with_color_stop_rgb(self, offset: f64, red: f64, green: f64, blue: f64) -> Self {
+                self.add_color_stop_rgb(offset, red, green, blue);
+                self
+            }
+
+            pub fn with_color_stop_rgba(
+                self,
+                offset: f64,
+                red: f64,
+                green: f64,
+                blue: f64,
+                alpha: f64,
+            ) -> Self {
+                self.add_color_stop_rgba(offset, red, green, blue, alpha);
+                self
+            }
+
+            pub fn with_extend(self, extend: Extend) -> Self {
+                self.set_extend(extend);
+                self
+            }
+
+            pub fn with_filter(self, filter: Filter) -> Self {
+                self.set_filter(filter);
+                self
+            }
+
+            pub fn with_matrix(self, matrix: Matrix) -> Self {
+                self.set_matrix(matrix);
+                self
+            }
+        }
     }
 }
 
@@ -499,3 +541,19 @@ fn try_from() {
     assert!(LinearGradient::try_from(gradient).is_ok());
     assert!(LinearGradient::try_from(pattern).is_ok());
 }
+
+#[test]
+fn gradient_builder() {
+    let linear = LinearGradient::new(0., 0., 100., 0.)
+        .with_color_stop_rgb(1., 0., 0., 1.)
+        .with_color_stop_rgba(0., 1., 0., 0., 0.5)
+        .with_extend(Extend::Reflect)
+        .with_filter(Filter::Nearest);
+
+    assert_eq!(
+        linear.get_color_stops(),
+        vec![(0., 1., 0., 0., 0.5), (1., 0., 0., 1., 1.)]
+    );
+    assert_eq!(linear.get_extend(), Extend::Reflect);
+    assert_eq!(linear.get_filter(), Filter::Nearest);
+}
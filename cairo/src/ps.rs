@@ -74,24 +74,39 @@ impl PsSurface {
         }
     }
 
-    pub fn cairo_ps_surface_dsc_begin_setup(&self) {
+    pub fn dsc_begin_setup(&self) {
         unsafe {
             ffi::cairo_ps_surface_dsc_begin_setup(self.0.to_raw_none());
         }
     }
 
-    pub fn cairo_ps_surface_dsc_begin_page_setup(&self) {
+    pub fn dsc_begin_page_setup(&self) {
         unsafe {
             ffi::cairo_ps_surface_dsc_begin_page_setup(self.0.to_raw_none());
         }
     }
 
-    pub fn cairo_ps_surface_dsc_comment(&self, comment: &str) {
+    pub fn dsc_comment(&self, comment: &str) {
         let comment = CString::new(comment).unwrap();
         unsafe {
             ffi::cairo_ps_surface_dsc_comment(self.0.to_raw_none(), comment.as_ptr());
         }
     }
+
+    #[deprecated(note = "Use `dsc_begin_setup` instead")]
+    pub fn cairo_ps_surface_dsc_begin_setup(&self) {
+        self.dsc_begin_setup();
+    }
+
+    #[deprecated(note = "Use `dsc_begin_page_setup` instead")]
+    pub fn cairo_ps_surface_dsc_begin_page_setup(&self) {
+        self.dsc_begin_page_setup();
+    }
+
+    #[deprecated(note = "Use `dsc_comment` instead")]
+    pub fn cairo_ps_surface_dsc_comment(&self, comment: &str) {
+        self.dsc_comment(comment);
+    }
 }
 
 #[cfg(test)]
@@ -185,6 +200,24 @@ mod test {
         assert_eq!(&buffer[..header.len()], header);
     }
 
+    #[test]
+    fn dsc_comment() {
+        let buffer: Vec<u8> = vec![];
+        let surface = PsSurface::for_stream(100., 100., buffer).unwrap();
+        surface.dsc_comment("%%Title: dsc test");
+        surface.dsc_begin_setup();
+        surface.dsc_begin_page_setup();
+        draw(&surface);
+        let buffer = *surface
+            .finish_output_stream()
+            .unwrap()
+            .downcast::<Vec<u8>>()
+            .unwrap();
+
+        let output = String::from_utf8_lossy(&buffer);
+        assert!(output.contains("%%Title: dsc test"));
+    }
+
     #[test]
     fn custom_writer() {
         struct CustomWriter(usize);
@@ -36,9 +36,12 @@ impl RecordingSurface {
 
     pub fn get_extents(&self) -> Option<Rectangle> {
         unsafe {
-            let rectangle: Rectangle = ::std::mem::zeroed();
-            if ffi::cairo_recording_surface_get_extents(self.to_raw_none(), rectangle.to_raw_none())
-                .as_bool()
+            let mut rectangle: Rectangle = ::std::mem::zeroed();
+            if ffi::cairo_recording_surface_get_extents(
+                self.to_raw_none(),
+                &mut rectangle as *mut Rectangle as *mut ffi::cairo_rectangle_t,
+            )
+            .as_bool()
             {
                 Some(rectangle)
             } else {
@@ -65,3 +68,66 @@ impl RecordingSurface {
         (x0, y0, width, height)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::Context;
+    use crate::enums::Format;
+    use crate::image_surface::ImageSurface;
+
+    fn draw(surface: &Surface) {
+        let cr = Context::new(surface);
+        cr.set_source_rgb(1.0, 0.0, 0.0);
+        cr.rectangle(10., 20., 30., 40.);
+        cr.fill();
+    }
+
+    #[test]
+    fn bounded_extents() {
+        let extents = Rectangle {
+            x: 0.,
+            y: 0.,
+            width: 100.,
+            height: 50.,
+        };
+        let surface = RecordingSurface::create(Content::ColorAlpha, extents).unwrap();
+        assert_eq!(surface.get_extents(), Some(extents));
+    }
+
+    #[test]
+    fn unbounded_extents() {
+        let surface = RecordingSurface::create(Content::ColorAlpha, None).unwrap();
+        assert_eq!(surface.get_extents(), None);
+    }
+
+    #[test]
+    fn ink_extents() {
+        let surface = RecordingSurface::create(Content::ColorAlpha, None).unwrap();
+        draw(&surface);
+        assert_eq!(surface.ink_extents(), (10., 20., 30., 40.));
+    }
+
+    #[test]
+    fn replay_scaled() {
+        let recording = RecordingSurface::create(Content::ColorAlpha, None).unwrap();
+        draw(&recording);
+
+        let target = ImageSurface::create(Format::ARgb32, 100, 200).unwrap();
+        let cr = Context::new(&target);
+        cr.scale(2., 2.);
+        cr.set_source_surface(&recording, 0., 0.);
+        cr.paint();
+        drop(cr);
+
+        let stride = target.get_stride() as usize;
+        target
+            .with_data(|data| {
+                // Inside the scaled rectangle, which now spans (20, 40) to (80, 120).
+                assert_ne!(data[50 * stride + 30 * 4..][..4], [0, 0, 0, 0]);
+                // Outside of it.
+                assert_eq!(data[10 * stride + 10 * 4..][..4], [0, 0, 0, 0]);
+            })
+            .unwrap();
+    }
+}
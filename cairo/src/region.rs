@@ -156,12 +156,22 @@ impl Region {
 
     pub fn get_rectangle(&self, nth: i32) -> RectangleInt {
         unsafe {
-            let rectangle: RectangleInt = ::std::mem::zeroed();
-            ffi::cairo_region_get_rectangle(self.0.as_ptr(), nth, rectangle.to_raw_none());
+            let mut rectangle: RectangleInt = ::std::mem::zeroed();
+            ffi::cairo_region_get_rectangle(
+                self.0.as_ptr(),
+                nth,
+                &mut rectangle as *mut RectangleInt as *mut ffi::cairo_rectangle_int_t,
+            );
             rectangle
         }
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Iterates over the non-overlapping rectangles making up the region.
+    pub fn rectangles(&self) -> impl Iterator<Item = RectangleInt> + '_ {
+        (0..self.num_rectangles()).map(move |nth| self.get_rectangle(nth))
+    }
+
     pub fn is_empty(&self) -> bool {
         unsafe { ffi::cairo_region_is_empty(self.0.as_ptr()).as_bool() }
     }
@@ -248,3 +258,56 @@ impl fmt::Display for Region {
         write!(f, "Region")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x: i32, y: i32, width: i32, height: i32) -> RectangleInt {
+        RectangleInt {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn union_and_contains() {
+        let region = Region::create_rectangle(&rect(0, 0, 10, 10));
+        region.union_rectangle(&rect(20, 0, 10, 10)).unwrap();
+
+        assert!(region.contains_point(5, 5));
+        assert!(region.contains_point(25, 5));
+        assert!(!region.contains_point(15, 5));
+        assert_eq!(
+            region.rectangles().collect::<Vec<_>>(),
+            vec![rect(0, 0, 10, 10), rect(20, 0, 10, 10)]
+        );
+    }
+
+    #[test]
+    fn subtract_and_intersect() {
+        let region = Region::create_rectangle(&rect(0, 0, 10, 10));
+        region.subtract_rectangle(&rect(0, 5, 10, 5)).unwrap();
+        assert_eq!(
+            region.rectangles().collect::<Vec<_>>(),
+            vec![rect(0, 0, 10, 5)]
+        );
+
+        region
+            .intersect(&Region::create_rectangle(&rect(5, 0, 10, 10)))
+            .unwrap();
+        assert_eq!(
+            region.rectangles().collect::<Vec<_>>(),
+            vec![rect(5, 0, 5, 5)]
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let region = Region::create();
+        assert!(region.is_empty());
+        assert_eq!(region.rectangles().count(), 0);
+    }
+}
@@ -275,6 +275,35 @@ pub type cairo_read_func_t =
     Option<unsafe extern "C" fn(*mut c_void, *mut c_uchar, c_uint) -> cairo_status_t>;
 pub type cairo_write_func_t =
     Option<unsafe extern "C" fn(*mut c_void, *mut c_uchar, c_uint) -> cairo_status_t>;
+pub type cairo_user_scaled_font_init_func_t = Option<
+    unsafe extern "C" fn(
+        *mut cairo_scaled_font_t,
+        *mut cairo_t,
+        *mut FontExtents,
+    ) -> cairo_status_t,
+>;
+pub type cairo_user_scaled_font_render_glyph_func_t = Option<
+    unsafe extern "C" fn(
+        *mut cairo_scaled_font_t,
+        c_ulong,
+        *mut cairo_t,
+        *mut TextExtents,
+    ) -> cairo_status_t,
+>;
+pub type cairo_user_scaled_font_text_to_glyphs_func_t = Option<
+    unsafe extern "C" fn(
+        *mut cairo_scaled_font_t,
+        *const c_char,
+        c_int,
+        *mut *mut Glyph,
+        *mut c_int,
+        *mut *mut TextCluster,
+        *mut c_int,
+        *mut cairo_text_cluster_flags_t,
+    ) -> cairo_status_t,
+>;
+pub type cairo_user_scaled_font_unicode_to_glyph_func_t =
+    Option<unsafe extern "C" fn(*mut cairo_scaled_font_t, c_ulong, *mut c_ulong) -> cairo_status_t>;
 
 #[cfg(any(feature = "freetype", feature = "dox"))]
 pub type FT_Face = *mut c_void;
@@ -821,6 +850,37 @@ extern "C" {
         key: *const cairo_user_data_key_t,
     ) -> *mut c_void;
 
+    // CAIRO USER FONT
+    pub fn cairo_user_font_face_create() -> *mut cairo_font_face_t;
+    pub fn cairo_user_font_face_set_init_func(
+        font_face: *mut cairo_font_face_t,
+        init_func: cairo_user_scaled_font_init_func_t,
+    );
+    pub fn cairo_user_font_face_get_init_func(
+        font_face: *mut cairo_font_face_t,
+    ) -> cairo_user_scaled_font_init_func_t;
+    pub fn cairo_user_font_face_set_render_glyph_func(
+        font_face: *mut cairo_font_face_t,
+        render_glyph_func: cairo_user_scaled_font_render_glyph_func_t,
+    );
+    pub fn cairo_user_font_face_get_render_glyph_func(
+        font_face: *mut cairo_font_face_t,
+    ) -> cairo_user_scaled_font_render_glyph_func_t;
+    pub fn cairo_user_font_face_set_text_to_glyphs_func(
+        font_face: *mut cairo_font_face_t,
+        text_to_glyphs_func: cairo_user_scaled_font_text_to_glyphs_func_t,
+    );
+    pub fn cairo_user_font_face_get_text_to_glyphs_func(
+        font_face: *mut cairo_font_face_t,
+    ) -> cairo_user_scaled_font_text_to_glyphs_func_t;
+    pub fn cairo_user_font_face_set_unicode_to_glyph_func(
+        font_face: *mut cairo_font_face_t,
+        unicode_to_glyph_func: cairo_user_scaled_font_unicode_to_glyph_func_t,
+    );
+    pub fn cairo_user_font_face_get_unicode_to_glyph_func(
+        font_face: *mut cairo_font_face_t,
+    ) -> cairo_user_scaled_font_unicode_to_glyph_func_t;
+
     // CAIRO SCALED FONT
     pub fn cairo_scaled_font_create(
         font_face: *mut cairo_font_face_t,
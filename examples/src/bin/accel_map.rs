@@ -0,0 +1,111 @@
+//! # Accel map example
+//!
+//! This sample shows keyboard shortcuts on a plain `gtk::Window`, without a `gtk::Application`:
+//! the shortcuts are connected to an accel group by accel path, and their accelerators are kept
+//! in the `gtk::AccelMap`, which is loaded at startup and saved on exit so they can be changed
+//! from the window.
+
+use gtk::prelude::*;
+
+use std::path::PathBuf;
+
+const COUNT_PATH: &str = "<AccelMap>/Counter/Count";
+const RESET_PATH: &str = "<AccelMap>/Counter/Reset";
+const QUIT_PATH: &str = "<AccelMap>/Window/Quit";
+
+fn accel_map_file() -> PathBuf {
+    std::env::temp_dir().join("gtk-rs-accel-map-example")
+}
+
+fn accelerator_label(accel_path: &str) -> String {
+    match gtk::AccelMap::lookup_entry(accel_path) {
+        Some((key, mods)) if key != 0 => gtk::accelerator_get_label(key, mods)
+            .map(String::from)
+            .unwrap_or_default(),
+        _ => String::from("disabled"),
+    }
+}
+
+fn main() {
+    gtk::init().expect("Failed to initialize GTK");
+
+    // The default accelerators, replaced by the ones found in the saved file.
+    for &(accel_path, accelerator) in &[
+        (COUNT_PATH, "<Primary>plus"),
+        (RESET_PATH, "<Primary>0"),
+        (QUIT_PATH, "<Primary>q"),
+    ] {
+        let (key, mods) = gtk::accelerator_parse(accelerator);
+        gtk::AccelMap::add_entry(accel_path, key, mods);
+    }
+    gtk::AccelMap::load(accel_map_file());
+
+    let window = gtk::Window::new(gtk::WindowType::Toplevel);
+    window.set_title("Accel map example");
+    window.set_position(gtk::WindowPosition::Center);
+    window.set_default_size(350, 150);
+
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 6);
+    vbox.set_border_width(12);
+    let counter = gtk::Label::new(Some("0"));
+    let shortcuts = gtk::Label::new(None);
+    let swap = gtk::Button::with_label("Swap the count and reset shortcuts");
+    vbox.pack_start(&counter, true, true, 0);
+    vbox.pack_start(&shortcuts, false, false, 0);
+    vbox.pack_start(&swap, false, false, 0);
+    window.add(&vbox);
+
+    let update_shortcuts = {
+        let shortcuts = shortcuts.clone();
+        move || {
+            shortcuts.set_text(&format!(
+                "Count: {}  Reset: {}  Quit: {}",
+                accelerator_label(COUNT_PATH),
+                accelerator_label(RESET_PATH),
+                accelerator_label(QUIT_PATH),
+            ));
+        }
+    };
+    update_shortcuts();
+    // The accel groups connected by path follow the changes of the map on their own.
+    gtk::AccelMap::get().connect_changed(move |_, _, _, _| update_shortcuts());
+
+    let accel_group = gtk::AccelGroup::new();
+    window.add_accel_group(&accel_group);
+    let count = counter.clone();
+    accel_group.connect_accel_group_by_path(COUNT_PATH, move |_, _, _, _| {
+        let value = count.get_text().parse::<u32>().unwrap_or(0);
+        count.set_text(&(value + 1).to_string());
+        true
+    });
+    accel_group.connect_accel_group_by_path(RESET_PATH, move |_, _, _, _| {
+        counter.set_text("0");
+        true
+    });
+    accel_group.connect_accel_group_by_path(QUIT_PATH, |_, _, _, _| {
+        gtk::main_quit();
+        true
+    });
+
+    swap.connect_clicked(|_| {
+        if let (Some((count_key, count_mods)), Some((reset_key, reset_mods))) = (
+            gtk::AccelMap::lookup_entry(COUNT_PATH),
+            gtk::AccelMap::lookup_entry(RESET_PATH),
+        ) {
+            // Each accelerator is still used by the other path, so it has to be replaced.
+            gtk::AccelMap::change_entry(COUNT_PATH, reset_key, reset_mods, true);
+            gtk::AccelMap::change_entry(RESET_PATH, count_key, count_mods, true);
+        }
+    });
+
+    window.connect_delete_event(|_, _| {
+        gtk::main_quit();
+        Inhibit(false)
+    });
+    window.show_all();
+
+    gtk::main();
+
+    // Only the accelerators changed from their default are saved uncommented.
+    gtk::AccelMap::save(accel_map_file());
+}
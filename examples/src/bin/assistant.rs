@@ -0,0 +1,110 @@
+//! # Assistant example
+//!
+//! This sample shows a multi-step wizard built with a `gtk::Assistant`: pages become complete
+//! as they are filled, an optional page is skipped with a forward page function, and the
+//! settings are applied from the confirmation page.
+
+use gio::prelude::*;
+use glib::clone;
+use gtk::prelude::*;
+
+use std::env::args;
+
+// The indices of the pages, in the order they are appended.
+const NAME_PAGE: i32 = 1;
+const CONFIRM_PAGE: i32 = 3;
+
+fn build_ui(application: &gtk::Application) {
+    let assistant = gtk::Assistant::new();
+    assistant.set_application(Some(application));
+    assistant.set_title("Assistant example");
+    assistant.set_position(gtk::WindowPosition::Center);
+    assistant.set_default_size(450, 300);
+
+    let intro = gtk::Label::new(Some("This assistant creates a new project."));
+    assistant.append_page(&intro);
+    assistant.set_page_type(&intro, gtk::AssistantPageType::Intro);
+    assistant.set_page_title(&intro, "Welcome");
+    assistant.set_page_complete(&intro, true);
+
+    // The name is required, so the page is only complete once it was entered.
+    let name_page = gtk::Box::new(gtk::Orientation::Vertical, 6);
+    let name = gtk::Entry::new();
+    name.set_placeholder_text(Some("Project name"));
+    name_page.pack_start(&name, false, false, 0);
+    let with_details = gtk::CheckButton::with_label("Configure the details");
+    name_page.pack_start(&with_details, false, false, 0);
+    assistant.append_page(&name_page);
+    assistant.set_page_title(&name_page, "Name");
+    name.connect_changed(clone!(@weak assistant, @weak name_page => move |name| {
+        assistant.set_page_complete(&name_page, !name.get_text().is_empty());
+    }));
+
+    let details_page = gtk::Box::new(gtk::Orientation::Vertical, 6);
+    let license = gtk::ComboBoxText::new();
+    for id in &["MIT", "Apache-2.0", "GPL-3.0"] {
+        license.append(Some(id), id);
+    }
+    license.set_active_id(Some("MIT"));
+    details_page.pack_start(&license, false, false, 0);
+    assistant.append_page(&details_page);
+    assistant.set_page_title(&details_page, "Details");
+    assistant.set_page_complete(&details_page, true);
+
+    let confirm = gtk::Label::new(None);
+    assistant.append_page(&confirm);
+    assistant.set_page_type(&confirm, gtk::AssistantPageType::Confirm);
+    assistant.set_page_title(&confirm, "Confirm");
+    assistant.set_page_complete(&confirm, true);
+
+    let summary = gtk::Label::new(None);
+    assistant.append_page(&summary);
+    assistant.set_page_type(&summary, gtk::AssistantPageType::Summary);
+    assistant.set_page_title(&summary, "Done");
+
+    // The details page is skipped unless it was asked for.
+    assistant.set_forward_page_func(Some(Box::new(
+        clone!(@strong with_details => move |current| {
+            if current == NAME_PAGE && !with_details.get_active() {
+                CONFIRM_PAGE
+            } else {
+                current + 1
+            }
+        }),
+    )));
+
+    // Each page is updated right before it is shown.
+    assistant.connect_prepare(
+        clone!(@weak name, @weak license, @weak confirm => move |assistant, _page| {
+            if assistant.get_current_page() == CONFIRM_PAGE {
+                confirm.set_text(&format!(
+                    "Create \"{}\" under the {} license?",
+                    name.get_text(),
+                    license.get_active_id().as_deref().unwrap_or("MIT")
+                ));
+            }
+        }),
+    );
+    // Emitted when the confirmation page is accepted, before going to the summary.
+    assistant.connect_apply(clone!(@weak name, @weak summary => move |_| {
+        summary.set_text(&format!("The project \"{}\" was created.", name.get_text()));
+    }));
+    assistant.connect_cancel(|assistant| assistant.close());
+    assistant.connect_close(|assistant| assistant.close());
+
+    assistant.show_all();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.assistant"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        build_ui(app);
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
@@ -0,0 +1,176 @@
+//! # Cell renderers example
+//!
+//! This sample shows a list of fake downloads using the pixbuf, spinner and progress cell
+//! renderers, with their properties bound to the columns of a `gtk::ListStore`.
+
+use gio::prelude::*;
+use glib::clone;
+use gtk::prelude::*;
+
+use std::env::args;
+use std::time::Duration;
+
+#[derive(Debug)]
+#[repr(i32)]
+enum Columns {
+    IconName,
+    Name,
+    Active,
+    Pulse,
+    Progress,
+    Status,
+}
+
+fn create_model() -> gtk::ListStore {
+    let model = gtk::ListStore::new(&[
+        String::static_type(),
+        String::static_type(),
+        bool::static_type(),
+        u32::static_type(),
+        i32::static_type(),
+        String::static_type(),
+    ]);
+    for (icon_name, name) in &[
+        ("text-x-generic", "notes.txt"),
+        ("image-x-generic", "holidays.png"),
+        ("audio-x-generic", "song.ogg"),
+        ("video-x-generic", "movie.webm"),
+        ("package-x-generic", "sources.tar.gz"),
+    ] {
+        model.insert_with_values(
+            None,
+            &[
+                Columns::IconName as u32,
+                Columns::Name as u32,
+                Columns::Active as u32,
+                Columns::Pulse as u32,
+                Columns::Progress as u32,
+                Columns::Status as u32,
+            ],
+            &[icon_name, name, &true, &0u32, &0i32, &"Waiting"],
+        );
+    }
+    model
+}
+
+fn create_view(model: &gtk::ListStore) -> gtk::TreeView {
+    let tree = gtk::TreeView::with_model(model);
+
+    let column = gtk::TreeViewColumn::new();
+    column.set_title("File");
+    column.set_expand(true);
+    let icon = gtk::CellRendererPixbuf::new();
+    column.pack_start(&icon, false);
+    column.add_attribute(&icon, "icon-name", Columns::IconName as i32);
+    let name = gtk::CellRendererText::new();
+    column.pack_start(&name, true);
+    column.add_attribute(&name, "text", Columns::Name as i32);
+    tree.append_column(&column);
+
+    // The spinner only moves when its pulse changes, and is hidden while inactive.
+    let column = gtk::TreeViewColumn::new();
+    let spinner = gtk::CellRendererSpinner::new();
+    column.pack_start(&spinner, false);
+    column.add_attribute(&spinner, "active", Columns::Active as i32);
+    column.add_attribute(&spinner, "pulse", Columns::Pulse as i32);
+    tree.append_column(&column);
+
+    // The text, if any, is shown over the bar instead of the percentage.
+    let column = gtk::TreeViewColumn::new();
+    column.set_title("Progress");
+    column.set_min_width(150);
+    let progress = gtk::CellRendererProgress::new();
+    column.pack_start(&progress, true);
+    column.add_attribute(&progress, "value", Columns::Progress as i32);
+    column.add_attribute(&progress, "text", Columns::Status as i32);
+    tree.append_column(&column);
+
+    tree
+}
+
+// Advances the fake downloads, one after the other.
+fn tick(model: &gtk::ListStore) {
+    let iter = match model.get_iter_first() {
+        Some(iter) => iter,
+        None => return,
+    };
+    let mut downloading = false;
+    loop {
+        let active = model
+            .get_value(&iter, Columns::Active as i32)
+            .get_some::<bool>()
+            .unwrap();
+        if active {
+            let pulse = model
+                .get_value(&iter, Columns::Pulse as i32)
+                .get_some::<u32>()
+                .unwrap();
+            model.set_value(&iter, Columns::Pulse as u32, &(pulse + 1).to_value());
+
+            if !downloading {
+                downloading = true;
+                let progress = model
+                    .get_value(&iter, Columns::Progress as i32)
+                    .get_some::<i32>()
+                    .unwrap()
+                    + 1;
+                if progress >= 100 {
+                    model.set(
+                        &iter,
+                        &[
+                            Columns::Active as u32,
+                            Columns::Progress as u32,
+                            Columns::Status as u32,
+                        ],
+                        &[&false, &100i32, &"Done"],
+                    );
+                } else {
+                    model.set(
+                        &iter,
+                        &[Columns::Progress as u32, Columns::Status as u32],
+                        &[&progress, &format!("{} %", progress)],
+                    );
+                }
+            }
+        }
+        if !model.iter_next(&iter) {
+            break;
+        }
+    }
+}
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_title("Cell renderers example");
+    window.set_position(gtk::WindowPosition::Center);
+    window.set_default_size(400, 250);
+
+    let model = create_model();
+    let tree = create_view(&model);
+    window.add(&tree);
+
+    glib::timeout_add_local(
+        Duration::from_millis(50),
+        clone!(@weak model => @default-return glib::Continue(false), move || {
+            tick(&model);
+            glib::Continue(true)
+        }),
+    );
+
+    window.show_all();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.cell_renderers"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        build_ui(app);
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
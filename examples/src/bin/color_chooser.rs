@@ -0,0 +1,86 @@
+//! # Color chooser example
+//!
+//! This sample shows a `gtk::ColorChooserWidget` offering custom palettes instead of the default
+//! one, with the editor to pick any other color, and a swatch showing the activated color.
+
+use gio::prelude::*;
+use glib::clone;
+use gtk::prelude::*;
+
+use std::env::args;
+
+// Each palette is laid out as columns of shades, one column per hue.
+const BRAND: &[&str] = &[
+    "#0b3c5d", "#328cc1", "#a3d1f0", "#d9b310", "#f2d46f", "#faeec5", "#1d2731", "#6b7a8f",
+    "#c5ced9",
+];
+const ACCENTS: &[&str] = &["#c0392b", "#e67e22", "#27ae60", "#8e44ad"];
+
+fn parse_colors(colors: &[&str]) -> Vec<gdk::RGBA> {
+    colors
+        .iter()
+        .map(|color| color.parse().expect("Invalid color"))
+        .collect()
+}
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_title("Color chooser example");
+    window.set_position(gtk::WindowPosition::Center);
+
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 6);
+    vbox.set_border_width(6);
+
+    let chooser = gtk::ColorChooserWidget::new();
+    chooser.set_use_alpha(false);
+    chooser.remove_palettes();
+    chooser.add_palette(gtk::Orientation::Vertical, 3, &parse_colors(BRAND));
+    chooser.add_palette(gtk::Orientation::Horizontal, 4, &parse_colors(ACCENTS));
+    vbox.pack_start(&chooser, true, true, 0);
+
+    let custom = gtk::CheckButton::with_label("Custom color");
+    vbox.pack_start(&custom, false, false, 0);
+
+    let swatch = gtk::DrawingArea::new();
+    swatch.set_size_request(-1, 48);
+    vbox.pack_start(&swatch, false, false, 0);
+
+    swatch.connect_draw(
+        clone!(@weak chooser => @default-return Inhibit(false), move |_, cr| {
+            let color = chooser.get_rgba();
+            cr.set_source_rgba(color.red, color.green, color.blue, color.alpha);
+            cr.paint();
+            Inhibit(false)
+        }),
+    );
+    // Double-clicking a swatch, or pressing Enter in the editor, activates the color.
+    chooser.connect_color_activated(clone!(@weak swatch, @weak custom => move |_, color| {
+        println!("Activated {}", color);
+        custom.set_active(false);
+        swatch.queue_draw();
+    }));
+    chooser.connect_property_show_editor_notify(clone!(@weak custom => move |chooser| {
+        custom.set_active(chooser.get_property_show_editor());
+    }));
+    custom.connect_toggled(clone!(@weak chooser => move |custom| {
+        chooser.set_property_show_editor(custom.get_active());
+    }));
+
+    window.add(&vbox);
+    window.show_all();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.color_chooser"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        build_ui(app);
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
@@ -0,0 +1,71 @@
+//! # Command line example
+//!
+//! This sample shows how a single-instance application gets the arguments of every invocation:
+//! with `HANDLES_COMMAND_LINE`, running the program again while it is already running sends
+//! the arguments to the first instance through the `command-line` signal, and the second
+//! process exits with the status set there.
+//!
+//! Run it, then run it again with a few arguments, e.g. `command_line foo bar`.
+
+use gio::prelude::*;
+use gtk::prelude::*;
+
+use std::cell::RefCell;
+use std::env::args;
+
+fn build_ui(application: &gtk::Application) -> gtk::ListBox {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_title("Command line example");
+    window.set_position(gtk::WindowPosition::Center);
+    window.set_default_size(350, 200);
+
+    let list_box = gtk::ListBox::new();
+    let scrolled_window = gtk::ScrolledWindow::new(gtk::NONE_ADJUSTMENT, gtk::NONE_ADJUSTMENT);
+    scrolled_window.add(&list_box);
+    window.add(&scrolled_window);
+    window.show_all();
+
+    list_box
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.command_line"),
+        gio::ApplicationFlags::HANDLES_COMMAND_LINE,
+    )
+    .expect("Initialization failed...");
+
+    // Only the first instance emits `command-line`, the list is created there.
+    let list_box = RefCell::new(None);
+    application.connect_command_line(move |app, command_line| {
+        let list_box = list_box
+            .borrow_mut()
+            .get_or_insert_with(|| build_ui(app))
+            .clone();
+
+        let arguments = command_line
+            .get_arguments()
+            .iter()
+            .skip(1)
+            .map(|argument| argument.to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        let origin = if command_line.get_is_remote() {
+            "another process"
+        } else {
+            "this process"
+        };
+        let label = gtk::Label::new(Some(&format!("From {}: {}", origin, arguments.join(" "))));
+        label.set_halign(gtk::Align::Start);
+        list_box.add(&label);
+        list_box.show_all();
+
+        command_line.print(&format!(
+            "Got {} argument(s) in the running instance\n",
+            arguments.len()
+        ));
+        0
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
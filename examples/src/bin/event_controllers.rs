@@ -0,0 +1,139 @@
+//! # Event controllers example
+//!
+//! This sample shows the GTK 3.24 event controllers, which replace the `*-event` signals of
+//! the widgets: the pointer is followed with a `gtk::EventControllerMotion`, the view is panned
+//! by scrolling with a `gtk::EventControllerScroll`, and the dot is moved with the arrow keys
+//! with a `gtk::EventControllerKey`.
+
+use gio::prelude::*;
+use glib::clone;
+use gtk::prelude::*;
+
+use std::cell::RefCell;
+use std::env::args;
+use std::rc::Rc;
+
+const STEP: f64 = 10.0;
+
+#[derive(Default)]
+struct State {
+    pointer: Option<(f64, f64)>,
+    dot: (f64, f64),
+    offset: (f64, f64),
+}
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_title("Event controllers example");
+    window.set_position(gtk::WindowPosition::Center);
+    window.set_default_size(400, 300);
+
+    let area = gtk::DrawingArea::new();
+    area.add_events(
+        gdk::EventMask::POINTER_MOTION_MASK
+            | gdk::EventMask::ENTER_NOTIFY_MASK
+            | gdk::EventMask::LEAVE_NOTIFY_MASK
+            | gdk::EventMask::SMOOTH_SCROLL_MASK
+            | gdk::EventMask::KEY_PRESS_MASK,
+    );
+    // The key controller only gets the key presses while the widget has the focus.
+    area.set_can_focus(true);
+    window.add(&area);
+
+    let state = Rc::new(RefCell::new(State {
+        dot: (200.0, 150.0),
+        ..State::default()
+    }));
+
+    area.set_draw_func(clone!(@strong state => move |_, cr, width, height| {
+        let state = state.borrow();
+        if let Some((x, y)) = state.pointer {
+            cr.set_source_rgb(0.6, 0.6, 0.6);
+            cr.move_to(x, 0.0);
+            cr.line_to(x, f64::from(height));
+            cr.move_to(0.0, y);
+            cr.line_to(f64::from(width), y);
+            cr.stroke();
+        }
+        cr.translate(state.offset.0, state.offset.1);
+        cr.arc(state.dot.0, state.dot.1, STEP, 0.0, 2.0 * std::f64::consts::PI);
+        cr.set_source_rgb(0.8, 0.2, 0.2);
+        cr.fill();
+    }));
+
+    let motion = gtk::EventControllerMotion::new(&area);
+    motion.connect_enter(clone!(@weak area, @strong state => move |_, x, y| {
+        state.borrow_mut().pointer = Some((x, y));
+        area.queue_draw();
+    }));
+    motion.connect_motion(clone!(@weak area, @strong state => move |_, x, y| {
+        state.borrow_mut().pointer = Some((x, y));
+        area.queue_draw();
+    }));
+    motion.connect_leave(clone!(@weak area, @strong state => move |_| {
+        state.borrow_mut().pointer = None;
+        area.queue_draw();
+    }));
+
+    // Kinetic scrolling keeps panning for a while after the fingers left a touchpad, which is
+    // reported by the `decelerate` signal.
+    let scroll = gtk::EventControllerScroll::new(
+        &area,
+        gtk::EventControllerScrollFlags::BOTH_AXES | gtk::EventControllerScrollFlags::KINETIC,
+    );
+    scroll.connect_scroll(clone!(@weak area, @strong state => move |_, dx, dy| {
+        let mut state = state.borrow_mut();
+        state.offset.0 -= dx * STEP;
+        state.offset.1 -= dy * STEP;
+        area.queue_draw();
+    }));
+    scroll.connect_decelerate(|_, velocity_x, velocity_y| {
+        println!("Decelerating at {:.0}×{:.0} px/s", velocity_x, velocity_y);
+    });
+
+    let key = gtk::EventControllerKey::new(&area);
+    key.connect_key_pressed(
+        clone!(@weak area, @strong state => @default-return false, move |_, keyval, _, modifiers| {
+            let step = if modifiers.contains(gdk::ModifierType::SHIFT_MASK) {
+                STEP * 5.0
+            } else {
+                STEP
+            };
+            let mut state = state.borrow_mut();
+            match gdk::keys::Key::from(keyval) {
+                gdk::keys::constants::Left => state.dot.0 -= step,
+                gdk::keys::constants::Right => state.dot.0 += step,
+                gdk::keys::constants::Up => state.dot.1 -= step,
+                gdk::keys::constants::Down => state.dot.1 += step,
+                gdk::keys::constants::Escape => state.offset = (0.0, 0.0),
+                // Other keys are left to the widget, e.g. Tab to move the focus.
+                _ => return false,
+            }
+            area.queue_draw();
+            true
+        }),
+    );
+
+    // The widget doesn't keep the controllers alive on its own.
+    area.keep_controller(&motion);
+    area.keep_controller(&scroll);
+    area.keep_controller(&key);
+
+    window.show_all();
+    area.grab_focus();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.event_controllers"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        build_ui(app);
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
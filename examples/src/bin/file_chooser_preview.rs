@@ -0,0 +1,95 @@
+//! # File chooser preview example
+//!
+//! This sample shows a file chooser with a thumbnail pane next to the file list, updated from
+//! the `update-preview` signal, and a filter accepting images by their content type.
+
+use gio::prelude::*;
+use glib::clone;
+use gtk::prelude::*;
+
+use std::env::args;
+
+const PREVIEW_SIZE: i32 = 160;
+
+fn create_image_filter() -> gtk::FileFilter {
+    let filter = gtk::FileFilter::new();
+    filter.set_name(Some("Images"));
+    // The MIME type is sniffed from the content of the file, so images without an extension
+    // are shown too.
+    filter.add_custom(
+        gtk::FileFilterFlags::MIME_TYPE,
+        |info| matches!(info.get_mime_type(), Some(mime_type) if mime_type.starts_with("image/")),
+    );
+    filter
+}
+
+fn open_file_chooser(window: &gtk::ApplicationWindow, image: &gtk::Image) {
+    let file_chooser = gtk::FileChooserDialog::new(
+        Some("Open Image"),
+        Some(window),
+        gtk::FileChooserAction::Open,
+    );
+    file_chooser.add_buttons(&[
+        ("Open", gtk::ResponseType::Ok),
+        ("Cancel", gtk::ResponseType::Cancel),
+    ]);
+    file_chooser.add_filter(&create_image_filter());
+
+    let preview = gtk::Image::new();
+    preview.set_size_request(PREVIEW_SIZE, -1);
+    file_chooser.set_preview_widget(&preview);
+    // The preview is hidden while the selection isn't an image the pane can show.
+    file_chooser.connect_update_preview(clone!(@weak preview => move |file_chooser| {
+        let thumbnail = file_chooser.get_preview_filename().and_then(|filename| {
+            gdk_pixbuf::Pixbuf::from_file_at_scale(filename, PREVIEW_SIZE, PREVIEW_SIZE, true).ok()
+        });
+        preview.set_from_pixbuf(thumbnail.as_ref());
+        file_chooser.set_preview_widget_active(thumbnail.is_some());
+    }));
+
+    file_chooser.connect_response(clone!(@weak image => move |file_chooser, response| {
+        if response == gtk::ResponseType::Ok {
+            if let Some(filename) = file_chooser.get_filename() {
+                image.set_from_file(filename);
+            }
+        }
+        file_chooser.close();
+    }));
+
+    file_chooser.show_all();
+}
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_title("File chooser preview example");
+    window.set_position(gtk::WindowPosition::Center);
+    window.set_default_size(400, 300);
+
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 6);
+    let button = gtk::Button::with_label("Open image…");
+    vbox.pack_start(&button, false, false, 0);
+    let image = gtk::Image::new();
+    vbox.pack_start(&image, true, true, 0);
+    window.add(&vbox);
+
+    button.connect_clicked(clone!(@weak window, @weak image => move |_| {
+        open_file_chooser(&window, &image);
+    }));
+
+    window.show_all();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.file_chooser_preview"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        build_ui(app);
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
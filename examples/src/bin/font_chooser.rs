@@ -0,0 +1,102 @@
+//! # Font chooser example
+//!
+//! This sample shows a `gtk::FontChooserWidget` restricted with a filter function, here to
+//! monospace fonts, and a preview applying the chosen font together with its OpenType features.
+
+use gio::prelude::*;
+use glib::clone;
+use gtk::prelude::*;
+use pango::prelude::*;
+
+use std::env::args;
+
+const PREVIEW_TEXT: &str = "fn main() { println!(\"0O 1lI ff fi -> != <=\"); }";
+
+// The filter is called for each face of each family, families without any accepted face are
+// hidden.
+fn update_filter(chooser: &gtk::FontChooserWidget, monospace_only: bool) {
+    if monospace_only {
+        chooser.set_filter_func(Some(Box::new(|family, _face| family.is_monospace())));
+    } else {
+        chooser.set_filter_func(None);
+    }
+}
+
+fn update_preview(chooser: &gtk::FontChooserWidget, preview: &gtk::Label) {
+    let font = chooser
+        .get_font_desc()
+        .map(|desc| desc.to_string())
+        .unwrap_or_default();
+    // The features are given in the CSS syntax, e.g. `"liga" 0, "zero" 1`.
+    let features = chooser
+        .get_font_features()
+        .map(|features| features.to_string())
+        .unwrap_or_default();
+    preview.set_markup(&format!(
+        "<span font=\"{}\" font_features=\"{}\">{}</span>",
+        glib::markup_escape_text(&font),
+        glib::markup_escape_text(&features),
+        glib::markup_escape_text(PREVIEW_TEXT)
+    ));
+    preview.set_tooltip_text(Some(&format!("{}\n{}", font, features)));
+}
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_title("Font chooser example");
+    window.set_position(gtk::WindowPosition::Center);
+    window.set_default_size(500, 600);
+
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 6);
+    vbox.set_border_width(6);
+
+    let monospace_only = gtk::CheckButton::with_label("Monospace fonts only");
+    monospace_only.set_active(true);
+    vbox.pack_start(&monospace_only, false, false, 0);
+
+    let chooser = gtk::FontChooserWidget::new();
+    // The features page lets ligatures or slashed zeros be toggled, for the language used by
+    // the preview text.
+    chooser.set_level(
+        gtk::FontChooserLevel::STYLE
+            | gtk::FontChooserLevel::SIZE
+            | gtk::FontChooserLevel::FEATURES,
+    );
+    chooser.set_language("en");
+    chooser.set_preview_text(PREVIEW_TEXT);
+    update_filter(&chooser, monospace_only.get_active());
+    vbox.pack_start(&chooser, true, true, 0);
+
+    let preview = gtk::Label::new(None);
+    preview.set_selectable(true);
+    vbox.pack_start(&preview, false, false, 0);
+    update_preview(&chooser, &preview);
+
+    monospace_only.connect_toggled(clone!(@weak chooser => move |monospace_only| {
+        update_filter(&chooser, monospace_only.get_active());
+    }));
+    chooser.connect_property_font_desc_notify(clone!(@weak preview => move |chooser| {
+        update_preview(chooser, &preview);
+    }));
+    chooser.connect_property_font_features_notify(clone!(@weak preview => move |chooser| {
+        update_preview(chooser, &preview);
+    }));
+
+    window.add(&vbox);
+    window.show_all();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.font_chooser"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        build_ui(app);
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
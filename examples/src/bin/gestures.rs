@@ -0,0 +1,160 @@
+//! # Gestures example
+//!
+//! This sample shows how to handle pointer and touch input with gestures instead of raw events:
+//! a square can be dragged, pinched to zoom, rotated with two fingers and reset with a double
+//! click, and swipes print their velocity.
+
+use gio::prelude::*;
+use glib::clone;
+use gtk::prelude::*;
+
+use std::cell::RefCell;
+use std::env::args;
+use std::rc::Rc;
+
+const SIZE: f64 = 100.0;
+
+#[derive(Clone, Copy, Debug)]
+struct Transform {
+    x: f64,
+    y: f64,
+    scale: f64,
+    angle: f64,
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Transform {
+            x: 150.0,
+            y: 150.0,
+            scale: 1.0,
+            angle: 0.0,
+        }
+    }
+}
+
+impl Transform {
+    fn contains(&self, x: f64, y: f64) -> bool {
+        let distance = (x - self.x).hypot(y - self.y);
+        distance <= SIZE / 2.0 * self.scale
+    }
+}
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_title("Gestures example");
+    window.set_position(gtk::WindowPosition::Center);
+    window.set_default_size(300, 300);
+
+    let area = gtk::DrawingArea::new();
+    // The gestures need the events of the widget.
+    area.add_events(
+        gdk::EventMask::BUTTON_PRESS_MASK
+            | gdk::EventMask::BUTTON_RELEASE_MASK
+            | gdk::EventMask::POINTER_MOTION_MASK
+            | gdk::EventMask::TOUCH_MASK,
+    );
+    window.add(&area);
+
+    let transform = Rc::new(RefCell::new(Transform::default()));
+    // The transform when the current gesture began.
+    let start = Rc::new(RefCell::new(Transform::default()));
+
+    area.connect_draw(clone!(@strong transform => move |_, cr| {
+        let transform = transform.borrow();
+        cr.translate(transform.x, transform.y);
+        cr.rotate(transform.angle);
+        cr.scale(transform.scale, transform.scale);
+        cr.rectangle(-SIZE / 2.0, -SIZE / 2.0, SIZE, SIZE);
+        cr.set_source_rgb(0.2, 0.4, 0.8);
+        cr.fill();
+        Inhibit(false)
+    }));
+
+    // Presses outside of the square are denied, so the drag never starts.
+    let drag = gtk::GestureDrag::new(&area);
+    drag.connect_drag_begin(
+        clone!(@strong transform, @strong start => move |drag, x, y| {
+            if transform.borrow().contains(x, y) {
+                *start.borrow_mut() = *transform.borrow();
+                drag.set_state(gtk::EventSequenceState::Claimed);
+            } else {
+                drag.set_state(gtk::EventSequenceState::Denied);
+            }
+        }),
+    );
+    drag.connect_drag_update(
+        clone!(@weak area, @strong transform, @strong start => move |_, dx, dy| {
+            let start = start.borrow();
+            let mut transform = transform.borrow_mut();
+            transform.x = start.x + dx;
+            transform.y = start.y + dy;
+            area.queue_draw();
+        }),
+    );
+
+    // The zoom and the rotation are grouped, so the same two touches drive both.
+    let zoom = gtk::GestureZoom::new(&area);
+    zoom.connect_begin(clone!(@strong transform, @strong start => move |_, _| {
+        *start.borrow_mut() = *transform.borrow();
+    }));
+    // The scale is relative to the distance between the touches when the gesture began.
+    zoom.connect_scale_changed(
+        clone!(@weak area, @strong transform, @strong start => move |_, scale| {
+            transform.borrow_mut().scale = (start.borrow().scale * scale).max(0.25);
+            area.queue_draw();
+        }),
+    );
+    let rotate = gtk::GestureRotate::new(&area);
+    rotate.connect_angle_changed(clone!(@weak area, @strong transform => move |_, _, delta| {
+        transform.borrow_mut().angle += delta;
+        area.queue_draw();
+    }));
+    zoom.group(&rotate);
+
+    // The double click is handled in the capture phase, before the drag sees the presses.
+    let multi_press = gtk::GestureMultiPress::new(&area);
+    multi_press.set_propagation_phase(gtk::PropagationPhase::Capture);
+    multi_press.connect_pressed(
+        clone!(@weak area, @strong transform => move |_, n_press, _, _| {
+            if n_press == 2 {
+                *transform.borrow_mut() = Transform::default();
+                area.queue_draw();
+            }
+        }),
+    );
+
+    let swipe = gtk::GestureSwipe::new(&area);
+    swipe.connect_swipe(|_, velocity_x, velocity_y| {
+        let angle = velocity_y.atan2(velocity_x).to_degrees();
+        println!(
+            "Swiped at {:.0} px/s towards {:.0}°",
+            velocity_x.hypot(velocity_y),
+            angle
+        );
+    });
+
+    // The widget doesn't keep the gestures alive on its own.
+    area.keep_controller(&drag);
+    area.keep_controller(&zoom);
+    area.keep_controller(&rotate);
+    area.keep_controller(&multi_press);
+    area.keep_controller(&swipe);
+
+    window.show_all();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.gestures"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        build_ui(app);
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
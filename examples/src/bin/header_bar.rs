@@ -0,0 +1,104 @@
+//! # Header bar example
+//!
+//! This sample shows a client-side decorated window: a `gtk::HeaderBar` set as the titlebar of
+//! the window, with buttons packed on both sides, a custom title widget, and window controls
+//! that can be moved to the other side through the decoration layout.
+
+use gio::prelude::*;
+use glib::clone;
+use gtk::prelude::*;
+
+use std::cell::Cell;
+use std::env::args;
+
+// The layout lists the buttons on the left and on the right of the colon.
+const LAYOUT_RIGHT: &str = "menu:minimize,maximize,close";
+const LAYOUT_LEFT: &str = "close,minimize,maximize:menu";
+
+fn build_title(title: &str) -> (gtk::Box, gtk::Label) {
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 0);
+    vbox.set_valign(gtk::Align::Center);
+
+    let title = gtk::Label::new(Some(title));
+    title.get_style_context().add_class("title");
+    vbox.pack_start(&title, false, false, 0);
+    let subtitle = gtk::Label::new(None);
+    subtitle.get_style_context().add_class("subtitle");
+    subtitle.set_no_show_all(true);
+    vbox.pack_start(&subtitle, false, false, 0);
+
+    (vbox, subtitle)
+}
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_position(gtk::WindowPosition::Center);
+    window.set_default_size(450, 250);
+
+    let header_bar = gtk::HeaderBar::new();
+    header_bar.set_show_close_button(true);
+    header_bar.set_decoration_layout(Some(LAYOUT_RIGHT));
+
+    // The custom title replaces the title and the subtitle of the header bar.
+    let (title, subtitle) = build_title("Header bar example");
+    header_bar.set_custom_title(Some(&title));
+
+    let new = gtk::Button::from_icon_name(Some("document-new-symbolic"), gtk::IconSize::Button);
+    new.set_tooltip_text(Some("New document"));
+    header_bar.pack_start(&new);
+    let search = gtk::ToggleButton::new();
+    search.set_image(Some(&gtk::Image::from_icon_name(
+        Some("edit-find-symbolic"),
+        gtk::IconSize::Button,
+    )));
+    header_bar.pack_end(&search);
+
+    // The header bar is drawn in place of the titlebar of the window manager.
+    window.set_titlebar(Some(&header_bar));
+
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 6);
+    vbox.set_border_width(12);
+    let search_bar = gtk::SearchBar::new();
+    let entry = gtk::SearchEntry::new();
+    search_bar.add(&entry);
+    search_bar.connect_entry(&entry);
+    vbox.pack_start(&search_bar, false, false, 0);
+    let controls_left = gtk::CheckButton::with_label("Window controls on the left");
+    vbox.pack_start(&controls_left, false, false, 0);
+    window.add(&vbox);
+
+    let documents = Cell::new(0);
+    new.connect_clicked(clone!(@weak subtitle => move |_| {
+        documents.set(documents.get() + 1);
+        subtitle.set_text(&format!("{} unsaved documents", documents.get()));
+        subtitle.show();
+    }));
+    search
+        .bind_property("active", &search_bar, "search-mode-enabled")
+        .flags(glib::BindingFlags::BIDIRECTIONAL)
+        .build();
+    controls_left.connect_toggled(clone!(@weak header_bar => move |controls_left| {
+        header_bar.set_decoration_layout(Some(if controls_left.get_active() {
+            LAYOUT_LEFT
+        } else {
+            LAYOUT_RIGHT
+        }));
+    }));
+
+    window.show_all();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.header_bar"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        build_ui(app);
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
@@ -0,0 +1,106 @@
+//! # Layout widgets example
+//!
+//! This sample shows the small layout primitives used by most stock GNOME dialogs: separators,
+//! framed sections with different shadow types, a box with a centered child and a paned
+//! container with a wide, easy to grab handle.
+
+use gio::prelude::*;
+use gtk::prelude::*;
+
+use std::env::args;
+
+fn framed_section(title: &str, shadow_type: gtk::ShadowType) -> gtk::Frame {
+    let frame = gtk::Frame::new(Some(title));
+    frame.set_shadow_type(shadow_type);
+
+    let content = gtk::Label::new(Some(&format!("{:?}", shadow_type)));
+    content.set_margin_top(6);
+    content.set_margin_bottom(6);
+    frame.add(&content);
+
+    frame
+}
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_title("Layout widgets");
+    window.set_position(gtk::WindowPosition::Center);
+    window.set_default_size(500, 300);
+
+    // A header line with a start, a centered and an end widget.
+    let header = gtk::Box::new(gtk::Orientation::Horizontal, 6);
+    header.pack_start(&gtk::Button::with_label("Cancel"), false, false, 0);
+    header.set_center_widget(Some(&gtk::Label::new(Some("Centered title"))));
+    header.pack_end(&gtk::Button::with_label("Apply"), false, false, 0);
+
+    // Framed sections, one per shadow type, separated by vertical separators.
+    let sections = gtk::Box::new(gtk::Orientation::Horizontal, 6);
+    for (i, shadow_type) in [
+        gtk::ShadowType::None,
+        gtk::ShadowType::In,
+        gtk::ShadowType::Out,
+        gtk::ShadowType::EtchedIn,
+        gtk::ShadowType::EtchedOut,
+    ]
+    .iter()
+    .enumerate()
+    {
+        if i > 0 {
+            sections.pack_start(
+                &gtk::Separator::new(gtk::Orientation::Vertical),
+                false,
+                false,
+                0,
+            );
+        }
+        sections.pack_start(
+            &framed_section(&format!("Section {}", i + 1), *shadow_type),
+            true,
+            true,
+            0,
+        );
+    }
+
+    // A paned container whose handle is wide enough to be easily grabbed.
+    let paned = gtk::Paned::new(gtk::Orientation::Horizontal);
+    paned.set_wide_handle(true);
+    paned.pack1(&gtk::Label::new(Some("Sidebar")), false, false);
+    paned.pack2(&gtk::Label::new(Some("Content")), true, false);
+    paned.set_position(150);
+
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 6);
+    vbox.set_border_width(6);
+    vbox.pack_start(&header, false, false, 0);
+    vbox.pack_start(
+        &gtk::Separator::new(gtk::Orientation::Horizontal),
+        false,
+        false,
+        0,
+    );
+    vbox.pack_start(&sections, false, false, 0);
+    vbox.pack_start(
+        &gtk::Separator::new(gtk::Orientation::Horizontal),
+        false,
+        false,
+        0,
+    );
+    vbox.pack_start(&paned, true, true, 0);
+
+    window.add(&vbox);
+    window.show_all();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.layout_widgets"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        build_ui(app);
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
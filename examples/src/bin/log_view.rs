@@ -0,0 +1,77 @@
+//! # Log view
+//!
+//! This sample shows how to lay out log entries in a `DrawingArea`: every entry is a single
+//! paragraph, so the newlines it contains are shown instead of starting new paragraphs, long
+//! words are broken at any character, and the wrapped lines of an entry are indented.
+
+use gio::prelude::*;
+use gtk::prelude::*;
+
+use std::env::args;
+
+const ENTRIES: &[&str] = &[
+    "12:00:01 INFO  server started",
+    "12:00:02 DEBUG loading /usr/share/example/a/very/long/path/without/any/space/to/break/at.conf",
+    "12:00:03 WARN  request failed:\nconnection reset by peer",
+    "12:00:04 INFO  retrying the request in 5 seconds, as configured in the settings of the client",
+];
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_title("Log view");
+    window.set_position(gtk::WindowPosition::Center);
+    window.set_default_size(400, 300);
+
+    let drawing_area = gtk::DrawingArea::new();
+    drawing_area.connect_draw(|widget, cr| {
+        let width = widget.get_allocated_width();
+        let margin = 6;
+        let indent = 4 * margin;
+
+        cr.set_source_rgb(1., 1., 1.);
+        cr.paint();
+        cr.set_source_rgb(0., 0., 0.);
+
+        let layout = widget.create_pango_layout(None);
+        layout.set_font_description(Some(&pango::FontDescription::from_string("Monospace 10")));
+        layout.set_single_paragraph_mode(true);
+        layout.set_wrap(pango::WrapMode::WordChar);
+        layout.set_width((width - 2 * margin - indent) * pango::SCALE);
+
+        let mut y = margin;
+        for entry in ENTRIES {
+            layout.set_text(entry);
+            // The lines are only read, so the read-only variant avoids invalidating the
+            // layout.
+            for (i, line) in layout.get_lines_readonly().iter().enumerate() {
+                let (_, logical) = line.get_pixel_extents();
+                let x = if i == 0 { margin } else { margin + indent };
+                // `show_layout_line` draws from the baseline of the line.
+                cr.move_to(x as f64, (y - logical.y) as f64);
+                pangocairo::show_layout_line(cr, line);
+                y += logical.height;
+            }
+            y += margin;
+        }
+
+        Inhibit(false)
+    });
+
+    window.add(&drawing_area);
+    window.show_all();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.log_view"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        build_ui(app);
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
@@ -0,0 +1,102 @@
+//! # MenuButton example
+//!
+//! This sample shows a `gtk::MenuButton` built from a `gio::MenuModel`, which can show the menu
+//! either in a popover or as a classic menu, in the direction picked below it.
+
+use gio::prelude::*;
+use glib::clone;
+use gtk::prelude::*;
+
+use std::env::args;
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_title("MenuButton example");
+    window.set_position(gtk::WindowPosition::Center);
+    window.set_default_size(350, 250);
+
+    let label = gtk::Label::new(Some("Pick something in the menu"));
+
+    window.add_simple_action(
+        "greet",
+        clone!(@weak label => move |_| label.set_text("Hello!")),
+    );
+    window.add_radio_action(
+        "color",
+        &String::from("red"),
+        clone!(@weak label => move |_, color: String| {
+            label.set_text(&format!("Color: {}", color));
+        }),
+    );
+
+    let menu = gio::MenuBuilder::new()
+        .action("_Greet", "win.greet")
+        .section(
+            Some("Color"),
+            &gio::MenuBuilder::new()
+                .action("_Red", "win.color::red")
+                .action("_Green", "win.color::green")
+                .action("_Blue", "win.color::blue")
+                .build(),
+        )
+        .build();
+
+    let menu_button = gtk::MenuButton::new();
+    menu_button.set_menu_model(Some(&menu));
+    menu_button.set_halign(gtk::Align::Center);
+
+    let use_popover = gtk::CheckButton::with_label("Use a popover");
+    menu_button
+        .bind_property("use-popover", &use_popover, "active")
+        .flags(glib::BindingFlags::BIDIRECTIONAL | glib::BindingFlags::SYNC_CREATE)
+        .build();
+
+    let direction = gtk::ComboBoxText::new();
+    for (id, name) in &[
+        ("up", "Up"),
+        ("down", "Down"),
+        ("left", "Left"),
+        ("right", "Right"),
+    ] {
+        direction.append(Some(id), name);
+    }
+    direction.connect_changed(clone!(@weak menu_button => move |direction| {
+        let arrow = match direction.get_active_id().as_ref().map(|id| id.as_str()) {
+            Some("up") => gtk::ArrowType::Up,
+            Some("left") => gtk::ArrowType::Left,
+            Some("right") => gtk::ArrowType::Right,
+            _ => gtk::ArrowType::Down,
+        };
+        // `WidgetExt` has a `set_direction` too, for the text direction.
+        MenuButtonExt::set_direction(&menu_button, arrow);
+    }));
+    direction.set_active_id(Some("down"));
+
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 12);
+    vbox.set_border_width(12);
+    vbox.pack_start(&menu_button, false, false, 0);
+    vbox.pack_start(&label, true, true, 0);
+    vbox.pack_start(&use_popover, false, false, 0);
+    vbox.pack_start(&direction, false, false, 0);
+    window.add(&vbox);
+
+    // Menus going up or down are aligned on the whole box instead of the button.
+    menu_button.set_align_widget(Some(&vbox));
+
+    window.show_all();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.menu_button"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        build_ui(app);
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
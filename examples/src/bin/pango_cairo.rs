@@ -0,0 +1,68 @@
+//! # Pango and cairo
+//!
+//! This sample shows how to draw shaped and wrapped text in a `DrawingArea` with pangocairo,
+//! instead of the toy text API of cairo, and how to use the outline of a layout as a path.
+
+use gio::prelude::*;
+use gtk::prelude::*;
+
+use std::env::args;
+
+const TEXT: &str = "Text drawn with <b>Pango</b> is shaped, wrapped and can use \
+                    <i>markup</i>: عربي, हिन्दी, 日本語.";
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_title("Pango and cairo");
+    window.set_position(gtk::WindowPosition::Center);
+    window.set_default_size(400, 300);
+
+    let drawing_area = gtk::DrawingArea::new();
+    drawing_area.connect_draw(|widget, cr| {
+        let width = widget.get_allocated_width();
+        let margin = 10.;
+
+        cr.set_source_rgb(1., 1., 1.);
+        cr.paint();
+
+        let layout = pangocairo::create_layout(cr).expect("Couldn't create layout");
+        layout.set_markup(TEXT);
+        layout.set_width((width as f64 - 2. * margin) as i32 * pango::SCALE);
+        layout.set_wrap(pango::WrapMode::WordChar);
+        layout.set_font_description(Some(&pango::FontDescription::from_string("Sans 14")));
+
+        cr.set_source_rgb(0., 0., 0.);
+        cr.move_to(margin, margin);
+        pangocairo::show_layout(cr, &layout);
+
+        // The same layout, larger and outlined.
+        let (_, text_height) = layout.get_pixel_size();
+        cr.move_to(margin, 2. * margin + text_height as f64);
+        layout.set_font_description(Some(&pango::FontDescription::from_string("Serif Bold 24")));
+        pangocairo::update_layout(cr, &layout);
+        pangocairo::layout_path(cr, &layout);
+        cr.set_source_rgb(0.2, 0.4, 0.8);
+        cr.set_line_width(1.);
+        cr.stroke();
+
+        Inhibit(false)
+    });
+
+    window.add(&drawing_area);
+    window.show_all();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.pango_cairo"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        build_ui(app);
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
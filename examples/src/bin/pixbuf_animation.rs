@@ -0,0 +1,93 @@
+//! # Pixbuf animation example
+//!
+//! This sample shows how to play an animation, e.g. a GIF, either with a `gtk::Image` or with
+//! custom drawing code stepping through the frames with a `gdk_pixbuf::PixbufAnimationIter`.
+//!
+//! Run it with the path of an animation, e.g.
+//! `cargo run --bin pixbuf_animation -- animation.gif`.
+
+use gdk::prelude::*;
+use gdk_pixbuf::prelude::*;
+use gio::prelude::*;
+use glib::clone;
+use gtk::prelude::*;
+
+use std::env::args;
+use std::time::SystemTime;
+
+// Shows the next frame once the current one was displayed long enough.
+fn schedule_next_frame(iter: &gdk_pixbuf::PixbufAnimationIter, area: &gtk::DrawingArea) {
+    // The last frame of animations which don't loop is displayed forever.
+    let delay = match iter.get_delay() {
+        Some(delay) => delay,
+        None => return,
+    };
+    glib::timeout_add_local(
+        delay,
+        clone!(@strong iter, @weak area => @default-return glib::Continue(false), move || {
+            if iter.advance(SystemTime::now()) {
+                area.queue_draw();
+            }
+            schedule_next_frame(&iter, &area);
+            glib::Continue(false)
+        }),
+    );
+}
+
+fn build_ui(application: &gtk::Application, file: &gio::File) {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_title("Pixbuf animation example");
+    window.set_position(gtk::WindowPosition::Center);
+
+    let path = file.get_path().expect("Only local files are supported");
+    let animation = match gdk_pixbuf::PixbufAnimation::from_file(&path) {
+        Ok(animation) => animation,
+        Err(err) => {
+            eprintln!("Failed to load {}: {}", path.display(), err);
+            return;
+        }
+    };
+
+    let hbox = gtk::Box::new(gtk::Orientation::Horizontal, 12);
+    hbox.set_border_width(12);
+
+    // The image plays the animation on its own.
+    let image = gtk::Image::from_animation(&animation);
+    hbox.pack_start(&image, false, false, 0);
+
+    // The drawing area shows the frames given by the iterator, twice as large.
+    let iter = animation.get_iter(Some(SystemTime::now()));
+    let area = gtk::DrawingArea::new();
+    area.set_size_request(animation.get_width() * 2, animation.get_height() * 2);
+    area.connect_draw(clone!(@strong iter => move |_, cr| {
+        cr.scale(2.0, 2.0);
+        cr.set_source_pixbuf(&iter.get_pixbuf(), 0.0, 0.0);
+        cr.paint();
+        Inhibit(false)
+    }));
+    hbox.pack_start(&area, false, false, 0);
+    schedule_next_frame(&iter, &area);
+
+    window.add(&hbox);
+    window.show_all();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.pixbuf_animation"),
+        gio::ApplicationFlags::HANDLES_OPEN,
+    )
+    .expect("Initialization failed...");
+
+    application.connect_open(|app, files, _hint| {
+        for file in files {
+            build_ui(app, file);
+        }
+    });
+    application.connect_activate(|_| {
+        eprintln!("Usage: pixbuf_animation <animation path>");
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
@@ -0,0 +1,130 @@
+//! # Popover example
+//!
+//! This sample shows a `gtk::PopoverMenu` with a named submenu opened from a menu button, and a
+//! `gtk::Popover` pointing at the location of a right click, instead of the deprecated menus.
+
+use gio::prelude::*;
+use glib::clone;
+use gtk::prelude::*;
+
+use std::env::args;
+
+fn model_button(text: &str) -> gtk::ModelButton {
+    let button = gtk::ModelButton::new();
+    button.set_property_text(Some(text));
+    button
+}
+
+fn build_menu(status: &gtk::Label) -> gtk::PopoverMenu {
+    let menu = gtk::PopoverMenu::new();
+
+    let main = gtk::Box::new(gtk::Orientation::Vertical, 0);
+    main.set_border_width(6);
+    let open = model_button("Open");
+    main.add(&open);
+    // Buttons with a menu name open the submenu of that name instead of activating.
+    let share = model_button("Share");
+    share.set_property_menu_name(Some("share"));
+    main.add(&share);
+    menu.add(&main);
+
+    let submenu = gtk::Box::new(gtk::Orientation::Vertical, 0);
+    submenu.set_border_width(6);
+    // The inverted button at the top goes back to the main menu.
+    let back = model_button("Share");
+    back.set_property_menu_name(Some("main"));
+    back.set_property_inverted(true);
+    back.set_property_centered(true);
+    submenu.add(&back);
+    let email = model_button("Email");
+    submenu.add(&email);
+    let link = model_button("Copy link");
+    submenu.add(&link);
+    menu.add(&submenu);
+    // The first child is the "main" submenu, the other ones need a name.
+    menu.set_child_submenu(&submenu, Some("share"));
+
+    for button in &[open, email, link] {
+        button.connect_clicked(clone!(@weak status => move |button| {
+            if let Some(text) = button.get_property_text() {
+                status.set_text(&format!("{} activated", text));
+            }
+        }));
+    }
+    // The menu always opens on the main submenu.
+    menu.connect_closed(|menu| menu.open_submenu("main"));
+
+    menu.show_all();
+    menu
+}
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_title("Popover example");
+    window.set_position(gtk::WindowPosition::Center);
+    window.set_default_size(400, 300);
+
+    let status = gtk::Label::new(Some("Right click anywhere below the header bar"));
+
+    let header_bar = gtk::HeaderBar::new();
+    header_bar.set_show_close_button(true);
+    header_bar.set_title(Some("Popover example"));
+    let menu_button = gtk::MenuButton::new();
+    menu_button.set_image(Some(&gtk::Image::from_icon_name(
+        Some("open-menu-symbolic"),
+        gtk::IconSize::Menu,
+    )));
+    menu_button.set_popover(Some(&build_menu(&status)));
+    header_bar.pack_end(&menu_button);
+    window.set_titlebar(Some(&header_bar));
+
+    let event_box = gtk::EventBox::new();
+    event_box.add(&status);
+    window.add(&event_box);
+
+    let popover = gtk::Popover::new(Some(&event_box));
+    popover.set_position(gtk::PositionType::Bottom);
+    popover.set_border_width(6);
+    let label = gtk::Label::new(None);
+    popover.add(&label);
+    label.show();
+
+    event_box.connect_button_press_event(
+        clone!(@weak popover, @weak label => @default-return Inhibit(false), move |_, event| {
+            if !event.triggers_context_menu() {
+                return Inhibit(false);
+            }
+            let (x, y) = event.get_position();
+            label.set_text(&format!("Clicked at {:.0}×{:.0}", x, y));
+            // The popover points at a 1×1 rectangle, relative to the event box.
+            popover.set_pointing_to(&gdk::Rectangle {
+                x: x as i32,
+                y: y as i32,
+                width: 1,
+                height: 1,
+            });
+            popover.popup();
+            Inhibit(true)
+        }),
+    );
+    popover.connect_closed(clone!(@weak status => move |_| {
+        status.set_text("Popover closed");
+    }));
+
+    window.show_all();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.popover"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        build_ui(app);
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
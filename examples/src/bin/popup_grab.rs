@@ -0,0 +1,227 @@
+//! # Popup grab example
+//!
+//! This sample shows how a custom autocomplete popup dismisses itself on clicks outside of it,
+//! like menus do: while it's shown, the popup holds a GTK grab so the other widgets of the
+//! application don't get the pointer events, and a seat grab so it gets the clicks done on
+//! other applications too. The key events it gets are forwarded to the entry.
+//!
+//! Type a few letters of a fruit name in the entry to show the suggestions.
+
+use gio::prelude::*;
+use gtk::prelude::*;
+
+use std::env::args;
+use std::rc::Rc;
+
+const FRUITS: &[&str] = &[
+    "Apple",
+    "Apricot",
+    "Banana",
+    "Blackberry",
+    "Blueberry",
+    "Cherry",
+    "Grape",
+    "Grapefruit",
+    "Lemon",
+    "Lime",
+    "Mango",
+    "Orange",
+    "Peach",
+    "Pear",
+    "Pineapple",
+    "Plum",
+];
+
+struct Completion {
+    entry: gtk::Entry,
+    popup: gtk::Window,
+    list_box: gtk::ListBox,
+}
+
+impl Completion {
+    fn new(window: &gtk::ApplicationWindow, entry: &gtk::Entry) -> Rc<Self> {
+        let popup = gtk::Window::new(gtk::WindowType::Popup);
+        popup.set_type_hint(gdk::WindowTypeHint::Combo);
+        popup.set_transient_for(Some(window));
+        popup.set_attached_to(Some(entry));
+
+        let list_box = gtk::ListBox::new();
+        list_box.set_activate_on_single_click(true);
+        let frame = gtk::Frame::new(None);
+        frame.add(&list_box);
+        popup.add(&frame);
+
+        let completion = Rc::new(Completion {
+            entry: entry.clone(),
+            popup,
+            list_box,
+        });
+
+        entry.connect_changed(glib::clone!(@weak completion => move |_| completion.update()));
+        entry.connect_key_press_event(glib::clone!(@weak completion => @default-return Inhibit(false), move |_, event| {
+            if event.get_keyval() == gdk::keys::constants::Escape && completion.popup.is_visible() {
+                completion.hide();
+                return Inhibit(true);
+            }
+            Inhibit(false)
+        }));
+
+        completion
+            .list_box
+            .connect_row_activated(glib::clone!(@weak completion => move |_, row| {
+                let label = row.get_child().unwrap().downcast::<gtk::Label>().unwrap();
+                completion.hide();
+                completion.entry.set_text(&label.get_text());
+                completion.entry.set_position(-1);
+            }));
+
+        // With the grabs, clicks outside of the popup are reported to it as well, with
+        // coordinates outside of its window.
+        completion.popup.connect_button_press_event(
+            glib::clone!(@weak completion => @default-return Inhibit(false), move |popup, event| {
+                let window = popup.get_window().unwrap();
+                let (_, x, y) = window.get_origin();
+                let (root_x, root_y) = event.get_root();
+                let (root_x, root_y) = (root_x as i32, root_y as i32);
+                if root_x < x
+                    || root_y < y
+                    || root_x >= x + window.get_width()
+                    || root_y >= y + window.get_height()
+                {
+                    completion.hide();
+                    return Inhibit(true);
+                }
+                Inhibit(false)
+            }),
+        );
+
+        // The GTK grab sends the key events to the popup, they are forwarded to the entry to go
+        // on typing, like `gtk::EntryCompletion` does.
+        let entry = entry.downgrade();
+        let forward_key = move |_: &gtk::Window, event: &gdk::EventKey| {
+            if let Some(entry) = entry.upgrade() {
+                entry.event(event);
+            }
+            Inhibit(true)
+        };
+        completion
+            .popup
+            .connect_key_press_event(forward_key.clone());
+        completion.popup.connect_key_release_event(forward_key);
+
+        // Another application or the window manager took the grab over.
+        completion.popup.connect_grab_broken_event(
+            glib::clone!(@weak completion => @default-return Inhibit(false), move |_, _| {
+                completion.hide();
+                Inhibit(false)
+            }),
+        );
+
+        completion
+    }
+
+    fn update(&self) {
+        let text = self.entry.get_text().to_lowercase();
+        for row in self.list_box.get_children() {
+            self.list_box.remove(&row);
+        }
+        if text.is_empty() {
+            self.hide();
+            return;
+        }
+
+        let matches = FRUITS
+            .iter()
+            .filter(|fruit| fruit.to_lowercase().starts_with(&text))
+            .collect::<Vec<_>>();
+        if matches.is_empty() {
+            self.hide();
+            return;
+        }
+        for fruit in matches {
+            let label = gtk::Label::new(Some(fruit));
+            label.set_halign(gtk::Align::Start);
+            label.set_margin_start(6);
+            self.list_box.add(&label);
+        }
+        self.popup.show_all();
+        self.popup.resize(self.entry.get_allocated_width(), 1);
+        self.show();
+    }
+
+    fn show(&self) {
+        let entry_window = self.entry.get_window().unwrap();
+        let allocation = self.entry.get_allocation();
+        let (_, x, y) = entry_window.get_origin();
+        self.popup
+            .move_(x + allocation.x, y + allocation.y + allocation.height);
+
+        if self.popup.has_grab() {
+            return;
+        }
+        let popup_window = self.popup.get_window().unwrap();
+        let seat = popup_window.get_display().get_default_seat().unwrap();
+        // Only the pointer is grabbed on the seat, the keyboard stays with the application.
+        let status = seat.grab(
+            &popup_window,
+            gdk::SeatCapabilities::ALL_POINTING,
+            true,
+            None,
+            None,
+            None,
+        );
+        if status != gdk::GrabStatus::Success {
+            self.popup.hide();
+            return;
+        }
+        self.popup.grab_add();
+    }
+
+    fn hide(&self) {
+        if self.popup.has_grab() {
+            self.popup.grab_remove();
+            if let Some(seat) = self.popup.get_display().get_default_seat() {
+                seat.ungrab();
+            }
+        }
+        self.popup.hide();
+    }
+}
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_title("Popup grab example");
+    window.set_position(gtk::WindowPosition::Center);
+    window.set_default_size(350, 70);
+
+    let entry = gtk::Entry::new();
+    entry.set_placeholder_text(Some("Fruit"));
+    entry.set_margin_top(12);
+    entry.set_margin_start(12);
+    entry.set_margin_end(12);
+    entry.set_valign(gtk::Align::Start);
+    window.add(&entry);
+
+    let completion = Completion::new(&window, &entry);
+    window.connect_configure_event(move |_, _| {
+        completion.hide();
+        false
+    });
+
+    window.show_all();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.popup_grab"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        build_ui(app);
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
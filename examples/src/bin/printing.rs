@@ -51,18 +51,16 @@ fn print(window: &gtk::Window, value1: String, value2: String) {
         pangocairo::functions::show_layout(&cairo, &pango_layout);
     });
 
-    // Handle printing asynchronously: run() will immediately return below on
-    // platforms where this is supported and once the dialog is finished the
-    // "done" signal will be emitted.
-    print_operation.set_allow_async(true);
-    print_operation.connect_done(|_, res| {
-        println!("printing done: {:?}", res);
+    // Open Print dialog setting up main window as its parent, and handle printing
+    // asynchronously: the future resolves once the dialog is finished and the pages
+    // were printed.
+    let future = print_operation.run_future(gtk::PrintOperationAction::PrintDialog, Some(window));
+    glib::MainContext::default().spawn_local(async move {
+        match future.await {
+            Ok(res) => println!("printing done: {:?}", res),
+            Err(err) => eprintln!("Couldn't print: {}", err),
+        }
     });
-
-    // Open Print dialog setting up main window as its parent
-    print_operation
-        .run(gtk::PrintOperationAction::PrintDialog, Option::from(window))
-        .expect("Couldn't print");
 }
 
 fn build_ui(application: &gtk::Application) {
@@ -0,0 +1,115 @@
+//! # Progressive image example
+//!
+//! This sample shows how to display an image while it is being loaded, as it would be when
+//! downloaded over the network, by feeding it chunk by chunk to a `gdk_pixbuf::PixbufLoader`.
+//!
+//! Run it with the path or the URI of an image, e.g.
+//! `cargo run --bin progressive_image -- picture.jpg`.
+
+use gdk_pixbuf::prelude::*;
+use gio::prelude::*;
+use glib::clone;
+use gtk::prelude::*;
+
+use std::env::args;
+use std::time::Duration;
+
+const MAX_SIZE: i32 = 800;
+
+async fn load_image(
+    file: gio::File,
+    image: gtk::Image,
+    status: gtk::Label,
+) -> Result<(), glib::Error> {
+    let loader = gdk_pixbuf::PixbufLoader::new();
+
+    // Known once the headers are parsed: large images are scaled down while being decoded.
+    loader.connect_size_prepared(|loader, width, height| {
+        let scale = f64::from(MAX_SIZE) / f64::from(width.max(height));
+        if scale < 1.0 {
+            loader.set_size(
+                (f64::from(width) * scale) as i32,
+                (f64::from(height) * scale) as i32,
+            );
+        }
+    });
+    // The pixbuf is allocated, but still empty.
+    loader.connect_area_prepared(clone!(@weak image => move |loader| {
+        image.set_from_pixbuf(loader.get_pixbuf().as_ref());
+    }));
+    // Some rows were decoded into the pixbuf shown by the image, which only has to redraw.
+    loader.connect_area_updated(clone!(@weak image => move |_, _x, _y, _width, _height| {
+        image.queue_draw();
+    }));
+
+    let stream = file.read_async_future(glib::PRIORITY_DEFAULT).await?;
+    let mut buf = vec![0; 4096];
+    let mut total = 0;
+    loop {
+        let (b, len) = stream
+            .read_async_future(buf, glib::PRIORITY_DEFAULT)
+            .await
+            .map_err(|(_buf, err)| err)?;
+        if len == 0 {
+            break;
+        }
+        buf = b;
+
+        loader.write(&buf[..len])?;
+        total += len;
+        status.set_text(&format!("{} KiB loaded", total / 1024));
+
+        // Slow down local files so that the image appears progressively.
+        glib::timeout_future(Duration::from_millis(10)).await;
+    }
+    loader.close()?;
+
+    status.set_text(&format!("Done, {} KiB", total / 1024));
+    Ok(())
+}
+
+fn build_ui(application: &gtk::Application, file: &gio::File) {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_title("Progressive image example");
+    window.set_position(gtk::WindowPosition::Center);
+    window.set_default_size(400, 300);
+
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 6);
+    let scrolled = gtk::ScrolledWindow::new(gtk::NONE_ADJUSTMENT, gtk::NONE_ADJUSTMENT);
+    scrolled.set_vexpand(true);
+    let image = gtk::Image::new();
+    scrolled.add(&image);
+    vbox.pack_start(&scrolled, true, true, 0);
+    let status = gtk::Label::new(Some("Loading…"));
+    vbox.pack_start(&status, false, false, 6);
+    window.add(&vbox);
+
+    let future = load_image(file.clone(), image, status.clone());
+    glib::MainContext::default().spawn_local(async move {
+        if let Err(err) = future.await {
+            status.set_text(&format!("Failed to load the image: {}", err));
+        }
+    });
+
+    window.show_all();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.progressive_image"),
+        gio::ApplicationFlags::HANDLES_OPEN,
+    )
+    .expect("Initialization failed...");
+
+    application.connect_open(|app, files, _hint| {
+        for file in files {
+            build_ui(app, file);
+        }
+    });
+    application.connect_activate(|_| {
+        eprintln!("Usage: progressive_image <image path or URI>");
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
@@ -0,0 +1,137 @@
+//! # Revealer example
+//!
+//! This sample shows panels animated with `gtk::Revealer`: a sidebar sliding in from the left
+//! with a configurable transition, and an in-app notification which is only cleared once it
+//! finished sliding out, using the `child-revealed` property.
+
+use gio::prelude::*;
+use glib::clone;
+use gtk::prelude::*;
+
+use std::cell::Cell;
+use std::env::args;
+
+const TRANSITIONS: &[(&str, gtk::RevealerTransitionType)] = &[
+    ("Slide", gtk::RevealerTransitionType::SlideRight),
+    ("Crossfade", gtk::RevealerTransitionType::Crossfade),
+    ("None", gtk::RevealerTransitionType::None),
+];
+
+fn build_sidebar() -> gtk::Revealer {
+    let list = gtk::Box::new(gtk::Orientation::Vertical, 6);
+    list.set_border_width(12);
+    for name in &["Documents", "Music", "Pictures", "Videos"] {
+        list.pack_start(&gtk::Label::new(Some(name)), false, false, 0);
+    }
+
+    let sidebar = gtk::Revealer::new();
+    sidebar.set_transition_type(TRANSITIONS[0].1);
+    sidebar.add(&list);
+    sidebar
+}
+
+fn build_notification() -> (gtk::Revealer, gtk::Label) {
+    let hbox = gtk::Box::new(gtk::Orientation::Horizontal, 12);
+    hbox.set_border_width(6);
+    hbox.get_style_context().add_class("app-notification");
+    let message = gtk::Label::new(None);
+    hbox.pack_start(&message, true, true, 0);
+    let dismiss = gtk::Button::with_label("Dismiss");
+    hbox.pack_start(&dismiss, false, false, 0);
+
+    let notification = gtk::Revealer::new();
+    notification.set_transition_type(gtk::RevealerTransitionType::SlideDown);
+    notification.set_halign(gtk::Align::Center);
+    notification.set_valign(gtk::Align::Start);
+    notification.add(&hbox);
+
+    dismiss.connect_clicked(clone!(@weak notification => move |_| {
+        notification.set_reveal_child(false);
+    }));
+    // `reveal-child` changes right away, while `child-revealed` changes at the end of the
+    // transition: clearing the message before would be visible while sliding out.
+    notification.connect_property_child_revealed_notify(
+        clone!(@weak message => move |notification| {
+            if !notification.get_child_revealed() {
+                message.set_text("");
+            }
+        }),
+    );
+
+    (notification, message)
+}
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_title("Revealer example");
+    window.set_position(gtk::WindowPosition::Center);
+    window.set_default_size(500, 300);
+
+    let sidebar = build_sidebar();
+    let (notification, message) = build_notification();
+
+    let controls = gtk::Grid::new();
+    controls.set_border_width(12);
+    controls.set_row_spacing(6);
+    controls.set_column_spacing(12);
+    let show_sidebar = gtk::ToggleButton::with_label("Sidebar");
+    controls.attach(&show_sidebar, 0, 0, 2, 1);
+    let transition = gtk::ComboBoxText::new();
+    for (name, _) in TRANSITIONS {
+        transition.append(Some(name), name);
+    }
+    transition.set_active(Some(0));
+    controls.attach(&gtk::Label::new(Some("Transition")), 0, 1, 1, 1);
+    controls.attach(&transition, 1, 1, 1, 1);
+    let duration = gtk::SpinButton::with_range(0.0, 2000.0, 50.0);
+    duration.set_value(f64::from(sidebar.get_transition_duration()));
+    controls.attach(&gtk::Label::new(Some("Duration (ms)")), 0, 2, 1, 1);
+    controls.attach(&duration, 1, 2, 1, 1);
+    let notify = gtk::Button::with_label("Notify");
+    controls.attach(&notify, 0, 3, 2, 1);
+
+    show_sidebar
+        .bind_property("active", &sidebar, "reveal-child")
+        .build();
+    transition.connect_changed(clone!(@weak sidebar => move |transition| {
+        if let Some(index) = transition.get_active() {
+            sidebar.set_transition_type(TRANSITIONS[index as usize].1);
+        }
+    }));
+    duration.connect_value_changed(clone!(@weak sidebar => move |duration| {
+        sidebar.set_transition_duration(duration.get_value_as_int() as u32);
+    }));
+    let count = Cell::new(0);
+    notify.connect_clicked(clone!(@weak notification, @weak message => move |_| {
+        count.set(count.get() + 1);
+        message.set_text(&format!("Notification #{}", count.get()));
+        notification.set_reveal_child(true);
+    }));
+
+    let hbox = gtk::Box::new(gtk::Orientation::Horizontal, 0);
+    hbox.pack_start(&sidebar, false, false, 0);
+    hbox.pack_start(&controls, true, true, 0);
+
+    // The notification is shown over the content of the window.
+    let overlay = gtk::Overlay::new();
+    overlay.add(&hbox);
+    overlay.add_overlay(&notification);
+
+    window.add(&overlay);
+    window.show_all();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.revealer"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        build_ui(app);
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
@@ -0,0 +1,106 @@
+//! # Scrollbar example
+//!
+//! This sample shows a `gtk::Scrollbar` for a widget doing its own scrolling: a
+//! `gtk::DrawingArea` drawing a long list of rows. The scroll position is kept in a
+//! `gtk::Adjustment` given to `gtk::Scrollbar::new`, which the drawing area reads when drawing
+//! and changes on scroll events, so that the scrollbar and the rows always match.
+
+use gio::prelude::*;
+use glib::clone;
+use gtk::prelude::*;
+
+use std::env::args;
+
+const ROWS: u32 = 1000;
+const ROW_HEIGHT: f64 = 24.0;
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_title("Scrollbar example");
+    window.set_position(gtk::WindowPosition::Center);
+    window.set_default_size(300, 400);
+
+    let upper = f64::from(ROWS) * ROW_HEIGHT;
+    let adjustment = gtk::Adjustment::new(0.0, 0.0, upper, ROW_HEIGHT, 0.0, 0.0);
+    let area = gtk::DrawingArea::new();
+    area.add_events(gdk::EventMask::SCROLL_MASK | gdk::EventMask::SMOOTH_SCROLL_MASK);
+    let scrollbar = gtk::Scrollbar::new(gtk::Orientation::Vertical, Some(&adjustment));
+
+    // A page is the height of the drawing area, `configure` keeps the value in range.
+    area.connect_size_allocate(clone!(@weak adjustment => move |_, allocation| {
+        let height = f64::from(allocation.height);
+        adjustment.configure(
+            adjustment.get_value(),
+            0.0,
+            upper,
+            ROW_HEIGHT,
+            height,
+            height,
+        );
+    }));
+
+    area.connect_draw(
+        clone!(@weak adjustment => @default-return Inhibit(false), move |area, cr| {
+            let offset = adjustment.get_value();
+            let width = f64::from(area.get_allocated_width());
+            let height = f64::from(area.get_allocated_height());
+            // Only the visible rows are drawn.
+            let first = (offset / ROW_HEIGHT) as u32;
+            let last = (((offset + height) / ROW_HEIGHT).ceil() as u32).min(ROWS);
+            for row in first..last {
+                let y = f64::from(row) * ROW_HEIGHT - offset;
+                if row % 2 == 0 {
+                    cr.set_source_rgb(0.9, 0.9, 0.9);
+                } else {
+                    cr.set_source_rgb(1.0, 1.0, 1.0);
+                }
+                cr.rectangle(0.0, y, width, ROW_HEIGHT);
+                cr.fill();
+                cr.set_source_rgb(0.0, 0.0, 0.0);
+                cr.move_to(8.0, y + ROW_HEIGHT - 8.0);
+                cr.show_text(&format!("Row {}", row + 1));
+            }
+            Inhibit(false)
+        }),
+    );
+    // Dragging the scrollbar changes the adjustment, which moves the rows.
+    adjustment.connect_value_changed(clone!(@weak area => move |_| {
+        area.queue_draw();
+    }));
+
+    // Scrolling over the rows changes the adjustment, which moves the scrollbar.
+    area.connect_scroll_event(
+        clone!(@weak adjustment => @default-return Inhibit(false), move |_, event| {
+            let rows = match event.get_direction() {
+                gdk::ScrollDirection::Up => -3.0,
+                gdk::ScrollDirection::Down => 3.0,
+                gdk::ScrollDirection::Smooth => 3.0 * event.get_delta().1,
+                _ => return Inhibit(false),
+            };
+            adjustment.set_value(adjustment.get_value() + rows * ROW_HEIGHT);
+            Inhibit(true)
+        }),
+    );
+
+    let hbox = gtk::Box::new(gtk::Orientation::Horizontal, 0);
+    hbox.pack_start(&area, true, true, 0);
+    hbox.pack_start(&scrollbar, false, false, 0);
+
+    window.add(&hbox);
+    window.show_all();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.scrollbar"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        build_ui(app);
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
@@ -0,0 +1,88 @@
+//! # Search bar example
+//!
+//! This sample shows a type-to-search list: typing anywhere in the window reveals a
+//! `gtk::SearchBar`, and the rows of a `gtk::ListBox` are filtered as the text of its
+//! `gtk::SearchEntry` changes.
+
+use gio::prelude::*;
+use glib::clone;
+use gtk::prelude::*;
+
+use std::env::args;
+
+const FRUITS: &[&str] = &[
+    "Apple",
+    "Apricot",
+    "Banana",
+    "Blueberry",
+    "Cherry",
+    "Grape",
+    "Lemon",
+    "Mango",
+    "Orange",
+    "Peach",
+    "Pear",
+    "Strawberry",
+];
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_title("Search bar example");
+    window.set_position(gtk::WindowPosition::Center);
+    window.set_default_size(300, 400);
+
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 0);
+
+    let search_bar = gtk::SearchBar::new();
+    search_bar.set_show_close_button(true);
+    let entry = gtk::SearchEntry::new();
+    search_bar.add(&entry);
+    // The search bar reveals itself when the entry gets text, and clears it when hidden.
+    search_bar.connect_entry(&entry);
+    // Typing text anywhere in the window starts a search.
+    search_bar.forward_key_presses_from(&window);
+    vbox.pack_start(&search_bar, false, false, 0);
+
+    let list_box = gtk::ListBox::new();
+    for fruit in FRUITS {
+        list_box.add(&gtk::Label::new(Some(fruit)));
+    }
+    list_box.set_filter_func(Some(Box::new(
+        clone!(@weak entry => @default-return true, move |row| {
+            let query = entry.get_text().to_lowercase();
+            row.get_child()
+                .and_then(|child| child.downcast::<gtk::Label>().ok())
+                .map_or(true, |label| label.get_text().to_lowercase().contains(&query))
+        }),
+    )));
+    let scrolled_window = gtk::ScrolledWindow::new(gtk::NONE_ADJUSTMENT, gtk::NONE_ADJUSTMENT);
+    scrolled_window.add(&list_box);
+    vbox.pack_start(&scrolled_window, true, true, 0);
+
+    // Emitted a short while after the text changed, instead of for each key press.
+    entry.connect_search_changed(clone!(@weak list_box => move |_| {
+        list_box.invalidate_filter();
+    }));
+    // Emitted when Escape is pressed in the entry.
+    entry.connect_stop_search(clone!(@weak search_bar => move |_| {
+        search_bar.set_search_mode(false);
+    }));
+
+    window.add(&vbox);
+    window.show_all();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.search_bar"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        build_ui(app);
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
@@ -0,0 +1,109 @@
+//! # Shortcuts window example
+//!
+//! This sample shows a keyboard shortcuts cheat sheet: a `gtk::ShortcutsWindow` built from
+//! sections, groups and shortcuts, and attached to the application window as its help overlay,
+//! which is then opened with `Ctrl+?`.
+
+use gio::prelude::*;
+use glib::clone;
+use gtk::prelude::*;
+
+use std::env::args;
+
+// The actions of the window, with their accelerators.
+const ACTIONS: &[(&str, &str, &str)] = &[
+    ("new", "New document", "<Primary>n"),
+    ("open", "Open a document", "<Primary>o"),
+    ("save", "Save the document", "<Primary>s"),
+];
+
+fn build_shortcuts_window() -> gtk::ShortcutsWindow {
+    let documents = gtk::ShortcutsGroupBuilder::new().title("Documents").build();
+    for (name, title, _) in ACTIONS {
+        // The accelerator shown is the one of the action, it is looked up in the application.
+        let shortcut = gtk::ShortcutsShortcutBuilder::new()
+            .title(title)
+            .action_name(&format!("win.{}", name))
+            .build();
+        documents.add(&shortcut);
+    }
+
+    let general = gtk::ShortcutsGroupBuilder::new().title("General").build();
+    general.add(
+        &gtk::ShortcutsShortcutBuilder::new()
+            .title("Keyboard shortcuts")
+            .action_name("win.show-help-overlay")
+            .build(),
+    );
+    general.add(
+        &gtk::ShortcutsShortcutBuilder::new()
+            .title("Quit")
+            .accelerator("<Primary>q")
+            .build(),
+    );
+    // Shortcuts aren't only accelerators, gestures can be listed too.
+    general.add(
+        &gtk::ShortcutsShortcutBuilder::new()
+            .title("Zoom")
+            .shortcut_type(gtk::ShortcutType::GesturePinch)
+            .build(),
+    );
+
+    let section = gtk::ShortcutsSectionBuilder::new()
+        .section_name("shortcuts")
+        .max_height(10)
+        .build();
+    section.add(&documents);
+    section.add(&general);
+
+    let shortcuts_window = gtk::ShortcutsWindowBuilder::new().modal(true).build();
+    shortcuts_window.add(&section);
+    section.show_all();
+    shortcuts_window
+}
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_title("Shortcuts window example");
+    window.set_position(gtk::WindowPosition::Center);
+    window.set_default_size(400, 200);
+
+    let status = gtk::Label::new(Some("Press Ctrl+? to show the keyboard shortcuts"));
+    window.add(&status);
+
+    for (name, title, accel) in ACTIONS {
+        let action = gio::SimpleAction::new(name, None);
+        action.connect_activate(clone!(@weak status => move |_, _| {
+            status.set_text(title);
+        }));
+        window.add_action(&action);
+        application.set_accels_for_action(&format!("win.{}", name), &[accel]);
+    }
+
+    // Setting the help overlay adds the `win.show-help-overlay` action to the window.
+    window.set_help_overlay(Some(&build_shortcuts_window()));
+    application.set_accels_for_action("win.show-help-overlay", &["<Primary>question"]);
+
+    window.show_all();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.shortcuts_window"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        build_ui(app);
+    });
+    let quit = gio::SimpleAction::new("quit", None);
+    quit.connect_activate(clone!(@weak application => move |_, _| {
+        application.quit();
+    }));
+    application.add_action(&quit);
+    application.set_accels_for_action("app.quit", &["<Primary>q"]);
+
+    application.run(&args().collect::<Vec<_>>());
+}
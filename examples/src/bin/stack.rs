@@ -0,0 +1,141 @@
+//! # Stack example
+//!
+//! This sample shows view switching with `gtk::Stack`: the top-level views are switched with a
+//! `gtk::StackSwitcher` in the header bar, the folders of the mail view with a
+//! `gtk::StackSidebar`, and the transitions of both stacks can be changed from the settings.
+
+use gio::prelude::*;
+use glib::clone;
+use gtk::prelude::*;
+
+use std::env::args;
+
+const TRANSITIONS: &[(&str, gtk::StackTransitionType)] = &[
+    ("Crossfade", gtk::StackTransitionType::Crossfade),
+    ("Slide", gtk::StackTransitionType::SlideLeftRight),
+    ("Over", gtk::StackTransitionType::OverUpDown),
+    ("None", gtk::StackTransitionType::None),
+];
+
+fn build_mail_view() -> (gtk::Box, gtk::Stack) {
+    let folders = gtk::Stack::new();
+    folders.set_transition_type(TRANSITIONS[0].1);
+    folders.set_hexpand(true);
+
+    for &(name, title) in &[("inbox", "Inbox"), ("sent", "Sent"), ("drafts", "Drafts")] {
+        let label = gtk::Label::new(Some(&format!("No messages in {}", title)));
+        folders.add_titled(&label, name, title);
+    }
+
+    // A new message marks the inbox in the sidebar until it is opened.
+    let receive = gtk::Button::with_label("Receive a message");
+    receive.set_valign(gtk::Align::End);
+    receive.set_border_width(12);
+    receive.connect_clicked(clone!(@weak folders => move |_| {
+        if let Some(inbox) = folders.get_child_by_name("inbox") {
+            if folders.get_visible_child_name().as_deref() != Some("inbox") {
+                folders.set_child_needs_attention(&inbox, true);
+            }
+        }
+    }));
+    folders.connect_property_visible_child_name_notify(|folders| {
+        if let Some(child) = folders.get_visible_child() {
+            folders.set_child_needs_attention(&child, false);
+        }
+    });
+
+    let sidebar = gtk::StackSidebar::new();
+    sidebar.set_stack(&folders);
+
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 0);
+    vbox.pack_start(&sidebar, true, true, 0);
+    vbox.pack_start(&receive, false, false, 0);
+
+    let hbox = gtk::Box::new(gtk::Orientation::Horizontal, 0);
+    hbox.pack_start(&vbox, false, false, 0);
+    hbox.pack_start(
+        &gtk::Separator::new(gtk::Orientation::Vertical),
+        false,
+        false,
+        0,
+    );
+    hbox.pack_start(&folders, true, true, 0);
+    (hbox, folders)
+}
+
+fn build_settings_view(stacks: &[gtk::Stack]) -> gtk::Grid {
+    let grid = gtk::Grid::new();
+    grid.set_border_width(12);
+    grid.set_row_spacing(6);
+    grid.set_column_spacing(12);
+
+    let transition = gtk::ComboBoxText::new();
+    for (name, _) in TRANSITIONS {
+        transition.append(Some(name), name);
+    }
+    transition.set_active(Some(0));
+    let duration = gtk::SpinButton::with_range(0.0, 2000.0, 50.0);
+    duration.set_value(f64::from(stacks[0].get_transition_duration()));
+
+    grid.attach(&gtk::Label::new(Some("Transition")), 0, 0, 1, 1);
+    grid.attach(&transition, 1, 0, 1, 1);
+    grid.attach(&gtk::Label::new(Some("Duration (ms)")), 0, 1, 1, 1);
+    grid.attach(&duration, 1, 1, 1, 1);
+
+    let stacks = stacks.to_vec();
+    transition.connect_changed(clone!(@strong stacks => move |transition| {
+        if let Some(index) = transition.get_active() {
+            for stack in &stacks {
+                stack.set_transition_type(TRANSITIONS[index as usize].1);
+            }
+        }
+    }));
+    duration.connect_value_changed(move |duration| {
+        for stack in &stacks {
+            stack.set_transition_duration(duration.get_value_as_int() as u32);
+        }
+    });
+
+    grid
+}
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_title("Stack example");
+    window.set_position(gtk::WindowPosition::Center);
+    window.set_default_size(500, 350);
+
+    let views = gtk::Stack::new();
+    views.set_transition_type(TRANSITIONS[0].1);
+
+    let (mail, folders) = build_mail_view();
+    views.add_titled(&mail, "mail", "Mail");
+    let settings = build_settings_view(&[views.clone(), folders]);
+    views.add_titled(&settings, "settings", "Settings");
+
+    // The switcher shows a button for each titled child of the stack.
+    let switcher = gtk::StackSwitcher::new();
+    switcher.set_stack(Some(&views));
+
+    let header_bar = gtk::HeaderBar::new();
+    header_bar.set_show_close_button(true);
+    header_bar.set_custom_title(Some(&switcher));
+    window.set_titlebar(Some(&header_bar));
+
+    window.add(&views);
+    window.show_all();
+    views.set_visible_child_name("mail");
+}
+
+fn main() {
+    let application =
+        gtk::Application::new(Some("com.github.gtk-rs.examples.stack"), Default::default())
+            .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        build_ui(app);
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
@@ -0,0 +1,68 @@
+//! # Tablet pad example
+//!
+//! This sample shows how to map the buttons, rings and strips of a drawing tablet pad to the
+//! actions of a window with a `gtk::PadController`. The labels given to the entries are shown
+//! by the desktop, e.g. in the on-screen help of the tablet settings.
+
+use gio::prelude::*;
+use glib::clone;
+use gtk::prelude::*;
+
+use std::env::args;
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_title("Tablet pad example");
+    window.set_position(gtk::WindowPosition::Center);
+    window.set_default_size(350, 150);
+
+    let status = gtk::Label::new(Some("Use the buttons, rings or strips of a tablet pad"));
+    window.add(&status);
+
+    for &name in &["undo", "redo"] {
+        let action = gio::SimpleAction::new(name, None);
+        action.connect_activate(clone!(@weak status => move |_, _| {
+            status.set_text(name);
+        }));
+        window.add_action(&action);
+    }
+    // Rings and strips activate their action with their position as a double parameter.
+    let brush_size = gio::SimpleAction::new("brush-size", Some(&f64::static_variant_type()));
+    brush_size.connect_activate(clone!(@weak status => move |_, value| {
+        if let Some(value) = value.and_then(|value| value.get::<f64>()) {
+            status.set_text(&format!("Brush size {:.2}", value));
+        }
+    }));
+    window.add_action(&brush_size);
+
+    // The index is the number of the button, ring or strip, and a mode of -1 matches all the
+    // modes of the pad.
+    let entries = [
+        gtk::PadActionEntry::new(gtk::PadActionType::Button, 0, -1, "Undo", "undo"),
+        gtk::PadActionEntry::new(gtk::PadActionType::Button, 1, -1, "Redo", "redo"),
+        gtk::PadActionEntry::new(gtk::PadActionType::Ring, 0, -1, "Brush size", "brush-size"),
+        gtk::PadActionEntry::new(gtk::PadActionType::Strip, 0, -1, "Brush size", "brush-size"),
+    ];
+    // Without a device, the controller handles the events of all the pads.
+    let controller = gtk::PadController::new(&window, &window, None);
+    controller.set_action_entries(&entries);
+    // The window doesn't keep the controller alive on its own.
+    window.keep_controller(&controller);
+
+    window.show_all();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.tablet_pad"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        build_ui(app);
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
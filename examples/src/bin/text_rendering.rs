@@ -0,0 +1,77 @@
+//! # Text rendering
+//!
+//! This sample shows how a custom widget can tweak the pango context it draws text with:
+//! the first line uses the context of the widget, the second one disables hinting, as for
+//! a print preview, and the third one renders at a fixed resolution of 72 DPI.
+//!
+//! The widget context is kept in sync with the screen settings by GTK, so the tweaked
+//! contexts are created with `create_pango_context` instead of changing it.
+
+use gio::prelude::*;
+use gtk::prelude::*;
+
+use std::env::args;
+
+const TEXT: &str = "The quick brown fox jumps over the lazy dog";
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_title("Text rendering");
+    window.set_position(gtk::WindowPosition::Center);
+    window.set_default_size(500, 150);
+
+    let drawing_area = gtk::DrawingArea::new();
+    drawing_area.connect_draw(|widget, cr| {
+        let margin = 10.;
+
+        cr.set_source_rgb(1., 1., 1.);
+        cr.paint();
+        cr.set_source_rgb(0., 0., 0.);
+
+        let default_layout = widget.create_pango_layout(Some(TEXT));
+
+        let mut options = cairo::FontOptions::new();
+        options.set_antialias(cairo::Antialias::Gray);
+        options.set_hint_style(cairo::HintStyle::None);
+        options.set_hint_metrics(cairo::HintMetrics::Off);
+        let unhinted = widget.create_pango_context();
+        pangocairo::context_set_font_options(&unhinted, Some(&options));
+
+        let fixed_dpi = widget.create_pango_context();
+        pangocairo::context_set_resolution(&fixed_dpi, 72.);
+
+        let mut y = margin;
+        for layout in [
+            default_layout,
+            pango::Layout::new(&unhinted),
+            pango::Layout::new(&fixed_dpi),
+        ]
+        .iter()
+        {
+            layout.set_text(TEXT);
+            cr.move_to(margin, y);
+            pangocairo::show_layout(cr, layout);
+            y += layout.get_pixel_size().1 as f64 + margin;
+        }
+
+        Inhibit(false)
+    });
+
+    window.add(&drawing_area);
+    window.show_all();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.text_rendering"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        build_ui(app);
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
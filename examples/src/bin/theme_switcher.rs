@@ -0,0 +1,118 @@
+//! # Theme switcher example
+//!
+//! This sample shows how to change the theme and the font of the whole application at runtime,
+//! with a light/dark toggle, through the properties of `gtk::Settings`.
+
+// The glob import of the gio prelude would bring `gio::SettingsExt`, which clashes with
+// `gtk::SettingsExt`, so only the traits used here are imported.
+use gio::prelude::{ApplicationExt, ApplicationExtManual};
+use glib::clone;
+use gtk::prelude::*;
+
+use std::collections::BTreeSet;
+use std::env::args;
+
+// The themes installed in the usual directories, plus the ones built in GTK.
+fn installed_themes() -> BTreeSet<String> {
+    let mut themes: BTreeSet<String> = ["Adwaita", "HighContrast"]
+        .iter()
+        .map(|name| name.to_string())
+        .collect();
+    let mut dirs = vec![glib::get_user_data_dir()];
+    dirs.extend(glib::get_system_data_dirs());
+    for dir in dirs {
+        let entries = match std::fs::read_dir(dir.join("themes")) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+        for entry in entries.filter_map(Result::ok) {
+            if entry.path().join("gtk-3.0").is_dir() {
+                themes.insert(entry.file_name().to_string_lossy().into_owned());
+            }
+        }
+    }
+    themes
+}
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_title("Theme switcher example");
+    window.set_position(gtk::WindowPosition::Center);
+
+    let settings = gtk::Settings::get_default().expect("Failed to get the default settings");
+
+    let grid = gtk::Grid::new();
+    grid.set_border_width(12);
+    grid.set_row_spacing(6);
+    grid.set_column_spacing(12);
+
+    let add_row = |row, title: &str, widget: &gtk::Widget| {
+        let label = gtk::Label::new(Some(title));
+        label.set_halign(gtk::Align::Start);
+        grid.attach(&label, 0, row, 1, 1);
+        widget.set_hexpand(true);
+        grid.attach(widget, 1, row, 1, 1);
+    };
+
+    // Most themes have a dark variant, which applications like image viewers prefer.
+    let dark = gtk::Switch::new();
+    dark.set_halign(gtk::Align::End);
+    settings
+        .bind_property("gtk-application-prefer-dark-theme", &dark, "active")
+        .flags(glib::BindingFlags::BIDIRECTIONAL | glib::BindingFlags::SYNC_CREATE)
+        .build();
+    add_row(0, "Dark theme", dark.upcast_ref());
+
+    let theme = gtk::ComboBoxText::new();
+    for name in installed_themes() {
+        theme.append(Some(&name), &name);
+    }
+    settings
+        .bind_property("gtk-theme-name", &theme, "active-id")
+        .flags(glib::BindingFlags::BIDIRECTIONAL | glib::BindingFlags::SYNC_CREATE)
+        .build();
+    add_row(1, "Theme", theme.upcast_ref());
+
+    let font = gtk::FontButton::new();
+    settings
+        .bind_property("gtk-font-name", &font, "font")
+        .flags(glib::BindingFlags::BIDIRECTIONAL | glib::BindingFlags::SYNC_CREATE)
+        .build();
+    add_row(2, "Font", font.upcast_ref());
+
+    // The settings can also change from outside, e.g. from the desktop settings.
+    let status = gtk::Label::new(None);
+    let update_status = clone!(@weak status => move |settings: &gtk::Settings| {
+        status.set_text(&format!(
+            "Using {}{}",
+            settings.get_property_gtk_theme_name().as_deref().unwrap_or(""),
+            if settings.get_property_gtk_application_prefer_dark_theme() {
+                " (dark)"
+            } else {
+                ""
+            }
+        ));
+    });
+    update_status(&settings);
+    settings.connect_property_gtk_theme_name_notify(update_status.clone());
+    settings.connect_property_gtk_application_prefer_dark_theme_notify(update_status);
+    grid.attach(&status, 0, 3, 2, 1);
+
+    window.add(&grid);
+    window.show_all();
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.theme_switcher"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        build_ui(app);
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
@@ -0,0 +1,56 @@
+//! # Window groups example
+//!
+//! This sample shows how to keep a modal dialog modal to a single document window: every
+//! document window gets its own `WindowGroup`, so the grab of a dialog only blocks the
+//! windows of its group while the other documents stay usable.
+
+use gio::prelude::*;
+use gtk::prelude::*;
+
+use std::env::args;
+
+fn build_document_window(application: &gtk::Application, index: u32) {
+    let window = gtk::ApplicationWindow::new(application);
+    window.set_title(&format!("Document {}", index));
+    window.set_default_size(300, 120);
+
+    let group = gtk::WindowGroup::new();
+    group.add_window(&window);
+
+    let button = gtk::Button::with_label("Open modal dialog");
+    button.connect_clicked(glib::clone!(@weak window, @weak group => move |_| {
+        let dialog = gtk::MessageDialog::new(
+            Some(&window),
+            gtk::DialogFlags::MODAL | gtk::DialogFlags::DESTROY_WITH_PARENT,
+            gtk::MessageType::Info,
+            gtk::ButtonsType::Close,
+            "Only this document is blocked, the other ones still react to input.",
+        );
+        group.add_window(&dialog);
+        dialog.connect_response(|dialog, _| dialog.close());
+        dialog.show_all();
+    }));
+
+    window.add(&button);
+    window.show_all();
+}
+
+fn build_ui(application: &gtk::Application) {
+    for index in 1..=2 {
+        build_document_window(application, index);
+    }
+}
+
+fn main() {
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.window_groups"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        build_ui(app);
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
@@ -0,0 +1,94 @@
+//! # XEmbed example
+//!
+//! This sample shows how to embed the window of another process with `Socket` and `Plug`.
+//! The main window holds a `Socket` and starts a second instance of this program, which puts
+//! its widgets in a `Plug` connected to the id of the socket. Closing the embedded client
+//! leaves the socket empty, and a new one can be started.
+//!
+//! XEmbed only exists on X11, so this example needs a GTK built with the X11 backend and has
+//! to be enabled with the `x11` feature.
+
+use gio::prelude::*;
+use gtk::prelude::*;
+
+use std::env::{self, args};
+use std::process::Command;
+
+const SOCKET_ID_VAR: &str = "GTK_RS_XEMBED_SOCKET_ID";
+
+fn run_plug(socket_id: gtk::xlib::Window) {
+    gtk::init().expect("Failed to initialize GTK");
+
+    let plug = gtk::Plug::new(socket_id);
+    let button = gtk::Button::with_label("Quit the embedded client");
+    button.connect_clicked(|_| gtk::main_quit());
+    plug.add(&button);
+    plug.connect_destroy(|_| gtk::main_quit());
+    plug.show_all();
+
+    gtk::main();
+}
+
+fn start_plug(socket: &gtk::Socket) {
+    let exe = env::current_exe().expect("Couldn't find the path of the example");
+    if let Err(err) = Command::new(exe)
+        .env(SOCKET_ID_VAR, socket.get_id().to_string())
+        .spawn()
+    {
+        eprintln!("Couldn't start the embedded client: {}", err);
+    }
+}
+
+fn build_ui(application: &gtk::Application) {
+    let window = gtk::ApplicationWindow::new(application);
+
+    window.set_title("XEmbed example");
+    window.set_position(gtk::WindowPosition::Center);
+    window.set_default_size(300, 150);
+
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 6);
+    let socket = gtk::Socket::new();
+    socket.set_vexpand(true);
+    let start_button = gtk::Button::with_label("Start the embedded client");
+    vbox.pack_start(&socket, true, true, 0);
+    vbox.pack_start(&start_button, false, false, 0);
+    window.add(&vbox);
+
+    socket.connect_plug_added(glib::clone!(@weak start_button => move |_| {
+        start_button.set_sensitive(false);
+    }));
+    // Returning `true` keeps the socket once the plug is gone, so that it can be reused.
+    socket.connect_plug_removed(
+        glib::clone!(@weak start_button => @default-return false, move |_| {
+            start_button.set_sensitive(true);
+            true
+        }),
+    );
+    start_button.connect_clicked(glib::clone!(@weak socket => move |_| {
+        start_plug(&socket);
+    }));
+
+    // The socket only has an id once it is realized.
+    window.show_all();
+    start_plug(&socket);
+}
+
+fn main() {
+    if let Ok(socket_id) = env::var(SOCKET_ID_VAR) {
+        let socket_id = socket_id.parse().expect("Invalid socket id");
+        run_plug(socket_id);
+        return;
+    }
+
+    let application = gtk::Application::new(
+        Some("com.github.gtk-rs.examples.xembed"),
+        Default::default(),
+    )
+    .expect("Initialization failed...");
+
+    application.connect_activate(|app| {
+        build_ui(app);
+    });
+
+    application.run(&args().collect::<Vec<_>>());
+}
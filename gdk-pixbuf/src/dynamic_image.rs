@@ -0,0 +1,114 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+//! Conversions from and to the images of the [`image`](https://crates.io/crates/image) crate.
+
+use crate::{Colorspace, Pixbuf};
+use image::{DynamicImage, ImageBuffer};
+
+impl Pixbuf {
+    // rustdoc-stripper-ignore-next
+    /// Creates a `Pixbuf` with the pixels of `image`, converted to 8 bits RGB or RGBA depending
+    /// on whether it has an alpha channel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `image` is empty, as pixbufs can't be.
+    pub fn from_dynamic_image(image: DynamicImage) -> Pixbuf {
+        let has_alpha = image.color().has_alpha();
+        let (width, height, data) = if has_alpha {
+            let image = image.into_rgba8();
+            (image.width(), image.height(), image.into_raw())
+        } else {
+            let image = image.into_rgb8();
+            (image.width(), image.height(), image.into_raw())
+        };
+        let row_stride = data.len() / height.max(1) as usize;
+        Pixbuf::from_mut_slice(
+            data,
+            Colorspace::Rgb,
+            has_alpha,
+            8,
+            width as i32,
+            height as i32,
+            row_stride as i32,
+        )
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Copies the pixels of the pixbuf into an `image::DynamicImage`, which is
+    /// `DynamicImage::ImageRgba8` if the pixbuf has an alpha channel and
+    /// `DynamicImage::ImageRgb8` otherwise.
+    pub fn to_dynamic_image(&self) -> DynamicImage {
+        let width = self.get_width() as u32;
+        let height = self.get_height() as u32;
+        let n_channels = self.get_n_channels() as usize;
+        let row_stride = self.get_rowstride() as usize;
+        let row_len = width as usize * n_channels;
+
+        // Rows are padded to the row stride, except the last one.
+        let mut data = Vec::with_capacity(row_len * height as usize);
+        let pixels = unsafe { self.get_pixels() };
+        for row in 0..height as usize {
+            let start = row * row_stride;
+            data.extend_from_slice(&pixels[start..start + row_len]);
+        }
+
+        if self.get_has_alpha() {
+            DynamicImage::ImageRgba8(ImageBuffer::from_raw(width, height, data).unwrap())
+        } else {
+            DynamicImage::ImageRgb8(ImageBuffer::from_raw(width, height, data).unwrap())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgb, RgbImage, Rgba, RgbaImage};
+
+    #[test]
+    fn rgb_round_trip() {
+        // 5 RGB pixels take 15 bytes, which pixbufs pad to a row stride of 16.
+        let image = RgbImage::from_fn(5, 3, |x, y| Rgb([x as u8 * 40, y as u8 * 60, 200]));
+
+        let pixbuf = Pixbuf::from_dynamic_image(DynamicImage::ImageRgb8(image.clone()));
+        assert!(!pixbuf.get_has_alpha());
+        assert_eq!(pixbuf.get_n_channels(), 3);
+        assert_eq!((pixbuf.get_width(), pixbuf.get_height()), (5, 3));
+        assert_eq!(pixbuf.to_dynamic_image().as_rgb8(), Some(&image));
+
+        let padded = Pixbuf::new(Colorspace::Rgb, false, 8, 5, 3).unwrap();
+        assert!(padded.get_rowstride() > 15);
+        pixbuf.copy_area(0, 0, 5, 3, &padded, 0, 0);
+        assert_eq!(padded.to_dynamic_image().as_rgb8(), Some(&image));
+    }
+
+    #[test]
+    fn rgba_round_trip() {
+        let image = RgbaImage::from_fn(3, 2, |x, y| {
+            Rgba([x as u8 * 80, y as u8 * 120, 10, (x + y) as u8 * 60])
+        });
+
+        let pixbuf = Pixbuf::from_dynamic_image(DynamicImage::ImageRgba8(image.clone()));
+        assert!(pixbuf.get_has_alpha());
+        assert_eq!(pixbuf.get_n_channels(), 4);
+        assert_eq!(pixbuf.get_rowstride(), 12);
+        assert_eq!(pixbuf.to_dynamic_image().as_rgba8(), Some(&image));
+    }
+
+    #[test]
+    fn converted_to_8_bits() {
+        // Grayscale images without alpha become RGB, with alpha RGBA.
+        let gray = DynamicImage::new_luma8(2, 2);
+        let pixbuf = Pixbuf::from_dynamic_image(gray);
+        assert_eq!(pixbuf.get_n_channels(), 3);
+        assert!(pixbuf.to_dynamic_image().as_rgb8().is_some());
+
+        let gray_alpha = DynamicImage::new_luma_a8(2, 2);
+        let pixbuf = Pixbuf::from_dynamic_image(gray_alpha);
+        assert_eq!(pixbuf.get_n_channels(), 4);
+        assert!(pixbuf.to_dynamic_image().as_rgba8().is_some());
+    }
+}
@@ -9,11 +9,15 @@ pub use ffi;
 #[allow(unused_imports)]
 mod auto;
 
+#[cfg(feature = "image")]
+mod dynamic_image;
 mod pixbuf;
 mod pixbuf_animation;
 mod pixbuf_animation_iter;
 pub mod prelude;
+mod save_option;
 
 pub use crate::auto::*;
 
 pub use self::pixbuf_animation_iter::PixbufAnimationIter;
+pub use self::save_option::SaveOption;
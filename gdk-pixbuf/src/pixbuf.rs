@@ -15,7 +15,7 @@ use std::slice;
 
 use std::future::Future;
 
-use crate::{Colorspace, Pixbuf, PixbufFormat};
+use crate::{Colorspace, Pixbuf, PixbufFormat, SaveOption};
 
 impl Pixbuf {
     pub fn from_mut_slice<T: AsMut<[u8]>>(
@@ -434,6 +434,16 @@ impl Pixbuf {
         }
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Encodes the pixbuf in memory in the `type_` format, e.g. "png" or "jpeg".
+    ///
+    /// This is `save_to_bufferv` with typed options.
+    pub fn save_to_buffer(&self, type_: &str, options: &[SaveOption]) -> Result<Vec<u8>, Error> {
+        let options = SaveOption::to_key_values(options);
+        let options = key_value_strs(&options);
+        self.save_to_bufferv(type_, &options)
+    }
+
     #[cfg(any(feature = "v2_36", feature = "dox"))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_36")))]
     pub fn save_to_streamv<P: IsA<gio::OutputStream>, Q: IsA<gio::Cancellable>>(
@@ -465,6 +475,41 @@ impl Pixbuf {
         }
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Encodes the pixbuf to `stream` in the `type_` format, e.g. "png" or "jpeg".
+    ///
+    /// This is `save_to_streamv` with typed options.
+    #[cfg(any(feature = "v2_36", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_36")))]
+    pub fn save_to_stream<P: IsA<gio::OutputStream>, Q: IsA<gio::Cancellable>>(
+        &self,
+        stream: &P,
+        type_: &str,
+        options: &[SaveOption],
+        cancellable: Option<&Q>,
+    ) -> Result<(), Error> {
+        let options = SaveOption::to_key_values(options);
+        let options = key_value_strs(&options);
+        self.save_to_streamv(stream, type_, &options, cancellable)
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Asynchronously encodes the pixbuf to `stream` in the `type_` format.
+    ///
+    /// This is `save_to_streamv_async_future` with typed options.
+    #[cfg(any(feature = "v2_36", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_36")))]
+    pub fn save_to_stream_future<P: IsA<gio::OutputStream> + Clone + 'static>(
+        &self,
+        stream: &P,
+        type_: &str,
+        options: &[SaveOption],
+    ) -> Pin<Box<dyn Future<Output = Result<(), Error>> + 'static>> {
+        let options = SaveOption::to_key_values(options);
+        let options = key_value_strs(&options);
+        self.save_to_streamv_async_future(stream, type_, &options)
+    }
+
     #[cfg(any(feature = "v2_36", feature = "dox"))]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_36")))]
     pub fn save_to_streamv_async<
@@ -531,10 +576,7 @@ impl Pixbuf {
             .collect::<Vec<(String, String)>>();
         Box::pin(gio::GioFuture::new(self, move |obj, send| {
             let cancellable = gio::Cancellable::new();
-            let options = options
-                .iter()
-                .map(|&(ref k, ref v)| (k.as_str(), v.as_str()))
-                .collect::<Vec<(&str, &str)>>();
+            let options = key_value_strs(&options);
 
             obj.save_to_streamv_async(
                 &stream,
@@ -576,3 +618,12 @@ impl Pixbuf {
         }
     }
 }
+
+// Borrows the options made by `SaveOption::to_key_values` the way the `*v` save functions take
+// them.
+fn key_value_strs(options: &[(String, String)]) -> Vec<(&str, &str)> {
+    options
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect()
+}
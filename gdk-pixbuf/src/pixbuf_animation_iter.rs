@@ -5,7 +5,7 @@
 use super::Pixbuf;
 use glib::translate::*;
 
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 
 glib::glib_wrapper! {
     pub struct PixbufAnimationIter(Object<ffi::GdkPixbufAnimationIter>);
@@ -44,6 +44,18 @@ impl PixbufAnimationIter {
         unsafe { ffi::gdk_pixbuf_animation_iter_get_delay_time(self.to_glib_none().0) }
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Returns how long the current frame should be displayed, or `None` if it should be
+    /// displayed forever, e.g. for the last frame of animations which don't loop.
+    ///
+    /// The next frame is shown by calling `advance` once this delay has elapsed.
+    pub fn get_delay(&self) -> Option<Duration> {
+        match self.get_delay_time() {
+            delay if delay < 0 => None,
+            delay => Some(Duration::from_millis(delay as u64)),
+        }
+    }
+
     pub fn on_currently_loading_frame(&self) -> bool {
         unsafe {
             from_glib(ffi::gdk_pixbuf_animation_iter_on_currently_loading_frame(
@@ -0,0 +1,105 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+/// An option passed to the image saver, for [`Pixbuf::save_to_buffer`](struct.Pixbuf.html#method.save_to_buffer)
+/// and the other typed save functions.
+///
+/// Savers ignore the options they don't know of, and fail on invalid values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveOption<'a> {
+    /// JPEG quality, from 0 to 100.
+    Quality(u8),
+    /// PNG compression level, from 0 to 9, or TIFF compression codec.
+    Compression(u8),
+    /// ICC color profile, embedded in PNG, JPEG and TIFF images.
+    IccProfile(&'a [u8]),
+    /// Horizontal resolution in dots per inch of PNG and JPEG images.
+    XDpi(u32),
+    /// Vertical resolution in dots per inch of PNG and JPEG images.
+    YDpi(u32),
+    /// Text chunk of PNG images, as key and value.
+    Text(&'a str, &'a str),
+    /// Color depth of ICO images, 16, 24 or 32.
+    Depth(u8),
+    /// Cursor hotspot of ICO images.
+    Hotspot(i32, i32),
+    /// Any other option, as key and value.
+    Other(&'a str, &'a str),
+}
+
+impl SaveOption<'_> {
+    pub(crate) fn to_key_values(options: &[SaveOption]) -> Vec<(String, String)> {
+        let mut key_values = Vec::with_capacity(options.len());
+        for option in options {
+            match *option {
+                SaveOption::Quality(quality) => {
+                    key_values.push(("quality".into(), quality.to_string()))
+                }
+                SaveOption::Compression(compression) => {
+                    key_values.push(("compression".into(), compression.to_string()))
+                }
+                SaveOption::IccProfile(profile) => key_values.push((
+                    "icc-profile".into(),
+                    glib::base64_encode(profile).to_string(),
+                )),
+                SaveOption::XDpi(dpi) => key_values.push(("x-dpi".into(), dpi.to_string())),
+                SaveOption::YDpi(dpi) => key_values.push(("y-dpi".into(), dpi.to_string())),
+                SaveOption::Text(key, value) => {
+                    key_values.push((format!("tEXt::{}", key), value.into()))
+                }
+                SaveOption::Depth(depth) => key_values.push(("depth".into(), depth.to_string())),
+                SaveOption::Hotspot(x, y) => {
+                    key_values.push(("x_hot".into(), x.to_string()));
+                    key_values.push(("y_hot".into(), y.to_string()));
+                }
+                SaveOption::Other(key, value) => key_values.push((key.into(), value.into())),
+            }
+        }
+        key_values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_key_values() {
+        let key_values = SaveOption::to_key_values(&[
+            SaveOption::Quality(90),
+            SaveOption::Compression(9),
+            SaveOption::IccProfile(b"icc"),
+            SaveOption::XDpi(300),
+            SaveOption::YDpi(150),
+            SaveOption::Text("Author", "gtk-rs"),
+            SaveOption::Depth(32),
+            SaveOption::Hotspot(4, -2),
+            SaveOption::Other("tiff-bits-per-sample", "8"),
+        ]);
+        let key_values = key_values
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            key_values,
+            [
+                ("quality", "90"),
+                ("compression", "9"),
+                ("icc-profile", "aWNj"),
+                ("x-dpi", "300"),
+                ("y-dpi", "150"),
+                ("tEXt::Author", "gtk-rs"),
+                ("depth", "32"),
+                ("x_hot", "4"),
+                ("y_hot", "-2"),
+                ("tiff-bits-per-sample", "8"),
+            ]
+        );
+    }
+
+    #[test]
+    fn to_key_values_empty() {
+        assert!(SaveOption::to_key_values(&[]).is_empty());
+    }
+}
@@ -457,14 +457,6 @@ pub fn selection_send_notify_for_display(
     }
 }
 
-#[doc(alias = "gdk_set_allowed_backends")]
-pub fn set_allowed_backends(backends: &str) {
-    assert_initialized_main_thread!();
-    unsafe {
-        ffi::gdk_set_allowed_backends(backends.to_glib_none().0);
-    }
-}
-
 #[doc(alias = "gdk_set_double_click_time")]
 pub fn set_double_click_time(msec: u32) {
     assert_initialized_main_thread!();
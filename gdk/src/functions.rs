@@ -85,3 +85,25 @@ pub fn property_change(
         );
     }
 }
+
+// rustdoc-stripper-ignore-next
+/// Calls `trace` with every event and its target window before passing it on to `dispatch`.
+///
+/// GDK has a single event handler and doesn't allow querying it, so the function normally
+/// handling the events must be given as `dispatch`, i.e. `gtk::main_do_event` in GTK
+/// applications. The traced events can be recorded and replayed later with `Event::put`.
+///
+/// Passing `None` as `trace` stops tracing and restores `dispatch` as the event handler.
+pub fn set_event_trace_fn<F: Fn(&crate::Event, Option<&crate::Window>) + 'static>(
+    trace: Option<F>,
+    dispatch: fn(&mut crate::Event),
+) {
+    assert_initialized_main_thread!();
+    match trace {
+        Some(trace) => crate::Event::set_handler(Some(move |event: &mut crate::Event| {
+            trace(event, event.get_window().as_ref());
+            dispatch(event);
+        })),
+        None => crate::Event::set_handler(Some(dispatch)),
+    }
+}
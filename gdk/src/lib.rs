@@ -70,10 +70,12 @@ mod screen;
 mod time_coord;
 mod visual;
 mod window;
+#[cfg(feature = "raw-window-handle")]
+mod window_handle;
 
 pub use ffi::GdkColor as Color;
 
-pub use self::rt::{init, set_initialized};
+pub use self::rt::{init, set_allowed_backends, set_initialized};
 
 pub use crate::atom::Atom;
 pub use crate::atom::NONE as ATOM_NONE;
@@ -71,6 +71,89 @@ impl RGBA {
             alpha: 1f64,
         }
     }
+
+    // rustdoc-stripper-ignore-next
+    /// Parses a color in the `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa` hexadecimal notations.
+    ///
+    /// Unlike `str::parse`, this accepts an alpha component in hexadecimal, but no color names.
+    pub fn from_hex(s: &str) -> Result<RGBA, RgbaParseError> {
+        skip_assert_initialized!();
+        let digits = s.strip_prefix('#').ok_or(RgbaParseError)?;
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(RgbaParseError);
+        }
+        let component = |i: usize, width: usize| {
+            let value = u8::from_str_radix(&digits[i * width..(i + 1) * width], 16).unwrap();
+            // `#f80` is the short form of `#ff8800`.
+            let value = if width == 1 { value * 0x11 } else { value };
+            f64::from(value) / 255.
+        };
+        let (width, has_alpha) = match digits.len() {
+            3 => (1, false),
+            4 => (1, true),
+            6 => (2, false),
+            8 => (2, true),
+            _ => return Err(RgbaParseError),
+        };
+        Ok(RGBA {
+            red: component(0, width),
+            green: component(1, width),
+            blue: component(2, width),
+            alpha: if has_alpha { component(3, width) } else { 1. },
+        })
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Formats the color as `#rrggbb`, ignoring its alpha component.
+    pub fn to_hex(&self) -> String {
+        format!(
+            "#{:02x}{:02x}{:02x}",
+            to_u8(self.red),
+            to_u8(self.green),
+            to_u8(self.blue)
+        )
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Formats the color as `#rrggbbaa`.
+    pub fn to_hex_with_alpha(&self) -> String {
+        format!("{}{:02x}", self.to_hex(), to_u8(self.alpha))
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the color and the alpha component for Pango, e.g. for
+    /// `pango::Attribute::new_foreground` and `pango::Attribute::new_foreground_alpha`.
+    pub fn to_pango_color(&self) -> (pango::Color, u16) {
+        (
+            pango::Color::new(to_u16(self.red), to_u16(self.green), to_u16(self.blue)),
+            to_u16(self.alpha),
+        )
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Creates an opaque color from a Pango color.
+    pub fn from_pango_color(color: &pango::Color) -> RGBA {
+        skip_assert_initialized!();
+        RGBA::from_pango_color_with_alpha(color, u16::MAX)
+    }
+
+    pub fn from_pango_color_with_alpha(color: &pango::Color, alpha: u16) -> RGBA {
+        skip_assert_initialized!();
+        RGBA {
+            red: f64::from(color.get_red()) / 65535.,
+            green: f64::from(color.get_green()) / 65535.,
+            blue: f64::from(color.get_blue()) / 65535.,
+            alpha: f64::from(alpha) / 65535.,
+        }
+    }
+}
+
+fn to_u8(component: f64) -> u8 {
+    (component.max(0.).min(1.) * 255.).round() as u8
+}
+
+fn to_u16(component: f64) -> u16 {
+    (component.max(0.).min(1.) * 65535.).round() as u16
 }
 
 impl fmt::Display for RGBA {
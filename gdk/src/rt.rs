@@ -4,6 +4,7 @@
 
 //! General — Library initialization and miscellaneous functions
 
+use glib::translate::*;
 use std::cell::Cell;
 use std::ptr;
 use std::sync::atomic::{AtomicBool, Ordering, ATOMIC_BOOL_INIT};
@@ -74,3 +75,17 @@ pub fn init() {
         set_initialized();
     }
 }
+
+// rustdoc-stripper-ignore-next
+/// Restricts the backends GDK may use, e.g. `"wayland,x11"` to prefer Wayland, or `"x11"` to
+/// open the displays given to `DisplayManager::open_display` as X displays.
+///
+/// `"*"` stands for all the backends not listed explicitly. This has to be called before
+/// `gdk::init` or `gtk::init`.
+#[doc(alias = "gdk_set_allowed_backends")]
+pub fn set_allowed_backends(backends: &str) {
+    assert_not_initialized!();
+    unsafe {
+        ffi::gdk_set_allowed_backends(backends.to_glib_none().0);
+    }
+}
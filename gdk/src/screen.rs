@@ -2,7 +2,7 @@
 // See the COPYRIGHT file at the top-level directory of this distribution.
 // Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
 
-use crate::Screen;
+use crate::{Screen, Visual, VisualType};
 use glib::translate::*;
 
 impl Screen {
@@ -30,4 +30,21 @@ impl Screen {
             }
         }
     }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the visual of the screen with the given depth and type, preferring the system
+    /// visual, e.g. to match the visual an external toolkit or OpenGL config asks for.
+    pub fn find_visual(
+        &self,
+        depth: Option<i32>,
+        visual_type: Option<VisualType>,
+    ) -> Option<Visual> {
+        let matches = |visual: &Visual| {
+            depth.map_or(true, |depth| visual.get_depth() == depth)
+                && visual_type.map_or(true, |visual_type| visual.get_visual_type() == visual_type)
+        };
+        self.get_system_visual()
+            .filter(&matches)
+            .or_else(|| self.list_visuals().into_iter().find(&matches))
+    }
 }
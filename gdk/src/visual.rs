@@ -2,7 +2,8 @@
 // See the COPYRIGHT file at the top-level directory of this distribution.
 // Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
 
-use crate::Visual;
+use crate::{Visual, VisualType};
+use glib::translate::*;
 use std::ptr;
 use std::slice;
 
@@ -17,4 +18,20 @@ impl Visual {
             Vec::from(slice::from_raw_parts(ptr as *const i32, count as usize))
         }
     }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the visual types available on the default screen.
+    pub fn query_visual_types() -> Vec<VisualType> {
+        assert_initialized_main_thread!();
+        let mut ptr = ptr::null_mut();
+        let mut count = 0;
+
+        unsafe {
+            ffi::gdk_query_visual_types(&mut ptr, &mut count);
+            slice::from_raw_parts(ptr as *const ffi::GdkVisualType, count as usize)
+                .iter()
+                .map(|visual_type| from_glib(*visual_type))
+                .collect()
+        }
+    }
 }
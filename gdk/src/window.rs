@@ -139,6 +139,30 @@ impl Window {
             ))
         }
     }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the topmost visible child window containing the point (`x`, `y`) of this
+    /// window, with the point translated to the coordinates of that child.
+    ///
+    /// Only the direct children are checked, call it again on the result to go deeper.
+    pub fn pick_child(&self, x: f64, y: f64) -> Option<(Window, f64, f64)> {
+        // The children are listed from the top of the stack.
+        self.get_children()
+            .into_iter()
+            .filter(|child| child.is_visible())
+            .find_map(|child| {
+                let (child_x, child_y) = child.coords_from_parent(x, y);
+                if child_x >= 0.
+                    && child_y >= 0.
+                    && child_x < f64::from(child.get_width())
+                    && child_y < f64::from(child.get_height())
+                {
+                    Some((child, child_x, child_y))
+                } else {
+                    None
+                }
+            })
+    }
 }
 
 pub trait WindowExtManual: 'static {
@@ -0,0 +1,173 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+//! [`raw-window-handle`](https://crates.io/crates/raw-window-handle) support.
+//!
+//! The backend is picked at runtime from the type of the `Window` or `Display`, so a GDK
+//! built with several backends (for instance X11 and Wayland) hands out the right handle. On
+//! unix, at least one of the `x11` and `wayland` features has to be enabled, and only when GDK
+//! is built with the matching backend.
+//!
+//! The trait implementations panic for an unsupported backend, use
+//! `Window::try_raw_window_handle` and `Display::try_raw_display_handle` to get an error
+//! instead.
+
+use crate::{Display, Window};
+use glib::object::ObjectExt;
+#[cfg(any(feature = "x11", feature = "wayland", windows, target_os = "macos"))]
+use glib::translate::*;
+use raw_window_handle::{
+    HasRawDisplayHandle, HasRawWindowHandle, RawDisplayHandle, RawWindowHandle,
+};
+
+#[cfg(all(
+    unix,
+    not(target_os = "macos"),
+    not(any(feature = "x11", feature = "wayland", feature = "dox"))
+))]
+compile_error!("The raw-window-handle feature needs the x11 or wayland feature on this platform");
+
+#[cfg(all(unix, not(target_os = "macos"), feature = "x11"))]
+mod x11 {
+    use libc::{c_int, c_ulong, c_void};
+
+    extern "C" {
+        pub fn gdk_x11_window_get_xid(window: *mut ffi::GdkWindow) -> c_ulong;
+        pub fn gdk_x11_display_get_xdisplay(display: *mut ffi::GdkDisplay) -> *mut c_void;
+        pub fn gdk_x11_screen_get_screen_number(screen: *mut ffi::GdkScreen) -> c_int;
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos"), feature = "wayland"))]
+mod wayland {
+    use libc::c_void;
+
+    extern "C" {
+        pub fn gdk_wayland_window_get_wl_surface(window: *mut ffi::GdkWindow) -> *mut c_void;
+        pub fn gdk_wayland_display_get_wl_display(display: *mut ffi::GdkDisplay) -> *mut c_void;
+    }
+}
+
+#[cfg(windows)]
+mod backend {
+    use libc::c_void;
+
+    extern "C" {
+        pub fn gdk_win32_window_get_handle(window: *mut ffi::GdkWindow) -> *mut c_void;
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod backend {
+    use libc::c_void;
+
+    extern "C" {
+        pub fn gdk_quartz_window_get_nsview(window: *mut ffi::GdkWindow) -> *mut c_void;
+        pub fn gdk_quartz_window_get_nswindow(window: *mut ffi::GdkWindow) -> *mut c_void;
+    }
+}
+
+impl Window {
+    // rustdoc-stripper-ignore-next
+    /// Returns the raw handle of the window, or an error if its backend isn't supported.
+    pub fn try_raw_window_handle(&self) -> Result<RawWindowHandle, glib::BoolError> {
+        let type_name = self.get_type().name();
+        match type_name.as_str() {
+            #[cfg(all(unix, not(target_os = "macos"), feature = "x11"))]
+            "GdkX11Window" => {
+                let mut handle = raw_window_handle::XlibWindowHandle::empty();
+                handle.window = unsafe { x11::gdk_x11_window_get_xid(self.to_glib_none().0) };
+                Ok(RawWindowHandle::Xlib(handle))
+            }
+            #[cfg(all(unix, not(target_os = "macos"), feature = "wayland"))]
+            "GdkWaylandWindow" => {
+                let mut handle = raw_window_handle::WaylandWindowHandle::empty();
+                handle.surface =
+                    unsafe { wayland::gdk_wayland_window_get_wl_surface(self.to_glib_none().0) };
+                Ok(RawWindowHandle::Wayland(handle))
+            }
+            #[cfg(windows)]
+            "GdkWin32Window" => {
+                let mut handle = raw_window_handle::Win32WindowHandle::empty();
+                handle.hwnd =
+                    unsafe { backend::gdk_win32_window_get_handle(self.to_glib_none().0) };
+                Ok(RawWindowHandle::Win32(handle))
+            }
+            #[cfg(target_os = "macos")]
+            "GdkQuartzWindow" => {
+                let mut handle = raw_window_handle::AppKitWindowHandle::empty();
+                unsafe {
+                    handle.ns_window =
+                        backend::gdk_quartz_window_get_nswindow(self.to_glib_none().0);
+                    handle.ns_view = backend::gdk_quartz_window_get_nsview(self.to_glib_none().0);
+                }
+                Ok(RawWindowHandle::AppKit(handle))
+            }
+            _ => Err(glib::glib_bool_error!(
+                "Unsupported GDK backend for raw window handle: {}",
+                type_name
+            )),
+        }
+    }
+}
+
+unsafe impl HasRawWindowHandle for Window {
+    fn raw_window_handle(&self) -> RawWindowHandle {
+        self.try_raw_window_handle()
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+unsafe impl HasRawDisplayHandle for Window {
+    fn raw_display_handle(&self) -> RawDisplayHandle {
+        self.get_display().raw_display_handle()
+    }
+}
+
+impl Display {
+    // rustdoc-stripper-ignore-next
+    /// Returns the raw handle of the display, or an error if its backend isn't supported.
+    pub fn try_raw_display_handle(&self) -> Result<RawDisplayHandle, glib::BoolError> {
+        let type_name = self.get_type().name();
+        match type_name.as_str() {
+            #[cfg(all(unix, not(target_os = "macos"), feature = "x11"))]
+            "GdkX11Display" => {
+                let mut handle = raw_window_handle::XlibDisplayHandle::empty();
+                unsafe {
+                    handle.display = x11::gdk_x11_display_get_xdisplay(self.to_glib_none().0);
+                    handle.screen = x11::gdk_x11_screen_get_screen_number(
+                        self.get_default_screen().to_glib_none().0,
+                    );
+                }
+                Ok(RawDisplayHandle::Xlib(handle))
+            }
+            #[cfg(all(unix, not(target_os = "macos"), feature = "wayland"))]
+            "GdkWaylandDisplay" => {
+                let mut handle = raw_window_handle::WaylandDisplayHandle::empty();
+                handle.display =
+                    unsafe { wayland::gdk_wayland_display_get_wl_display(self.to_glib_none().0) };
+                Ok(RawDisplayHandle::Wayland(handle))
+            }
+            #[cfg(windows)]
+            "GdkWin32Display" => Ok(RawDisplayHandle::Windows(
+                raw_window_handle::WindowsDisplayHandle::empty(),
+            )),
+            #[cfg(target_os = "macos")]
+            "GdkQuartzDisplay" => Ok(RawDisplayHandle::AppKit(
+                raw_window_handle::AppKitDisplayHandle::empty(),
+            )),
+            _ => Err(glib::glib_bool_error!(
+                "Unsupported GDK backend for raw display handle: {}",
+                type_name
+            )),
+        }
+    }
+}
+
+unsafe impl HasRawDisplayHandle for Display {
+    fn raw_display_handle(&self) -> RawDisplayHandle {
+        self.try_raw_display_handle()
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+}
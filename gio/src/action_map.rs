@@ -0,0 +1,180 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::{ActionExt, ActionMap, ActionMapExt, SimpleAction};
+use glib::variant::FromVariant;
+use glib::{IsA, ToVariant};
+
+// rustdoc-stripper-ignore-next
+/// Helpers adding `SimpleAction`s whose parameter and state are Rust values.
+///
+/// The values go through `ToVariant` and `FromVariant`, so any type implementing them can be
+/// used, including custom types which convert themselves to and from a `Variant`.
+pub trait ActionMapExtManual {
+    // rustdoc-stripper-ignore-next
+    /// Adds an action without parameter nor state, calling `f` when it's activated.
+    fn add_simple_action<F: Fn(&SimpleAction) + 'static>(&self, name: &str, f: F) -> SimpleAction;
+
+    // rustdoc-stripper-ignore-next
+    /// Adds an action taking a parameter of type `T`, calling `f` with the decoded parameter
+    /// when it's activated.
+    fn add_action_with_parameter<T: FromVariant, F: Fn(&SimpleAction, T) + 'static>(
+        &self,
+        name: &str,
+        f: F,
+    ) -> SimpleAction;
+
+    // rustdoc-stripper-ignore-next
+    /// Adds an action with a boolean state, like the ones behind check menu items.
+    ///
+    /// Activating the action flips its state, then `f` is called with the new state. `f` is
+    /// called as well when the state is changed with `change_state`.
+    fn add_toggle_action<F: Fn(&SimpleAction, bool) + 'static>(
+        &self,
+        name: &str,
+        initial: bool,
+        f: F,
+    ) -> SimpleAction;
+
+    // rustdoc-stripper-ignore-next
+    /// Adds an action whose parameter and state are of type `T`, like the ones behind radio
+    /// menu items, e.g. with `T` being `String` and detailed action names like
+    /// `"win.mode::compact"`.
+    ///
+    /// Activating the action sets its state to the parameter, then `f` is called with the new
+    /// state. `f` is called as well when the state is changed with `change_state`.
+    fn add_radio_action<T: FromVariant + ToVariant, F: Fn(&SimpleAction, T) + 'static>(
+        &self,
+        name: &str,
+        initial: &T,
+        f: F,
+    ) -> SimpleAction;
+}
+
+impl<O: IsA<ActionMap>> ActionMapExtManual for O {
+    fn add_simple_action<F: Fn(&SimpleAction) + 'static>(&self, name: &str, f: F) -> SimpleAction {
+        let action = SimpleAction::new(name, None);
+        action.connect_activate(move |action, _| f(action));
+        self.add_action(&action);
+        action
+    }
+
+    fn add_action_with_parameter<T: FromVariant, F: Fn(&SimpleAction, T) + 'static>(
+        &self,
+        name: &str,
+        f: F,
+    ) -> SimpleAction {
+        let action = SimpleAction::new(name, Some(&*T::static_variant_type()));
+        action.connect_activate(move |action, parameter| {
+            // GIO checks the type of the parameter before emitting `activate`.
+            if let Some(parameter) = parameter.and_then(T::from_variant) {
+                f(action, parameter);
+            }
+        });
+        self.add_action(&action);
+        action
+    }
+
+    fn add_toggle_action<F: Fn(&SimpleAction, bool) + 'static>(
+        &self,
+        name: &str,
+        initial: bool,
+        f: F,
+    ) -> SimpleAction {
+        let action = SimpleAction::new_stateful(name, None, &initial.to_variant());
+        action.connect_activate(|action, _| {
+            if let Some(state) = action.get_state().as_ref().and_then(bool::from_variant) {
+                action.change_state(&(!state).to_variant());
+            }
+        });
+        action.connect_change_state(move |action, value| {
+            if let Some(value) = value {
+                if let Some(state) = bool::from_variant(value) {
+                    action.set_state(value);
+                    f(action, state);
+                }
+            }
+        });
+        self.add_action(&action);
+        action
+    }
+
+    fn add_radio_action<T: FromVariant + ToVariant, F: Fn(&SimpleAction, T) + 'static>(
+        &self,
+        name: &str,
+        initial: &T,
+        f: F,
+    ) -> SimpleAction {
+        let action = SimpleAction::new_stateful(
+            name,
+            Some(&*T::static_variant_type()),
+            &initial.to_variant(),
+        );
+        action.connect_activate(|action, parameter| {
+            if let Some(parameter) = parameter {
+                action.change_state(parameter);
+            }
+        });
+        action.connect_change_state(move |action, value| {
+            if let Some(value) = value {
+                if let Some(state) = T::from_variant(value) {
+                    action.set_state(value);
+                    f(action, state);
+                }
+            }
+        });
+        self.add_action(&action);
+        action
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ActionGroupExt, SimpleActionGroup};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn toggle() {
+        let group = SimpleActionGroup::new();
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let changes_clone = changes.clone();
+        group.add_toggle_action("toggle", false, move |_, state| {
+            changes_clone.borrow_mut().push(state)
+        });
+
+        group.activate_action("toggle", None);
+        assert_eq!(group.get_action_state("toggle"), Some(true.to_variant()));
+        group.activate_action("toggle", None);
+        group.change_action_state("toggle", &true.to_variant());
+        assert_eq!(*changes.borrow(), vec![true, false, true]);
+    }
+
+    #[test]
+    fn radio() {
+        let group = SimpleActionGroup::new();
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let changes_clone = changes.clone();
+        group.add_radio_action("mode", &String::from("list"), move |_, mode: String| {
+            changes_clone.borrow_mut().push(mode)
+        });
+
+        group.activate_action("mode", Some(&"grid".to_variant()));
+        assert_eq!(group.get_action_state("mode"), Some("grid".to_variant()));
+        assert_eq!(*changes.borrow(), vec![String::from("grid")]);
+    }
+
+    #[test]
+    fn parameter() {
+        let group = SimpleActionGroup::new();
+        let sum = Rc::new(RefCell::new(0));
+        let sum_clone = sum.clone();
+        group.add_action_with_parameter("add", move |_, n: i32| *sum_clone.borrow_mut() += n);
+
+        group.activate_action("add", Some(&2.to_variant()));
+        group.activate_action("add", Some(&3.to_variant()));
+        assert_eq!(*sum.borrow(), 5);
+    }
+}
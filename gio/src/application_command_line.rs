@@ -0,0 +1,44 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::ApplicationCommandLine;
+use glib::object::IsA;
+use glib::translate::*;
+use libc::c_char;
+
+pub trait ApplicationCommandLineExtManual {
+    // rustdoc-stripper-ignore-next
+    /// Prints `message` on the standard output of the process which was invoked, which may be
+    /// a remote one.
+    #[doc(alias = "g_application_command_line_print")]
+    fn print(&self, message: &str);
+
+    // rustdoc-stripper-ignore-next
+    /// Prints `message` on the standard error of the process which was invoked, which may be a
+    /// remote one.
+    #[doc(alias = "g_application_command_line_printerr")]
+    fn printerr(&self, message: &str);
+}
+
+impl<O: IsA<ApplicationCommandLine>> ApplicationCommandLineExtManual for O {
+    fn print(&self, message: &str) {
+        unsafe {
+            ffi::g_application_command_line_print(
+                self.as_ref().to_glib_none().0,
+                b"%s\0".as_ptr() as *const c_char,
+                ToGlibPtr::<*const c_char>::to_glib_none(message).0,
+            );
+        }
+    }
+
+    fn printerr(&self, message: &str) {
+        unsafe {
+            ffi::g_application_command_line_printerr(
+                self.as_ref().to_glib_none().0,
+                b"%s\0".as_ptr() as *const c_char,
+                ToGlibPtr::<*const c_char>::to_glib_none(message).0,
+            );
+        }
+    }
+}
@@ -47,9 +47,6 @@ pub trait CancellableExt: 'static {
     #[doc(alias = "g_cancellable_cancel")]
     fn cancel(&self);
 
-    //#[doc(alias = "g_cancellable_connect")]
-    //fn connect<P: Fn() + Send + Sync + 'static>(&self, callback: P, data: /*Unimplemented*/Option<Fundamental: Pointer>) -> libc::c_ulong;
-
     #[doc(alias = "g_cancellable_disconnect")]
     fn disconnect(&self, handler_id: libc::c_ulong);
 
@@ -84,10 +81,6 @@ impl<O: IsA<Cancellable>> CancellableExt for O {
         }
     }
 
-    //fn connect<P: Fn() + Send + Sync + 'static>(&self, callback: P, data: /*Unimplemented*/Option<Fundamental: Pointer>) -> libc::c_ulong {
-    //    unsafe { TODO: call ffi:g_cancellable_connect() }
-    //}
-
     fn disconnect(&self, handler_id: libc::c_ulong) {
         unsafe {
             ffi::g_cancellable_disconnect(self.as_ref().to_glib_none().0, handler_id);
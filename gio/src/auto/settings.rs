@@ -110,9 +110,6 @@ pub trait SettingsExt: 'static {
         flags: SettingsBindFlags,
     );
 
-    //#[doc(alias = "g_settings_bind_with_mapping")]
-    //fn bind_with_mapping<P: IsA<glib::Object>, Q: Fn(&glib::Value, &glib::Variant) -> bool + 'static, R: Fn(&glib::Value, &glib::VariantType) -> glib::Variant + 'static>(&self, key: &str, object: &P, property: &str, flags: SettingsBindFlags, get_mapping: Q, set_mapping: R);
-
     #[doc(alias = "g_settings_bind_writable")]
     fn bind_writable<P: IsA<glib::Object>>(
         &self,
@@ -293,10 +290,6 @@ impl<O: IsA<Settings>> SettingsExt for O {
         }
     }
 
-    //fn bind_with_mapping<P: IsA<glib::Object>, Q: Fn(&glib::Value, &glib::Variant) -> bool + 'static, R: Fn(&glib::Value, &glib::VariantType) -> glib::Variant + 'static>(&self, key: &str, object: &P, property: &str, flags: SettingsBindFlags, get_mapping: Q, set_mapping: R) {
-    //    unsafe { TODO: call ffi:g_settings_bind_with_mapping() }
-    //}
-
     fn bind_writable<P: IsA<glib::Object>>(
         &self,
         key: &str,
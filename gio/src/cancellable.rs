@@ -2,11 +2,123 @@
 // See the COPYRIGHT file at the top-level directory of this distribution.
 // Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
 
-use crate::CancellableExt;
+use crate::Cancellable;
+use glib::object::{Cast, IsA};
+use glib::translate::*;
+use std::num::NonZeroU64;
 
-#[test]
-fn check_callback() {
-    let c = crate::Cancellable::new();
-    c.connect_cancelled(|_| {});
-    c.cancel(); // if it doesn't crash at this point, then we're good to go!
+#[derive(Debug, Eq, PartialEq)]
+pub struct CancelledHandlerId(NonZeroU64);
+
+pub trait CancellableExtManual {
+    // rustdoc-stripper-ignore-next
+    /// Calls `callback` once the operation is cancelled, right away if it already is, in which
+    /// case `None` is returned.
+    ///
+    /// Unlike with `connect_cancelled`, this doesn't race with a cancellation happening in
+    /// another thread. The callback is called in the thread cancelling the operation.
+    fn connect_cancelled_once<F: FnOnce(&Self) + Send + 'static>(
+        &self,
+        callback: F,
+    ) -> Option<CancelledHandlerId>;
+
+    // rustdoc-stripper-ignore-next
+    /// Disconnects a callback connected with `connect_cancelled_once`, waiting for it to
+    /// return if it is running in another thread.
+    fn disconnect_cancelled(&self, id: CancelledHandlerId);
+}
+
+impl<O: IsA<Cancellable>> CancellableExtManual for O {
+    fn connect_cancelled_once<F: FnOnce(&Self) + Send + 'static>(
+        &self,
+        callback: F,
+    ) -> Option<CancelledHandlerId> {
+        unsafe extern "C" fn connect_trampoline<P: IsA<Cancellable>, F: FnOnce(&P)>(
+            this: *mut ffi::GCancellable,
+            callback: glib::ffi::gpointer,
+        ) {
+            // The cancellable can be reset and cancelled again, the callback runs only the
+            // first time.
+            let callback: &mut Option<F> = &mut *(callback as *mut Option<F>);
+            if let Some(callback) = callback.take() {
+                callback(Cancellable::from_glib_borrow(this).unsafe_cast_ref())
+            }
+        }
+
+        unsafe extern "C" fn destroy_closure<F>(ptr: glib::ffi::gpointer) {
+            let _ = Box::<Option<F>>::from_raw(ptr as *mut _);
+        }
+
+        let callback: Box<Option<F>> = Box::new(Some(callback));
+        unsafe {
+            let id = ffi::g_cancellable_connect(
+                self.as_ptr() as *mut _,
+                Some(std::mem::transmute::<_, unsafe extern "C" fn()>(
+                    connect_trampoline::<Self, F> as *const (),
+                )),
+                Box::into_raw(callback) as glib::ffi::gpointer,
+                Some(destroy_closure::<F>),
+            );
+            NonZeroU64::new(id as u64).map(CancelledHandlerId)
+        }
+    }
+
+    fn disconnect_cancelled(&self, id: CancelledHandlerId) {
+        unsafe {
+            ffi::g_cancellable_disconnect(self.as_ptr() as *mut _, id.0.get() as libc::c_ulong);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn check_callback() {
+        let c = Cancellable::new();
+        c.connect_cancelled(|_| {});
+        c.cancel(); // if it doesn't crash at this point, then we're good to go!
+    }
+
+    #[test]
+    fn cancelled_once() {
+        let c = Cancellable::new();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_clone = calls.clone();
+        let id = c.connect_cancelled_once(move |c| {
+            assert!(c.is_cancelled());
+            calls_clone.fetch_add(1, Ordering::SeqCst);
+        });
+        assert!(id.is_some());
+        c.cancel();
+        c.cancel();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn already_cancelled() {
+        let c = Cancellable::new();
+        c.cancel();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_clone = calls.clone();
+        let id = c.connect_cancelled_once(move |_| {
+            calls_clone.fetch_add(1, Ordering::SeqCst);
+        });
+        assert!(id.is_none());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn disconnect_cancelled() {
+        let c = Cancellable::new();
+        let id = c
+            .connect_cancelled_once(|_| panic!("disconnected callback called"))
+            .unwrap();
+        c.disconnect_cancelled(id);
+        c.cancel();
+    }
 }
@@ -9,10 +9,12 @@
 
 pub use ffi;
 
+mod action_map;
 mod app_info;
 mod application;
-#[cfg(test)]
+mod application_command_line;
 mod cancellable;
+pub use crate::cancellable::CancelledHandlerId;
 mod converter;
 mod data_input_stream;
 mod dbus;
@@ -43,6 +45,10 @@ pub use crate::input_stream::{InputStreamAsyncBufRead, InputStreamRead};
 mod list_store;
 mod memory_input_stream;
 mod memory_output_stream;
+mod menu;
+pub use crate::menu::{MenuBuilder, MenuItemBuilder};
+mod notification;
+pub use crate::notification::NotificationBuilder;
 mod output_stream;
 pub use crate::output_stream::OutputStreamWrite;
 mod pollable_input_stream;
@@ -54,6 +60,7 @@ mod settings;
 mod socket;
 mod subprocess;
 mod subprocess_launcher;
+mod themed_icon;
 mod threaded_socket_service;
 #[cfg(any(unix, feature = "dox"))]
 mod unix_fd_list;
@@ -0,0 +1,192 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::{Icon, Menu, MenuItem, MenuModel};
+use glib::{Cast, IsA, ToVariant};
+
+// rustdoc-stripper-ignore-next
+/// Builds a `Menu` in code, e.g. for `gtk::Application::set_menubar`.
+///
+/// ```
+/// use gio::{MenuBuilder, MenuItemBuilder};
+///
+/// let menubar = MenuBuilder::new()
+///     .submenu(
+///         "_File",
+///         &MenuBuilder::new()
+///             .item(MenuItemBuilder::new("_Open").action("app.open").accel("<Primary>o"))
+///             .section(
+///                 None,
+///                 &MenuBuilder::new()
+///                     .item(MenuItemBuilder::new("_Quit").action("app.quit"))
+///                     .build(),
+///             )
+///             .build(),
+///     )
+///     .build();
+/// ```
+#[derive(Clone, Default)]
+pub struct MenuBuilder {
+    items: Vec<MenuItem>,
+}
+
+impl MenuBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn build(self) -> Menu {
+        let menu = Menu::new();
+        for item in &self.items {
+            menu.append_item(item);
+        }
+        menu
+    }
+
+    pub fn item(mut self, item: MenuItemBuilder) -> Self {
+        self.items.push(item.build());
+        self
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Adds an item activating `detailed_action`, like `"app.quit"` or `"win.mode::compact"`.
+    pub fn action(mut self, label: &str, detailed_action: &str) -> Self {
+        self.items
+            .push(MenuItem::new(Some(label), Some(detailed_action)));
+        self
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Adds the items of `section` as a group, separated from the other items.
+    pub fn section<P: IsA<MenuModel>>(mut self, label: Option<&str>, section: &P) -> Self {
+        self.items.push(MenuItem::new_section(label, section));
+        self
+    }
+
+    pub fn submenu<P: IsA<MenuModel>>(mut self, label: &str, submenu: &P) -> Self {
+        self.items.push(MenuItem::new_submenu(Some(label), submenu));
+        self
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// Builds a `MenuItem`, to be added with [`MenuBuilder::item`](struct.MenuBuilder.html#method.item)
+/// or `Menu::append_item`.
+#[derive(Clone)]
+pub struct MenuItemBuilder {
+    label: String,
+    action: Option<String>,
+    target: Option<glib::Variant>,
+    icon: Option<Icon>,
+    attributes: Vec<(String, glib::Variant)>,
+    submenu: Option<MenuModel>,
+}
+
+impl MenuItemBuilder {
+    pub fn new(label: &str) -> Self {
+        MenuItemBuilder {
+            label: label.to_owned(),
+            action: None,
+            target: None,
+            icon: None,
+            attributes: Vec::new(),
+            submenu: None,
+        }
+    }
+
+    pub fn build(self) -> MenuItem {
+        let item = MenuItem::new(Some(&self.label), None);
+        if self.action.is_some() {
+            item.set_action_and_target_value(self.action.as_deref(), self.target.as_ref());
+        }
+        if let Some(ref icon) = self.icon {
+            item.set_icon(icon);
+        }
+        for (attribute, value) in &self.attributes {
+            item.set_attribute_value(attribute, Some(value));
+        }
+        if let Some(ref submenu) = self.submenu {
+            item.set_submenu(Some(submenu));
+        }
+        item
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Sets the name of the action activated by the item, like `"app.quit"`.
+    pub fn action(mut self, action: &str) -> Self {
+        self.action = Some(action.to_owned());
+        self
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Sets the parameter the action is activated with. For stateful actions, the item is shown
+    /// as a radio item which is selected while the state of the action is equal to `target`.
+    pub fn target<T: ToVariant>(mut self, target: &T) -> Self {
+        self.target = Some(target.to_variant());
+        self
+    }
+
+    pub fn icon<P: IsA<Icon>>(mut self, icon: &P) -> Self {
+        self.icon = Some(icon.clone().upcast());
+        self
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Sets the keyboard shortcut shown next to the item, like `"<Primary>q"`.
+    ///
+    /// This is only a hint displayed by GTK: the shortcut itself is set with
+    /// `gtk::Application::set_accels_for_action`.
+    pub fn accel(self, accel: &str) -> Self {
+        self.attribute("accel", &accel.to_variant())
+    }
+
+    pub fn attribute(mut self, attribute: &str, value: &glib::Variant) -> Self {
+        self.attributes.push((attribute.to_owned(), value.clone()));
+        self
+    }
+
+    pub fn submenu<P: IsA<MenuModel>>(mut self, submenu: &P) -> Self {
+        self.submenu = Some(submenu.clone().upcast());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn build() {
+        let menu = MenuBuilder::new()
+            .action("_New", "app.new")
+            .item(
+                MenuItemBuilder::new("_Compact")
+                    .action("win.mode")
+                    .target(&"compact")
+                    .accel("<Primary>1"),
+            )
+            .section(
+                None,
+                &MenuBuilder::new().action("_Quit", "app.quit").build(),
+            )
+            .build();
+
+        assert_eq!(menu.get_n_items(), 3);
+        assert_eq!(
+            menu.get_item_attribute_value(0, "action", None),
+            Some("app.new".to_variant())
+        );
+        assert_eq!(
+            menu.get_item_attribute_value(1, "target", None),
+            Some("compact".to_variant())
+        );
+        assert_eq!(
+            menu.get_item_attribute_value(1, "accel", None),
+            Some("<Primary>1".to_variant())
+        );
+        let section = menu.get_item_link(2, "section").unwrap();
+        assert_eq!(section.get_n_items(), 1);
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::{Icon, Notification, NotificationPriority};
+use glib::{Cast, IsA, ToVariant};
+
+// rustdoc-stripper-ignore-next
+/// Builds a `Notification`, to be shown with `ApplicationExt::send_notification`.
+///
+/// Clicking the notification or one of its buttons activates an action of the application,
+/// which has to be named like `"app.open-message"`.
+///
+/// ```
+/// use gio::NotificationBuilder;
+///
+/// let notification = NotificationBuilder::new("New message")
+///     .body("Hello there!")
+///     .default_action_with_target("app.open-message", &42u32)
+///     .button_with_target("Mark as read", "app.mark-read", &42u32)
+///     .build();
+/// ```
+#[derive(Clone)]
+pub struct NotificationBuilder {
+    title: String,
+    body: Option<String>,
+    icon: Option<Icon>,
+    priority: Option<NotificationPriority>,
+    default_action: Option<(String, Option<glib::Variant>)>,
+    buttons: Vec<(String, String, Option<glib::Variant>)>,
+}
+
+impl NotificationBuilder {
+    pub fn new(title: &str) -> Self {
+        NotificationBuilder {
+            title: title.to_owned(),
+            body: None,
+            icon: None,
+            priority: None,
+            default_action: None,
+            buttons: Vec::new(),
+        }
+    }
+
+    pub fn build(self) -> Notification {
+        let notification = Notification::new(&self.title);
+        if let Some(ref body) = self.body {
+            notification.set_body(Some(body));
+        }
+        if let Some(ref icon) = self.icon {
+            notification.set_icon(icon);
+        }
+        if let Some(priority) = self.priority {
+            notification.set_priority(priority);
+        }
+        match self.default_action {
+            Some((ref action, Some(ref target))) => {
+                notification.set_default_action_and_target_value(action, Some(target))
+            }
+            Some((ref action, None)) => notification.set_default_action(action),
+            None => (),
+        }
+        for (label, action, target) in &self.buttons {
+            match target {
+                Some(target) => {
+                    notification.add_button_with_target_value(label, action, Some(target))
+                }
+                None => notification.add_button(label, action),
+            }
+        }
+        notification
+    }
+
+    pub fn body(mut self, body: &str) -> Self {
+        self.body = Some(body.to_owned());
+        self
+    }
+
+    pub fn icon<P: IsA<Icon>>(mut self, icon: &P) -> Self {
+        self.icon = Some(icon.clone().upcast());
+        self
+    }
+
+    pub fn priority(mut self, priority: NotificationPriority) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Sets the action activated when the notification is clicked, like `"app.show"` or
+    /// `"app.open::inbox"`.
+    pub fn default_action(mut self, action: &str) -> Self {
+        self.default_action = Some((action.to_owned(), None));
+        self
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Sets the action activated with `target` as parameter when the notification is clicked.
+    pub fn default_action_with_target<T: ToVariant>(mut self, action: &str, target: &T) -> Self {
+        self.default_action = Some((action.to_owned(), Some(target.to_variant())));
+        self
+    }
+
+    pub fn button(mut self, label: &str, action: &str) -> Self {
+        self.buttons
+            .push((label.to_owned(), action.to_owned(), None));
+        self
+    }
+
+    pub fn button_with_target<T: ToVariant>(
+        mut self,
+        label: &str,
+        action: &str,
+        target: &T,
+    ) -> Self {
+        self.buttons.push((
+            label.to_owned(),
+            action.to_owned(),
+            Some(target.to_variant()),
+        ));
+        self
+    }
+}
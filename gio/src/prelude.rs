@@ -9,9 +9,12 @@ pub use glib::prelude::*;
 
 pub use crate::auto::traits::*;
 
+pub use crate::action_map::ActionMapExtManual;
 #[cfg(any(feature = "v2_60", feature = "dox"))]
 pub use crate::app_info::AppInfoExtManual;
 pub use crate::application::*;
+pub use crate::application_command_line::ApplicationCommandLineExtManual;
+pub use crate::cancellable::CancellableExtManual;
 pub use crate::converter::*;
 pub use crate::data_input_stream::DataInputStreamExtManual;
 #[cfg(any(feature = "v2_58", feature = "dox"))]
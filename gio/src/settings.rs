@@ -1,11 +1,34 @@
-use crate::{Settings, SettingsExt};
+use crate::{Settings, SettingsBindFlags, SettingsExt};
+use glib::translate::*;
 use glib::variant::FromVariant;
 use glib::{BoolError, IsA, ToVariant};
+use std::boxed::Box as Box_;
 
 pub trait SettingsExtManual {
     fn get<U: FromVariant>(&self, key: &str) -> U;
 
     fn set<U: ToVariant>(&self, key: &str, value: &U) -> Result<(), BoolError>;
+
+    // rustdoc-stripper-ignore-next
+    /// Binds `key` to the property `property` of `object` like `bind`, converting the values
+    /// with closures, e.g. to bind a string key to the index of a combo box.
+    ///
+    /// `get_mapping` converts the value of the key to a value of the type of the property, and
+    /// `set_mapping` converts the value of the property to a value of the type of the key.
+    /// Returning `None` leaves the other side unchanged.
+    fn bind_with_mapping<
+        P: IsA<glib::Object>,
+        F: Fn(&glib::Variant, glib::Type) -> Option<glib::Value> + 'static,
+        G: Fn(&glib::Value, &glib::VariantTy) -> Option<glib::Variant> + 'static,
+    >(
+        &self,
+        key: &str,
+        object: &P,
+        property: &str,
+        flags: SettingsBindFlags,
+        get_mapping: F,
+        set_mapping: G,
+    );
 }
 
 impl<O: IsA<Settings>> SettingsExtManual for O {
@@ -23,6 +46,74 @@ impl<O: IsA<Settings>> SettingsExtManual for O {
     fn set<U: ToVariant>(&self, key: &str, value: &U) -> Result<(), BoolError> {
         self.set_value(key, &value.to_variant())
     }
+
+    fn bind_with_mapping<
+        P: IsA<glib::Object>,
+        F: Fn(&glib::Variant, glib::Type) -> Option<glib::Value> + 'static,
+        G: Fn(&glib::Value, &glib::VariantTy) -> Option<glib::Variant> + 'static,
+    >(
+        &self,
+        key: &str,
+        object: &P,
+        property: &str,
+        flags: SettingsBindFlags,
+        get_mapping: F,
+        set_mapping: G,
+    ) {
+        unsafe extern "C" fn get_mapping_trampoline<
+            F: Fn(&glib::Variant, glib::Type) -> Option<glib::Value> + 'static,
+            G: Fn(&glib::Value, &glib::VariantTy) -> Option<glib::Variant> + 'static,
+        >(
+            value: *mut glib::gobject_ffi::GValue,
+            variant: *mut glib::ffi::GVariant,
+            user_data: glib::ffi::gpointer,
+        ) -> glib::ffi::gboolean {
+            let mappings: &(F, G) = &*(user_data as *const (F, G));
+            let type_ = from_glib((*value).g_type);
+            match (mappings.0)(&from_glib_borrow(variant), type_) {
+                // Also accepts values which can be converted to the type of the property.
+                Some(mapped) => {
+                    glib::gobject_ffi::g_value_transform(mapped.to_glib_none().0, value)
+                }
+                None => glib::ffi::GFALSE,
+            }
+        }
+        unsafe extern "C" fn set_mapping_trampoline<
+            F: Fn(&glib::Variant, glib::Type) -> Option<glib::Value> + 'static,
+            G: Fn(&glib::Value, &glib::VariantTy) -> Option<glib::Variant> + 'static,
+        >(
+            value: *const glib::gobject_ffi::GValue,
+            expected_type: *const glib::ffi::GVariantType,
+            user_data: glib::ffi::gpointer,
+        ) -> *mut glib::ffi::GVariant {
+            let mappings: &(F, G) = &*(user_data as *const (F, G));
+            let value = &*(value as *const glib::Value);
+            (mappings.1)(value, glib::VariantTy::from_ptr(expected_type)).to_glib_full()
+        }
+        unsafe extern "C" fn destroy_notify<
+            F: Fn(&glib::Variant, glib::Type) -> Option<glib::Value> + 'static,
+            G: Fn(&glib::Value, &glib::VariantTy) -> Option<glib::Variant> + 'static,
+        >(
+            user_data: glib::ffi::gpointer,
+        ) {
+            let _ = Box_::from_raw(user_data as *mut (F, G));
+        }
+
+        let mappings: Box_<(F, G)> = Box_::new((get_mapping, set_mapping));
+        unsafe {
+            ffi::g_settings_bind_with_mapping(
+                self.as_ref().to_glib_none().0,
+                key.to_glib_none().0,
+                object.as_ref().to_glib_none().0,
+                property.to_glib_none().0,
+                flags.to_glib(),
+                Some(get_mapping_trampoline::<F, G>),
+                Some(set_mapping_trampoline::<F, G>),
+                Box_::into_raw(mappings) as glib::ffi::gpointer,
+                Some(destroy_notify::<F, G>),
+            );
+        }
+    }
 }
 
 #[cfg(test)]
@@ -81,6 +172,34 @@ mod test {
         assert!(!settings.get::<bool>("test-bool"));
     }
 
+    #[test]
+    #[serial_test::serial]
+    fn bind_with_mapping() {
+        use crate::ActionExt;
+        use glib::ToValue;
+
+        set_env();
+        let settings = Settings::new("com.github.gtk-rs.test");
+        settings.set("test-bool", &true).unwrap();
+        let action = crate::SimpleAction::new("test", None);
+        // Binds the action to be enabled while the key is false.
+        settings.bind_with_mapping(
+            "test-bool",
+            &action,
+            "enabled",
+            SettingsBindFlags::DEFAULT,
+            |variant, _| variant.get::<bool>().map(|b| (!b).to_value()),
+            |value, _| value.get_some::<bool>().ok().map(|b| (!b).to_variant()),
+        );
+        assert!(!action.get_enabled());
+
+        settings.set("test-bool", &false).unwrap();
+        assert!(action.get_enabled());
+
+        action.set_enabled(false);
+        assert!(settings.get::<bool>("test-bool"));
+    }
+
     #[test]
     #[should_panic]
     #[serial_test::serial]
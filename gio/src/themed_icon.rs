@@ -0,0 +1,33 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::ThemedIcon;
+
+impl ThemedIcon {
+    // rustdoc-stripper-ignore-next
+    /// Creates a themed icon for `iconname` which falls back to each of `fallbacks`, in order,
+    /// when the current icon theme doesn't provide it.
+    pub fn with_fallbacks(iconname: &str, fallbacks: &[&str]) -> ThemedIcon {
+        let icon = ThemedIcon::new(iconname);
+        for fallback in fallbacks {
+            icon.append_name(fallback);
+        }
+        icon
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_fallbacks() {
+        let icon =
+            ThemedIcon::with_fallbacks("my-app-symbolic", &["my-app", "application-x-executable"]);
+        assert_eq!(
+            icon.get_names(),
+            &["my-app-symbolic", "my-app", "application-x-executable"]
+        );
+    }
+}
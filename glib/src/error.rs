@@ -76,7 +76,29 @@ impl Error {
         }
     }
 
-    fn message(&self) -> &str {
+    /// Converts into an error of the `T` domain, which can be matched on with its `kind`.
+    ///
+    /// Returns `Err(self)` if the error belongs to another domain, or if its code is unknown
+    /// and `T` has no catch-all variant.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// match provider.load_from_data(css).map_err(glib::Error::downcast::<CssProviderError>) {
+    ///     Ok(()) => ...
+    ///     Err(Ok(error)) if error.kind() == CssProviderError::Syntax => ...
+    ///     Err(_) => ...
+    /// }
+    /// ```
+    pub fn downcast<T: ErrorDomain>(self) -> Result<TypedError<T>, Error> {
+        match self.kind::<T>() {
+            Some(kind) => Ok(TypedError { kind, error: self }),
+            None => Err(self),
+        }
+    }
+
+    /// Returns the message describing the error, which is also displayed by `Display`.
+    pub fn message(&self) -> &str {
         unsafe {
             let bytes = CStr::from_ptr(self.0.message).to_bytes();
             str::from_utf8(bytes)
@@ -103,6 +125,54 @@ impl fmt::Debug for Error {
 
 impl error::Error for Error {}
 
+/// An `Error` known to belong to the error domain `T`, as returned by `Error::downcast`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TypedError<T> {
+    kind: T,
+    error: Error,
+}
+
+impl<T: ErrorDomain> TypedError<T> {
+    /// Returns the variant of the error enum.
+    pub fn kind(&self) -> T {
+        self.kind
+    }
+
+    /// Returns the message describing the error.
+    pub fn message(&self) -> &str {
+        self.error.message()
+    }
+
+    /// Returns the original `Error`.
+    pub fn into_inner(self) -> Error {
+        self.error
+    }
+}
+
+impl<T> From<TypedError<T>> for Error {
+    fn from(error: TypedError<T>) -> Error {
+        error.error
+    }
+}
+
+impl<T> fmt::Display for TypedError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for TypedError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TypedError")
+            .field("kind", &self.kind)
+            .field("code", &self.error.0.code)
+            .field("message", &self.error.message())
+            .finish()
+    }
+}
+
+impl<T: fmt::Debug> error::Error for TypedError<T> {}
+
 /// `GLib` error domain.
 ///
 /// This trait is implemented by error enums that represent error domains (types).
@@ -207,6 +277,22 @@ impl error::Error for BoolError {}
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::FileError;
+
+    #[test]
+    fn test_downcast() {
+        let error = Error::new(FileError::Noent, "No such file");
+        let typed = error.clone().downcast::<FileError>().unwrap();
+        assert_eq!(typed.kind(), FileError::Noent);
+        assert_eq!(typed.message(), "No such file");
+        assert_eq!(typed.to_string(), "No such file");
+        assert_eq!(Error::from(typed), error);
+
+        let error = Error::new(crate::KeyFileError::NotFound, "Not found");
+        assert_eq!(error.clone().downcast::<FileError>(), Err(error));
+    }
+
     #[test]
     fn test_bool_error() {
         let from_static_msg = glib_bool_error!("Static message");
@@ -90,10 +90,11 @@ pub use glib_macros::{clone, gflags, GBoxed, GEnum};
 pub use self::byte_array::ByteArray;
 pub use self::bytes::Bytes;
 pub use self::closure::Closure;
-pub use self::error::{BoolError, Error};
+pub use self::error::{BoolError, Error, TypedError};
 pub use self::file_error::FileError;
 pub use self::object::{
-    Cast, Class, InitiallyUnowned, IsA, Object, ObjectExt, ObjectType, SendWeakRef, WeakRef,
+    Cast, Class, InitiallyUnowned, IsA, Object, ObjectExt, ObjectType, PropertyError, SendWeakRef,
+    WeakRef,
 };
 pub use self::signal::{
     signal_handler_block, signal_handler_disconnect, signal_handler_unblock,
@@ -149,6 +150,7 @@ mod functions;
 pub use self::functions::*;
 mod key_file;
 pub mod prelude;
+#[macro_use]
 pub mod signal;
 pub mod source;
 pub use self::source::*;
@@ -8,6 +8,7 @@ use crate::quark::Quark;
 use crate::translate::*;
 use crate::types::StaticType;
 use std::cmp;
+use std::error;
 use std::fmt;
 use std::hash;
 use std::marker::PhantomData;
@@ -1176,6 +1177,66 @@ impl Object {
     }
 }
 
+/// An error returned by [`ObjectExt::get_property_as`] and [`ObjectExt::set_property_as`].
+///
+/// [`ObjectExt::get_property_as`]: trait.ObjectExt.html#tymethod.get_property_as
+/// [`ObjectExt::set_property_as`]: trait.ObjectExt.html#tymethod.set_property_as
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PropertyError {
+    /// The object type has no property with this name.
+    NotFound { type_: Type, property: String },
+    /// The property can't be read.
+    NotReadable { type_: Type, property: String },
+    /// The property can't be written, or can only be set at construction.
+    NotWritable { type_: Type, property: String },
+    /// The property has values of type `expected`, which can't be converted from or to `given`.
+    TypeMismatch {
+        type_: Type,
+        property: String,
+        expected: Type,
+        given: Type,
+    },
+    /// The value is invalid or out of range for the property.
+    InvalidValue { type_: Type, property: String },
+}
+
+impl fmt::Display for PropertyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PropertyError::NotFound { type_, property } => {
+                write!(f, "property '{}' of type '{}' not found", property, type_)
+            }
+            PropertyError::NotReadable { type_, property } => write!(
+                f,
+                "property '{}' of type '{}' is not readable",
+                property, type_
+            ),
+            PropertyError::NotWritable { type_, property } => write!(
+                f,
+                "property '{}' of type '{}' is not writable",
+                property, type_
+            ),
+            PropertyError::TypeMismatch {
+                type_,
+                property,
+                expected,
+                given,
+            } => write!(
+                f,
+                "property '{}' of type '{}' expects '{}', got '{}'",
+                property, type_, expected, given
+            ),
+            PropertyError::InvalidValue { type_, property } => write!(
+                f,
+                "property '{}' of type '{}' can't be set from given value, it is invalid or out of range",
+                property, type_
+            ),
+        }
+    }
+}
+
+impl error::Error for PropertyError {}
+
 pub trait ObjectExt: ObjectType {
     /// Returns `true` if the object is an instance of (can be cast to) `T`.
     fn is<T: StaticType>(&self) -> bool;
@@ -1196,6 +1257,19 @@ pub trait ObjectExt: ObjectType {
     fn set_properties(&self, property_values: &[(&str, &dyn ToValue)]) -> Result<(), BoolError>;
     fn set_properties_generic(&self, property_values: &[(&str, Value)]) -> Result<(), BoolError>;
     fn get_property<'a, N: Into<&'a str>>(&self, property_name: N) -> Result<Value, BoolError>;
+    /// Gets the value of the property `property_name` as a `V`, which has to be the type of the
+    /// property or one of its parent types.
+    fn get_property_as<'a, N, V>(&self, property_name: N) -> Result<Option<V>, PropertyError>
+    where
+        N: Into<&'a str>,
+        V: for<'b> crate::value::FromValueOptional<'b>;
+    /// Sets the property `property_name` to `value`, after checking that the property is
+    /// writable, that the type of `value` is the type of the property or one of its subtypes and
+    /// that the value is valid for the property.
+    fn set_property_as<'a, N, V>(&self, property_name: N, value: &V) -> Result<(), PropertyError>
+    where
+        N: Into<&'a str>,
+        V: ToValue;
     fn has_property<'a, N: Into<&'a str>>(&self, property_name: N, type_: Option<Type>) -> bool;
     fn get_property_type<'a, N: Into<&'a str>>(&self, property_name: N) -> Option<Type>;
     fn find_property<'a, N: Into<&'a str>>(&self, property_name: N) -> Option<crate::ParamSpec>;
@@ -1289,6 +1363,20 @@ pub trait ObjectExt: ObjectType {
         name: Option<&str>,
         f: F,
     ) -> SignalHandlerId;
+    /// Connects to the notifications of the property `name`, passing its new value to `f`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object has no property `name` or if its values can't be retrieved as `V`.
+    fn connect_notify_value<V, F>(&self, name: &str, f: F) -> SignalHandlerId
+    where
+        V: for<'b> crate::value::FromValueOptional<'b> + 'static,
+        F: Fn(&Self, Option<V>) + Send + Sync + 'static;
+    /// Like `connect_notify_value`, but `f` doesn't have to be `Send` and `Sync`.
+    fn connect_notify_value_local<V, F>(&self, name: &str, f: F) -> SignalHandlerId
+    where
+        V: for<'b> crate::value::FromValueOptional<'b> + 'static,
+        F: Fn(&Self, Option<V>) + 'static;
     fn notify<'a, N: Into<&'a str>>(&self, property_name: N);
     fn notify_by_pspec(&self, pspec: &crate::ParamSpec);
 
@@ -1502,6 +1590,77 @@ impl<T: ObjectType> ObjectExt for T {
         }
     }
 
+    fn get_property_as<'a, N, V>(&self, property_name: N) -> Result<Option<V>, PropertyError>
+    where
+        N: Into<&'a str>,
+        V: for<'b> crate::value::FromValueOptional<'b>,
+    {
+        let property_name = property_name.into();
+
+        let pspec = self
+            .find_property(property_name)
+            .ok_or_else(|| PropertyError::NotFound {
+                type_: self.get_type(),
+                property: property_name.to_string(),
+            })?;
+
+        if !pspec.get_flags().contains(crate::ParamFlags::READABLE) {
+            return Err(PropertyError::NotReadable {
+                type_: self.get_type(),
+                property: property_name.to_string(),
+            });
+        }
+
+        if !pspec.get_value_type().is_a(&V::static_type()) {
+            return Err(PropertyError::TypeMismatch {
+                type_: self.get_type(),
+                property: property_name.to_string(),
+                expected: pspec.get_value_type(),
+                given: V::static_type(),
+            });
+        }
+
+        unsafe {
+            let mut value = Value::from_type(pspec.get_value_type());
+            gobject_ffi::g_object_get_property(
+                self.as_object_ref().to_glib_none().0,
+                property_name.to_glib_none().0,
+                value.to_glib_none_mut().0,
+            );
+
+            Ok(value
+                .get::<V>()
+                .expect("property value type conformity already checked"))
+        }
+    }
+
+    fn set_property_as<'a, N, V>(&self, property_name: N, value: &V) -> Result<(), PropertyError>
+    where
+        N: Into<&'a str>,
+        V: ToValue,
+    {
+        let property_name = property_name.into();
+
+        let pspec = self
+            .find_property(property_name)
+            .ok_or_else(|| PropertyError::NotFound {
+                type_: self.get_type(),
+                property: property_name.to_string(),
+            })?;
+
+        let mut property_value = value.to_value();
+        check_property_value(self.get_type(), false, &pspec, &mut property_value)?;
+        unsafe {
+            gobject_ffi::g_object_set_property(
+                self.as_object_ref().to_glib_none().0,
+                property_name.to_glib_none().0,
+                property_value.to_glib_none().0,
+            );
+        }
+
+        Ok(())
+    }
+
     unsafe fn set_qdata<QD: 'static>(&self, key: Quark, value: QD) {
         unsafe extern "C" fn drop_value<QD>(ptr: ffi::gpointer) {
             debug_assert!(!ptr.is_null());
@@ -1645,6 +1804,32 @@ impl<T: ObjectType> ObjectExt for T {
         )
     }
 
+    fn connect_notify_value<V, F>(&self, name: &str, f: F) -> SignalHandlerId
+    where
+        V: for<'b> crate::value::FromValueOptional<'b> + 'static,
+        F: Fn(&Self, Option<V>) + Send + Sync + 'static,
+    {
+        check_notify_value_type::<V, _>(self, name);
+
+        self.connect_notify(Some(name), move |obj, pspec| {
+            let value = obj.get_property(pspec.get_name()).unwrap();
+            f(obj, value.get::<V>().unwrap())
+        })
+    }
+
+    fn connect_notify_value_local<V, F>(&self, name: &str, f: F) -> SignalHandlerId
+    where
+        V: for<'b> crate::value::FromValueOptional<'b> + 'static,
+        F: Fn(&Self, Option<V>) + 'static,
+    {
+        check_notify_value_type::<V, _>(self, name);
+
+        self.connect_notify_local(Some(name), move |obj, pspec| {
+            let value = obj.get_property(pspec.get_name()).unwrap();
+            f(obj, value.get::<V>().unwrap())
+        })
+    }
+
     fn notify<'a, N: Into<&'a str>>(&self, property_name: N) {
         let property_name = property_name.into();
 
@@ -1972,6 +2157,25 @@ impl<T: ObjectType> ObjectExt for T {
     }
 }
 
+// Check that the values of the property can be retrieved as `V` before connecting to its
+// notifications, so that a wrong type is caught right away instead of on the first change
+fn check_notify_value_type<V, O>(obj: &O, name: &str)
+where
+    V: for<'b> crate::value::FromValueOptional<'b>,
+    O: ObjectExt,
+{
+    let ptype = obj
+        .get_property_type(name)
+        .unwrap_or_else(|| panic!("property '{}' of type '{}' not found", name, obj.get_type()));
+    assert!(
+        ptype.is_a(&V::static_type()),
+        "property '{}' of type '{}' can't be retrieved as '{}'",
+        name,
+        ptype,
+        V::static_type()
+    );
+}
+
 // Validate that the given property value has an acceptable type for the given property pspec
 // and if necessary update the value
 fn validate_property_type(
@@ -1980,17 +2184,48 @@ fn validate_property_type(
     pspec: &crate::ParamSpec,
     property_value: &mut Value,
 ) -> Result<(), BoolError> {
+    check_property_value(type_, allow_construct_only, pspec, property_value).map_err(|err| {
+        match err {
+            // Object values are checked against the type of the contained object
+            PropertyError::TypeMismatch {
+                expected, given, ..
+            } if given.is_a(&Object::static_type()) => glib_bool_error!(
+                "property '{}' of type '{}' can't be set from the given object type (expected: '{}', got: '{}')",
+                pspec.get_name(),
+                type_,
+                expected,
+                given,
+            ),
+            PropertyError::TypeMismatch {
+                expected, given, ..
+            } => glib_bool_error!(
+                "property '{}' of type '{}' can't be set from the given type (expected: '{}', got: '{}')",
+                pspec.get_name(),
+                type_,
+                expected,
+                given,
+            ),
+            err => glib_bool_error!("{}", err),
+        }
+    })
+}
+
+fn check_property_value(
+    type_: Type,
+    allow_construct_only: bool,
+    pspec: &crate::ParamSpec,
+    property_value: &mut Value,
+) -> Result<(), PropertyError> {
     if !pspec.get_flags().contains(crate::ParamFlags::WRITABLE)
         || (!allow_construct_only
             && pspec
                 .get_flags()
                 .contains(crate::ParamFlags::CONSTRUCT_ONLY))
     {
-        return Err(glib_bool_error!(
-            "property '{}' of type '{}' is not writable",
-            pspec.get_name(),
-            type_
-        ));
+        return Err(PropertyError::NotWritable {
+            type_,
+            property: pspec.get_name().to_string(),
+        });
     }
 
     unsafe {
@@ -2013,15 +2248,12 @@ fn validate_property_type(
                     if obj.get_type().is_a(&pspec.get_value_type()) {
                         property_value.0.g_type = pspec.get_value_type().to_glib();
                     } else {
-                        return Err(
-                            glib_bool_error!(
-                                "property '{}' of type '{}' can't be set from the given object type (expected: '{}', got: '{}')",
-                                pspec.get_name(),
-                                type_,
-                                pspec.get_value_type(),
-                                obj.get_type(),
-                            )
-                        );
+                        return Err(PropertyError::TypeMismatch {
+                            type_,
+                            property: pspec.get_name().to_string(),
+                            expected: pspec.get_value_type(),
+                            given: obj.get_type(),
+                        });
                     }
                 }
                 Ok(None) => {
@@ -2031,13 +2263,12 @@ fn validate_property_type(
                 Err(_) => unreachable!("property_value type conformity already checked"),
             }
         } else if !valid_type {
-            return Err(glib_bool_error!(format!(
-                "property '{}' of type '{}' can't be set from the given type (expected: '{}', got: '{}')",
-                pspec.get_name(),
+            return Err(PropertyError::TypeMismatch {
                 type_,
-                pspec.get_value_type(),
-                property_value.type_(),
-            )));
+                property: pspec.get_name().to_string(),
+                expected: pspec.get_value_type(),
+                given: property_value.type_(),
+            });
         }
 
         let changed: bool = from_glib(gobject_ffi::g_param_value_validate(
@@ -2048,11 +2279,10 @@ fn validate_property_type(
             .get_flags()
             .contains(crate::ParamFlags::LAX_VALIDATION);
         if changed && !change_allowed {
-            return Err(glib_bool_error!(
-                "property '{}' of type '{}' can't be set from given value, it is invalid or out of range",
-                pspec.get_name(),
+            return Err(PropertyError::InvalidValue {
                 type_,
-            ));
+                property: pspec.get_name().to_string(),
+            });
         }
     }
 
@@ -2244,6 +2474,20 @@ impl<T: ObjectType> WeakRef<T> {
             }
         }
     }
+
+    /// Makes the weak reference point to `obj`, or to nothing.
+    ///
+    /// This allows to keep a `WeakRef` in a field, e.g. of a subclass implementation, and to
+    /// update it when the referenced object changes.
+    pub fn set(&self, obj: Option<&T>) {
+        unsafe {
+            gobject_ffi::g_weak_ref_set(
+                mut_override(Pin::as_ref(&self.0).get_ref()),
+                obj.map(|obj| obj.as_object_ref().to_glib_none().0)
+                    .unwrap_or(ptr::null_mut()),
+            );
+        }
+    }
 }
 
 impl<T: ObjectType> Drop for WeakRef<T> {
@@ -2365,12 +2609,10 @@ impl<'a> BindingBuilder<'a> {
         }
     }
 
-    fn transform_closure<
-        F: Fn(&crate::Binding, &Value) -> Option<Value> + Send + Sync + 'static,
-    >(
+    fn transform_callback<F: Fn(&crate::Binding, &Value) -> Option<Value> + 'static>(
         func: F,
-    ) -> crate::Closure {
-        crate::Closure::new(move |values| {
+    ) -> impl Fn(&[Value]) -> Option<Value> + 'static {
+        move |values| {
             assert_eq!(values.len(), 3);
             let binding = values[0].get::<crate::Binding>().unwrap_or_else(|_| {
                 panic!(
@@ -2402,7 +2644,15 @@ impl<'a> BindingBuilder<'a> {
                     Some(true.to_value())
                 }
             }
-        })
+        }
+    }
+
+    fn transform_closure<
+        F: Fn(&crate::Binding, &Value) -> Option<Value> + Send + Sync + 'static,
+    >(
+        func: F,
+    ) -> crate::Closure {
+        crate::Closure::new(Self::transform_callback(func))
     }
 
     pub fn transform_from<
@@ -2427,6 +2677,32 @@ impl<'a> BindingBuilder<'a> {
         }
     }
 
+    /// Like `transform_from`, for closures which are not `Send`, like ones capturing widgets.
+    ///
+    /// The binding must then be updated only from the thread it was created in.
+    pub fn transform_from_local<F: Fn(&crate::Binding, &Value) -> Option<Value> + 'static>(
+        self,
+        func: F,
+    ) -> Self {
+        Self {
+            transform_from: Some(crate::Closure::new_local(Self::transform_callback(func))),
+            ..self
+        }
+    }
+
+    /// Like `transform_to`, for closures which are not `Send`, like ones capturing widgets.
+    ///
+    /// The binding must then be updated only from the thread it was created in.
+    pub fn transform_to_local<F: Fn(&crate::Binding, &Value) -> Option<Value> + 'static>(
+        self,
+        func: F,
+    ) -> Self {
+        Self {
+            transform_to: Some(crate::Closure::new_local(Self::transform_callback(func))),
+            ..self
+        }
+    }
+
     pub fn flags(self, flags: crate::BindingFlags) -> Self {
         Self { flags, ..self }
     }
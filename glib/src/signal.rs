@@ -109,3 +109,95 @@ pub fn signal_stop_emission_by_name<T: ObjectType>(instance: &T, signal_name: &s
         );
     }
 }
+
+/// Generates typed `connect_*` and `emit_*` methods for signals registered by a subclass.
+///
+/// Every signal is described by its name, the names of the two methods and the signature of
+/// the emit method. An optional return type is the return type of the signal, the handlers
+/// passed to the connect method have to return it.
+///
+/// ```ignore
+/// glib_signal_methods! {
+///     impl SimpleObject {
+///         "name-changed" => fn connect_name_changed, fn emit_name_changed(name: String);
+///         "create-string" => fn connect_create_string, fn emit_create_string() -> String;
+///     }
+/// }
+///
+/// obj.connect_name_changed(|obj, name| println!("{:?} is now called {}", obj, name));
+/// obj.emit_name_changed("new-name".to_string());
+/// ```
+///
+/// The arguments and return values are retrieved with `Value::get`, so `NULL` values panic.
+/// Handlers aren't required to be `Send`, so they can only be connected from the thread the
+/// object is used on.
+#[macro_export]
+macro_rules! glib_signal_methods {
+    (impl $name:ty {
+        $($signal:literal => fn $connect:ident, fn $emit:ident($($arg:ident: $arg_ty:ty),* $(,)?) $(-> $ret:ty)?;)*
+    }) => {
+        impl $name {
+            $(
+                $crate::glib_signal_methods!(
+                    @methods $signal, $connect, $emit, ($($arg: $arg_ty),*) $(-> $ret)?
+                );
+            )*
+        }
+    };
+
+    (@methods $signal:literal, $connect:ident, $emit:ident, ($($arg:ident: $arg_ty:ty),*)) => {
+        pub fn $connect<F: Fn(&Self $(, $arg_ty)*) + 'static>(&self, f: F) -> $crate::SignalHandlerId {
+            $crate::ObjectExt::connect_local(self, $signal, false, move |values| {
+                #[allow(unused_mut)]
+                let (obj, mut _args) = $crate::glib_signal_methods!(@args values);
+                $(let $arg = $crate::glib_signal_methods!(@arg _args, $arg_ty);)*
+                f(&obj $(, $arg)*);
+                None
+            })
+            .unwrap_or_else(|err| panic!("Can't connect to signal {}: {}", $signal, err))
+        }
+
+        pub fn $emit(&self $(, $arg: $arg_ty)*) {
+            $crate::ObjectExt::emit(self, $signal, &[$(&$arg as &dyn $crate::ToValue),*])
+                .unwrap_or_else(|err| panic!("Can't emit signal {}: {}", $signal, err));
+        }
+    };
+
+    (@methods $signal:literal, $connect:ident, $emit:ident, ($($arg:ident: $arg_ty:ty),*) -> $ret:ty) => {
+        pub fn $connect<F: Fn(&Self $(, $arg_ty)*) -> $ret + 'static>(&self, f: F) -> $crate::SignalHandlerId {
+            $crate::ObjectExt::connect_local(self, $signal, false, move |values| {
+                #[allow(unused_mut)]
+                let (obj, mut _args) = $crate::glib_signal_methods!(@args values);
+                $(let $arg = $crate::glib_signal_methods!(@arg _args, $arg_ty);)*
+                Some($crate::ToValue::to_value(&f(&obj $(, $arg)*)))
+            })
+            .unwrap_or_else(|err| panic!("Can't connect to signal {}: {}", $signal, err))
+        }
+
+        pub fn $emit(&self $(, $arg: $arg_ty)*) -> $ret {
+            $crate::ObjectExt::emit(self, $signal, &[$(&$arg as &dyn $crate::ToValue),*])
+                .unwrap_or_else(|err| panic!("Can't emit signal {}: {}", $signal, err))
+                .unwrap_or_else(|| panic!("Signal {} returned no value", $signal))
+                .get::<$ret>()
+                .unwrap_or_else(|err| panic!("Wrong return type for signal {}: {}", $signal, err))
+                .unwrap_or_else(|| panic!("Signal {} returned NULL", $signal))
+        }
+    };
+
+    (@args $values:ident) => {{
+        let obj = $values[0]
+            .get::<Self>()
+            .expect("Wrong instance type in signal")
+            .expect("NULL instance in signal");
+        (obj, $values[1..].iter())
+    }};
+
+    (@arg $args:ident, $arg_ty:ty) => {
+        $args
+            .next()
+            .expect("Missing signal argument")
+            .get::<$arg_ty>()
+            .expect("Wrong signal argument type")
+            .expect("NULL signal argument")
+    };
+}
@@ -519,6 +519,13 @@ mod test {
         pub struct SimpleObject(ObjectSubclass<imp::SimpleObject>);
     }
 
+    glib_signal_methods! {
+        impl SimpleObject {
+            "name-changed" => fn connect_name_changed, fn emit_name_changed(name: String);
+            "create-string" => fn connect_create_string, fn emit_create_string() -> String;
+        }
+    }
+
     #[repr(C)]
     pub struct DummyInterface {
         parent: gobject_ffi::GTypeInterface,
@@ -568,6 +575,23 @@ mod test {
         assert!(weak.upgrade().is_none());
     }
 
+    #[test]
+    fn test_weak_ref_set() {
+        let obj = Object::new(SimpleObject::static_type(), &[]).expect("Object::new failed");
+        let other = Object::new(SimpleObject::static_type(), &[]).expect("Object::new failed");
+
+        let weak = crate::WeakRef::new();
+        assert!(weak.upgrade().is_none());
+        weak.set(Some(&obj));
+        assert_eq!(weak.upgrade(), Some(obj.clone()));
+        weak.set(Some(&other));
+        assert_eq!(weak.upgrade(), Some(other.clone()));
+        drop(other);
+        assert!(weak.upgrade().is_none());
+        weak.set(None);
+        assert!(weak.upgrade().is_none());
+    }
+
     #[test]
     fn test_create_child_object() {
         let type_ = ChildObject::static_type();
@@ -664,6 +688,174 @@ mod test {
 
         let child = Object::new(ChildObject::static_type(), &[]).expect("Object::new failed");
         assert!(obj.set_property("child", &child).is_ok());
+
+        assert_eq!(
+            obj.get_property_as::<_, String>("name")
+                .expect("Failed to get 'name' property as String"),
+            Some(String::from("test"))
+        );
+        assert_eq!(
+            obj.get_property_as::<_, bool>("constructed")
+                .expect("Failed to get 'constructed' property as bool"),
+            Some(true)
+        );
+        assert_eq!(
+            obj.get_property_as::<_, Object>("child")
+                .expect("Failed to get 'child' property as Object"),
+            Some(child)
+        );
+        assert_eq!(
+            obj.get_property_as::<_, bool>("name")
+                .expect_err("Got 'name' property as bool"),
+            crate::PropertyError::TypeMismatch {
+                type_: SimpleObject::static_type(),
+                property: String::from("name"),
+                expected: String::static_type(),
+                given: bool::static_type(),
+            }
+        );
+        assert_eq!(
+            obj.get_property_as::<_, String>("missing")
+                .expect_err("Got 'missing' property"),
+            crate::PropertyError::NotFound {
+                type_: SimpleObject::static_type(),
+                property: String::from("missing"),
+            }
+        );
+    }
+
+    #[test]
+    fn test_set_property_as() {
+        let obj = Object::new(SimpleObject::static_type(), &[("construct-name", &"meh")])
+            .expect("Object::new failed");
+
+        assert!(obj.set_property_as("name", &"test").is_ok());
+        assert_eq!(
+            obj.get_property_as::<_, String>("name")
+                .expect("Failed to get 'name' property as String"),
+            Some(String::from("test"))
+        );
+
+        let child = Object::new(ChildObject::static_type(), &[]).expect("Object::new failed");
+        assert!(obj.set_property_as("child", &child).is_ok());
+        assert_eq!(
+            obj.get_property_as::<_, Object>("child")
+                .expect("Failed to get 'child' property as Object"),
+            Some(child)
+        );
+
+        assert_eq!(
+            obj.set_property_as("missing", &"test")
+                .expect_err("Set 'missing' property"),
+            crate::PropertyError::NotFound {
+                type_: SimpleObject::static_type(),
+                property: String::from("missing"),
+            }
+        );
+        assert_eq!(
+            obj.set_property_as("construct-name", &"test")
+                .expect_err("Set 'construct-name' property"),
+            crate::PropertyError::NotWritable {
+                type_: SimpleObject::static_type(),
+                property: String::from("construct-name"),
+            }
+        );
+        assert_eq!(
+            obj.set_property_as("constructed", &false)
+                .expect_err("Set 'constructed' property"),
+            crate::PropertyError::NotWritable {
+                type_: SimpleObject::static_type(),
+                property: String::from("constructed"),
+            }
+        );
+        assert_eq!(
+            obj.set_property_as("name", &true)
+                .expect_err("Set 'name' property from a bool"),
+            crate::PropertyError::TypeMismatch {
+                type_: SimpleObject::static_type(),
+                property: String::from("name"),
+                expected: String::static_type(),
+                given: bool::static_type(),
+            }
+        );
+
+        let other_obj = Object::new(SimpleObject::static_type(), &[]).expect("Object::new failed");
+        let error = obj
+            .set_property_as("child", &other_obj)
+            .expect_err("Set 'child' property from a SimpleObject");
+        assert_eq!(
+            error,
+            crate::PropertyError::TypeMismatch {
+                type_: SimpleObject::static_type(),
+                property: String::from("child"),
+                expected: ChildObject::static_type(),
+                given: SimpleObject::static_type(),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "property 'child' of type 'SimpleObject' expects 'ChildObject', got 'SimpleObject'",
+        );
+    }
+
+    #[test]
+    fn test_bind_property_local() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let source = Object::new(SimpleObject::static_type(), &[]).expect("Object::new failed");
+        let target = Object::new(SimpleObject::static_type(), &[]).expect("Object::new failed");
+
+        // `Rc` is not `Send`, so this requires a local transform closure.
+        let transforms = Rc::new(Cell::new(0));
+        let transforms_clone = transforms.clone();
+        source
+            .bind_property("name", &target, "name")
+            .transform_to_local(move |_, value| {
+                transforms_clone.set(transforms_clone.get() + 1);
+                let name = value.get::<&str>().ok()??;
+                Some(name.to_uppercase().to_value())
+            })
+            .build()
+            .expect("Failed to bind 'name' property");
+
+        source.set_property("name", &"test").unwrap();
+        assert_eq!(
+            target
+                .get_property("name")
+                .expect("Failed to get 'name' property")
+                .get::<&str>()
+                .expect("Failed to get str from 'name' property"),
+            Some("TEST")
+        );
+        assert_eq!(transforms.get(), 1);
+    }
+
+    #[test]
+    fn test_connect_notify_value() {
+        use std::sync::{Arc, Mutex};
+
+        let obj = Object::new(SimpleObject::static_type(), &[]).expect("Object::new failed");
+
+        let names = Arc::new(Mutex::new(Vec::new()));
+        let names_clone = names.clone();
+        obj.connect_notify_value("name", move |_, name: Option<String>| {
+            names_clone.lock().unwrap().push(name);
+        });
+
+        obj.set_property("name", &"test").unwrap();
+        obj.set_property("name", &None::<&str>).unwrap();
+        assert_eq!(
+            *names.lock().unwrap(),
+            vec![Some(String::from("test")), None]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_connect_notify_value_wrong_type() {
+        let obj = Object::new(SimpleObject::static_type(), &[]).expect("Object::new failed");
+        obj.connect_notify_value_local("name", |_, _: Option<bool>| {});
     }
 
     #[test]
@@ -712,6 +904,28 @@ mod test {
         assert!(name_changed_triggered.load(Ordering::Relaxed));
     }
 
+    #[test]
+    fn test_signal_methods() {
+        use std::rc::Rc;
+
+        let obj = Object::new(SimpleObject::static_type(), &[])
+            .expect("Object::new failed")
+            .downcast::<SimpleObject>()
+            .unwrap();
+
+        let names = Rc::new(RefCell::new(Vec::new()));
+        let names_clone = names.clone();
+        obj.connect_name_changed(move |_, name| names_clone.borrow_mut().push(name));
+
+        obj.set_property("name", &"set-name")
+            .expect("Failed to set 'name' property");
+        obj.emit_name_changed("emitted-name".to_string());
+        assert_eq!(*RefCell::borrow(&names), vec!["set-name", "emitted-name"]);
+
+        obj.connect_create_string(|_| "return value".to_string());
+        assert_eq!(obj.emit_create_string(), "return value");
+    }
+
     #[test]
     fn test_signal_return_expected_type() {
         let obj = Object::new(SimpleObject::static_type(), &[]).expect("Object::new failed");
@@ -40,9 +40,15 @@ fn check_features() {
     // as configuration variables.
     // For reference, the backend set at time of writing consists of:
     // x11 win32 quartz broadway wayland
+    let mut backends = Vec::new();
     if let Ok(targets) = pkg_config::get_variable("gtk+-3.0", "targets") {
-        for target in targets.split_whitespace() {
-            println!("cargo:rustc-cfg=gdk_backend=\"{}\"", target);
-        }
+        backends.extend(targets.split_whitespace().map(String::from));
+    }
+    // The `x11` feature enables the X11 bindings even if pkg-config doesn't list the backend.
+    if cfg!(feature = "x11") && !backends.iter().any(|backend| backend == "x11") {
+        backends.push(String::from("x11"));
+    }
+    for backend in backends {
+        println!("cargo:rustc-cfg=gdk_backend=\"{}\"", backend);
     }
 }
@@ -7,6 +7,7 @@ use crate::AccelGroup;
 use glib::object::{Cast, IsA};
 use glib::translate::*;
 use glib::ToValue;
+use std::ptr;
 
 pub trait AccelGroupExtManual: 'static {
     fn connect_accel_group<F>(
@@ -114,3 +115,39 @@ impl<O: IsA<AccelGroup>> AccelGroupExtManual for O {
         closure
     }
 }
+
+// rustdoc-stripper-ignore-next
+/// Parses an accelerator like `"<Control>q"` into its key, the hardware keycodes producing
+/// that key with the current keymap, and its modifiers.
+///
+/// Returns `None` if `accelerator` can't be parsed.
+pub fn accelerator_parse_with_keycode(
+    accelerator: &str,
+) -> Option<(u32, Vec<u32>, gdk::ModifierType)> {
+    assert_initialized_main_thread!();
+    unsafe {
+        let mut accelerator_key = 0;
+        let mut accelerator_codes = ptr::null_mut();
+        let mut accelerator_mods = 0;
+        ffi::gtk_accelerator_parse_with_keycode(
+            accelerator.to_glib_none().0,
+            &mut accelerator_key,
+            &mut accelerator_codes,
+            &mut accelerator_mods,
+        );
+        // The key and the codes are left unset when parsing fails.
+        if accelerator_key == 0 && accelerator_codes.is_null() {
+            return None;
+        }
+        let mut codes = Vec::new();
+        if !accelerator_codes.is_null() {
+            let mut code = accelerator_codes;
+            while *code != 0 {
+                codes.push(*code);
+                code = code.add(1);
+            }
+            glib::ffi::g_free(accelerator_codes as glib::ffi::gpointer);
+        }
+        Some((accelerator_key, codes, from_glib(accelerator_mods)))
+    }
+}
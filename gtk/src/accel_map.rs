@@ -0,0 +1,75 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::AccelMap;
+use glib::translate::*;
+use libc::{c_char, c_uint};
+use std::mem;
+
+impl AccelMap {
+    // rustdoc-stripper-ignore-next
+    /// Calls `foreach_func` with each accel path of the map that isn't filtered out, its
+    /// accelerator and whether it was changed from the default one.
+    #[doc(alias = "gtk_accel_map_foreach")]
+    pub fn foreach<F: FnMut(&str, u32, gdk::ModifierType, bool)>(foreach_func: F) {
+        assert_initialized_main_thread!();
+        let mut foreach_func = foreach_func;
+        unsafe {
+            ffi::gtk_accel_map_foreach(
+                &mut foreach_func as *mut F as glib::ffi::gpointer,
+                Some(foreach_trampoline::<F>),
+            );
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Like `foreach`, but including the accel paths filtered out with `add_filter`.
+    #[doc(alias = "gtk_accel_map_foreach_unfiltered")]
+    pub fn foreach_unfiltered<F: FnMut(&str, u32, gdk::ModifierType, bool)>(foreach_func: F) {
+        assert_initialized_main_thread!();
+        let mut foreach_func = foreach_func;
+        unsafe {
+            ffi::gtk_accel_map_foreach_unfiltered(
+                &mut foreach_func as *mut F as glib::ffi::gpointer,
+                Some(foreach_trampoline::<F>),
+            );
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the accelerator of `accel_path`, or `None` if the path isn't in the map.
+    #[doc(alias = "gtk_accel_map_lookup_entry")]
+    pub fn lookup_entry(accel_path: &str) -> Option<(u32, gdk::ModifierType)> {
+        assert_initialized_main_thread!();
+        unsafe {
+            let mut key = mem::MaybeUninit::<ffi::GtkAccelKey>::uninit();
+            let found: bool = from_glib(ffi::gtk_accel_map_lookup_entry(
+                accel_path.to_glib_none().0,
+                key.as_mut_ptr(),
+            ));
+            if found {
+                let key = key.assume_init();
+                Some((key.accel_key, from_glib(key.accel_mods)))
+            } else {
+                None
+            }
+        }
+    }
+}
+
+unsafe extern "C" fn foreach_trampoline<F: FnMut(&str, u32, gdk::ModifierType, bool)>(
+    data: glib::ffi::gpointer,
+    accel_path: *const c_char,
+    accel_key: c_uint,
+    accel_mods: gdk::ffi::GdkModifierType,
+    changed: glib::ffi::gboolean,
+) {
+    let foreach_func = &mut *(data as *mut F);
+    foreach_func(
+        &glib::GString::from_glib_borrow(accel_path),
+        accel_key,
+        from_glib(accel_mods),
+        from_glib(changed),
+    );
+}
@@ -0,0 +1,153 @@
+// This file was generated by gir (https://github.com/gtk-rs/gir)
+// from gir-files (https://github.com/gtk-rs/gir-files)
+// DO NOT EDIT
+
+use glib::object::ObjectType as ObjectType_;
+use glib::signal::connect_raw;
+use glib::signal::SignalHandlerId;
+use glib::translate::*;
+use std::boxed::Box as Box_;
+use std::fmt;
+use std::mem::transmute;
+
+glib::glib_wrapper! {
+    pub struct AccelMap(Object<ffi::GtkAccelMap, ffi::GtkAccelMapClass>);
+
+    match fn {
+        get_type => || ffi::gtk_accel_map_get_type(),
+    }
+}
+
+impl AccelMap {
+    #[doc(alias = "gtk_accel_map_add_entry")]
+    pub fn add_entry(accel_path: &str, accel_key: u32, accel_mods: gdk::ModifierType) {
+        assert_initialized_main_thread!();
+        unsafe {
+            ffi::gtk_accel_map_add_entry(
+                accel_path.to_glib_none().0,
+                accel_key,
+                accel_mods.to_glib(),
+            );
+        }
+    }
+
+    #[doc(alias = "gtk_accel_map_add_filter")]
+    pub fn add_filter(filter_pattern: &str) {
+        assert_initialized_main_thread!();
+        unsafe {
+            ffi::gtk_accel_map_add_filter(filter_pattern.to_glib_none().0);
+        }
+    }
+
+    #[doc(alias = "gtk_accel_map_change_entry")]
+    pub fn change_entry(
+        accel_path: &str,
+        accel_key: u32,
+        accel_mods: gdk::ModifierType,
+        replace: bool,
+    ) -> bool {
+        assert_initialized_main_thread!();
+        unsafe {
+            from_glib(ffi::gtk_accel_map_change_entry(
+                accel_path.to_glib_none().0,
+                accel_key,
+                accel_mods.to_glib(),
+                replace.to_glib(),
+            ))
+        }
+    }
+
+    #[doc(alias = "gtk_accel_map_get")]
+    pub fn get() -> AccelMap {
+        assert_initialized_main_thread!();
+        unsafe { from_glib_none(ffi::gtk_accel_map_get()) }
+    }
+
+    #[doc(alias = "gtk_accel_map_load")]
+    pub fn load<P: AsRef<std::path::Path>>(file_name: P) {
+        assert_initialized_main_thread!();
+        unsafe {
+            ffi::gtk_accel_map_load(file_name.as_ref().to_glib_none().0);
+        }
+    }
+
+    #[doc(alias = "gtk_accel_map_load_fd")]
+    pub fn load_fd(fd: i32) {
+        assert_initialized_main_thread!();
+        unsafe {
+            ffi::gtk_accel_map_load_fd(fd);
+        }
+    }
+
+    #[doc(alias = "gtk_accel_map_lock_path")]
+    pub fn lock_path(accel_path: &str) {
+        assert_initialized_main_thread!();
+        unsafe {
+            ffi::gtk_accel_map_lock_path(accel_path.to_glib_none().0);
+        }
+    }
+
+    #[doc(alias = "gtk_accel_map_save")]
+    pub fn save<P: AsRef<std::path::Path>>(file_name: P) {
+        assert_initialized_main_thread!();
+        unsafe {
+            ffi::gtk_accel_map_save(file_name.as_ref().to_glib_none().0);
+        }
+    }
+
+    #[doc(alias = "gtk_accel_map_save_fd")]
+    pub fn save_fd(fd: i32) {
+        assert_initialized_main_thread!();
+        unsafe {
+            ffi::gtk_accel_map_save_fd(fd);
+        }
+    }
+
+    #[doc(alias = "gtk_accel_map_unlock_path")]
+    pub fn unlock_path(accel_path: &str) {
+        assert_initialized_main_thread!();
+        unsafe {
+            ffi::gtk_accel_map_unlock_path(accel_path.to_glib_none().0);
+        }
+    }
+
+    pub fn connect_changed<F: Fn(&AccelMap, &str, u32, gdk::ModifierType) + 'static>(
+        &self,
+        f: F,
+    ) -> SignalHandlerId {
+        unsafe extern "C" fn changed_trampoline<
+            F: Fn(&AccelMap, &str, u32, gdk::ModifierType) + 'static,
+        >(
+            this: *mut ffi::GtkAccelMap,
+            accel_path: *mut libc::c_char,
+            accel_key: libc::c_uint,
+            accel_mods: gdk::ffi::GdkModifierType,
+            f: glib::ffi::gpointer,
+        ) {
+            let f: &F = &*(f as *const F);
+            f(
+                &from_glib_borrow(this),
+                &glib::GString::from_glib_borrow(accel_path),
+                accel_key,
+                from_glib(accel_mods),
+            )
+        }
+        unsafe {
+            let f: Box_<F> = Box_::new(f);
+            connect_raw(
+                self.as_ptr() as *mut _,
+                b"changed\0".as_ptr() as *const _,
+                Some(transmute::<_, unsafe extern "C" fn()>(
+                    changed_trampoline::<F> as *const (),
+                )),
+                Box_::into_raw(f),
+            )
+        }
+    }
+}
+
+impl fmt::Display for AccelMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("AccelMap")
+    }
+}
@@ -320,10 +320,6 @@ impl Clipboard {
             ))
         }
     }
-
-    //pub fn connect_owner_change<Unsupported or ignored types>(&self, f: F) -> SignalHandlerId {
-    //    Ignored event: Gdk.EventOwnerChange
-    //}
 }
 
 impl fmt::Display for Clipboard {
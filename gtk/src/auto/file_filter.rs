@@ -34,11 +34,6 @@ impl FileFilter {
         }
     }
 
-    //#[doc(alias = "gtk_file_filter_add_custom")]
-    //pub fn add_custom(&self, needed: FileFilterFlags, func: /*Unimplemented*/Fn(/*Ignored*/FileFilterInfo) -> bool, data: /*Unimplemented*/Option<Fundamental: Pointer>) {
-    //    unsafe { TODO: call ffi:gtk_file_filter_add_custom() }
-    //}
-
     #[doc(alias = "gtk_file_filter_add_mime_type")]
     pub fn add_mime_type(&self, mime_type: &str) {
         unsafe {
@@ -60,11 +55,6 @@ impl FileFilter {
         }
     }
 
-    //#[doc(alias = "gtk_file_filter_filter")]
-    //pub fn filter(&self, filter_info: /*Ignored*/&FileFilterInfo) -> bool {
-    //    unsafe { TODO: call ffi:gtk_file_filter_filter() }
-    //}
-
     #[doc(alias = "gtk_file_filter_get_name")]
     pub fn get_name(&self) -> Option<glib::GString> {
         unsafe { from_glib_none(ffi::gtk_file_filter_get_name(self.to_glib_none().0)) }
@@ -135,11 +135,6 @@ pub fn accelerator_parse(accelerator: &str) -> (u32, gdk::ModifierType) {
     }
 }
 
-//#[doc(alias = "gtk_accelerator_parse_with_keycode")]
-//pub fn accelerator_parse_with_keycode(accelerator: &str, accelerator_codes: Vec<u32>) -> (u32, gdk::ModifierType) {
-//    unsafe { TODO: call ffi:gtk_accelerator_parse_with_keycode() }
-//}
-
 #[doc(alias = "gtk_accelerator_set_default_mod_mask")]
 pub fn accelerator_set_default_mod_mask(default_mod_mask: gdk::ModifierType) {
     assert_initialized_main_thread!();
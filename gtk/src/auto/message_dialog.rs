@@ -34,18 +34,6 @@ glib::glib_wrapper! {
     }
 }
 
-impl MessageDialog {
-    //#[doc(alias = "gtk_message_dialog_new")]
-    //pub fn new<P: IsA<Window>>(parent: Option<&P>, flags: DialogFlags, type_: MessageType, buttons: ButtonsType, message_format: Option<&str>, : /*Unknown conversion*//*Unimplemented*/Fundamental: VarArgs) -> MessageDialog {
-    //    unsafe { TODO: call ffi:gtk_message_dialog_new() }
-    //}
-
-    //#[doc(alias = "gtk_message_dialog_new_with_markup")]
-    //pub fn with_markup<P: IsA<Window>>(parent: Option<&P>, flags: DialogFlags, type_: MessageType, buttons: ButtonsType, message_format: Option<&str>, : /*Unknown conversion*//*Unimplemented*/Fundamental: VarArgs) -> MessageDialog {
-    //    unsafe { TODO: call ffi:gtk_message_dialog_new_with_markup() }
-    //}
-}
-
 #[derive(Clone, Default)]
 pub struct MessageDialogBuilder {
     buttons: Option<ButtonsType>,
@@ -703,12 +691,6 @@ impl MessageDialogBuilder {
 pub const NONE_MESSAGE_DIALOG: Option<&MessageDialog> = None;
 
 pub trait MessageDialogExt: 'static {
-    //#[doc(alias = "gtk_message_dialog_format_secondary_markup")]
-    //fn format_secondary_markup(&self, message_format: &str, : /*Unknown conversion*//*Unimplemented*/Fundamental: VarArgs);
-
-    //#[doc(alias = "gtk_message_dialog_format_secondary_text")]
-    //fn format_secondary_text(&self, message_format: Option<&str>, : /*Unknown conversion*//*Unimplemented*/Fundamental: VarArgs);
-
     #[doc(alias = "gtk_message_dialog_get_message_area")]
     fn get_message_area(&self) -> Widget;
 
@@ -757,14 +739,6 @@ pub trait MessageDialogExt: 'static {
 }
 
 impl<O: IsA<MessageDialog>> MessageDialogExt for O {
-    //fn format_secondary_markup(&self, message_format: &str, : /*Unknown conversion*//*Unimplemented*/Fundamental: VarArgs) {
-    //    unsafe { TODO: call ffi:gtk_message_dialog_format_secondary_markup() }
-    //}
-
-    //fn format_secondary_text(&self, message_format: Option<&str>, : /*Unknown conversion*//*Unimplemented*/Fundamental: VarArgs) {
-    //    unsafe { TODO: call ffi:gtk_message_dialog_format_secondary_text() }
-    //}
-
     fn get_message_area(&self) -> Widget {
         unsafe {
             from_glib_none(ffi::gtk_message_dialog_get_message_area(
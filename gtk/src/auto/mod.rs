@@ -16,6 +16,9 @@ pub use self::accel_label::AccelLabelBuilder;
 pub use self::accel_label::AccelLabelExt;
 pub use self::accel_label::{AccelLabel, NONE_ACCEL_LABEL};
 
+mod accel_map;
+pub use self::accel_map::AccelMap;
+
 mod action_bar;
 pub use self::action_bar::ActionBarBuilder;
 pub use self::action_bar::ActionBarExt;
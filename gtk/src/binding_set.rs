@@ -0,0 +1,189 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::Widget;
+use glib::translate::*;
+use glib::IsA;
+use libc::c_long;
+use std::ffi::CString;
+use std::ptr;
+
+// rustdoc-stripper-ignore-next
+/// An argument of the signal emitted by a key binding.
+///
+/// Enumeration and flags arguments are given as `Long` with their value, or as `String` with
+/// their nick.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BindingArg {
+    Long(i64),
+    Double(f64),
+    String(String),
+}
+
+// rustdoc-stripper-ignore-next
+/// A set of key bindings, each emitting a keybinding signal on the widget which gets the key
+/// press, like `move-cursor` on a `TextView`.
+///
+/// The bindings of a widget class are used by all its instances, and override the bindings of
+/// its parent classes. Binding sets are never freed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BindingSet(ptr::NonNull<ffi::GtkBindingSet>);
+
+impl BindingSet {
+    // rustdoc-stripper-ignore-next
+    /// Creates a binding set named `name`, to be used by CSS with `-gtk-key-bindings`.
+    pub fn new(name: &str) -> BindingSet {
+        assert_initialized_main_thread!();
+        unsafe { BindingSet::from_raw(ffi::gtk_binding_set_new(name.to_glib_none().0)) }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Finds the binding set named `name`, the ones of widget classes are named like the type
+    /// of the class, e.g. `"GtkTextView"`.
+    pub fn find(name: &str) -> Option<BindingSet> {
+        assert_initialized_main_thread!();
+        unsafe {
+            ptr::NonNull::new(ffi::gtk_binding_set_find(name.to_glib_none().0)).map(BindingSet)
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the binding set of the widget class `T`, creating it if needed.
+    ///
+    /// Subclasses get theirs from `class_init` with `WidgetClassSubclassExt::binding_set`.
+    pub fn by_class<T: IsA<Widget>>() -> BindingSet {
+        assert_initialized_main_thread!();
+        let class = glib::Class::<T>::from_type(T::static_type()).unwrap();
+        unsafe { BindingSet::by_class_ptr(&*class as *const _ as glib::ffi::gpointer) }
+    }
+
+    pub(crate) unsafe fn by_class_ptr(class: glib::ffi::gpointer) -> BindingSet {
+        BindingSet::from_raw(ffi::gtk_binding_set_by_class(class))
+    }
+
+    unsafe fn from_raw(ptr: *mut ffi::GtkBindingSet) -> BindingSet {
+        BindingSet(ptr::NonNull::new(ptr).expect("GTK returned no binding set"))
+    }
+
+    fn as_ptr(&self) -> *mut ffi::GtkBindingSet {
+        self.0.as_ptr()
+    }
+
+    pub fn get_name(&self) -> glib::GString {
+        unsafe { from_glib_none((*self.as_ptr()).set_name) }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Binds `keyval` with `modifiers` to the emission of the keybinding signal `signal_name`,
+    /// replacing the previous binding of the key if any.
+    pub fn add_signal(
+        &self,
+        keyval: u32,
+        modifiers: gdk::ModifierType,
+        signal_name: &str,
+        args: &[BindingArg],
+    ) {
+        // GTK copies the arguments, strings included.
+        let strings = args
+            .iter()
+            .map(|arg| match arg {
+                BindingArg::String(s) => Some(CString::new(s.as_str()).unwrap()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let mut binding_args = args
+            .iter()
+            .zip(&strings)
+            .map(|(arg, string)| match arg {
+                BindingArg::Long(l) => ffi::GtkBindingArg {
+                    arg_type: glib::gobject_ffi::G_TYPE_LONG,
+                    d: ffi::GtkBindingArg_d {
+                        long_data: *l as c_long,
+                    },
+                },
+                BindingArg::Double(d) => ffi::GtkBindingArg {
+                    arg_type: glib::gobject_ffi::G_TYPE_DOUBLE,
+                    d: ffi::GtkBindingArg_d { double_data: *d },
+                },
+                BindingArg::String(_) => ffi::GtkBindingArg {
+                    arg_type: glib::gobject_ffi::G_TYPE_STRING,
+                    d: ffi::GtkBindingArg_d {
+                        string_data: string.as_ref().unwrap().as_ptr() as *mut _,
+                    },
+                },
+            })
+            .collect::<Vec<_>>();
+
+        unsafe {
+            let mut list = ptr::null_mut();
+            for arg in binding_args.iter_mut().rev() {
+                list = glib::ffi::g_slist_prepend(list, arg as *mut _ as glib::ffi::gpointer);
+            }
+            ffi::gtk_binding_entry_add_signall(
+                self.as_ptr(),
+                keyval,
+                modifiers.to_glib(),
+                signal_name.to_glib_none().0,
+                list,
+            );
+            glib::ffi::g_slist_free(list);
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Adds a binding described like in CSS, e.g.
+    /// `bind "<Control>d" { "delete-from-cursor" (words, 1) }`.
+    ///
+    /// This is the easiest way to load user-defined keybindings.
+    pub fn add_signal_from_string(&self, signal_desc: &str) -> Result<(), glib::BoolError> {
+        unsafe {
+            let token = ffi::gtk_binding_entry_add_signal_from_string(
+                self.as_ptr(),
+                signal_desc.to_glib_none().0,
+            );
+            if token == glib::ffi::G_TOKEN_NONE {
+                Ok(())
+            } else {
+                Err(glib::glib_bool_error!(
+                    "Failed to parse key binding `{}`",
+                    signal_desc
+                ))
+            }
+        }
+    }
+
+    pub fn remove(&self, keyval: u32, modifiers: gdk::ModifierType) {
+        unsafe {
+            ffi::gtk_binding_entry_remove(self.as_ptr(), keyval, modifiers.to_glib());
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Makes `keyval` with `modifiers` skip this binding set and the ones of the parent
+    /// classes, e.g. to let the key reach the application accelerators.
+    pub fn skip(&self, keyval: u32, modifiers: gdk::ModifierType) {
+        unsafe {
+            ffi::gtk_binding_entry_skip(self.as_ptr(), keyval, modifiers.to_glib());
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Emits the signal bound to `keyval` with `modifiers` on `object`, returning whether a
+    /// binding was found.
+    pub fn activate<P: IsA<glib::Object>>(
+        &self,
+        keyval: u32,
+        modifiers: gdk::ModifierType,
+        object: &P,
+    ) -> bool {
+        unsafe {
+            from_glib(ffi::gtk_binding_set_activate(
+                self.as_ptr(),
+                keyval,
+                modifiers.to_glib(),
+                object.as_ref().to_glib_none().0,
+            ))
+        }
+    }
+}
@@ -6,9 +6,27 @@ use crate::Clipboard;
 use crate::SelectionData;
 use crate::TargetEntry;
 use glib::ffi::gpointer;
+use glib::object::ObjectType;
+use glib::signal::{connect_raw, SignalHandlerId};
 use glib::translate::*;
 use libc::{c_char, c_uint};
 use std::boxed::Box as Box_;
+use std::convert::TryInto;
+use std::mem::transmute;
+
+// rustdoc-stripper-ignore-next
+/// The contents of a clipboard at a given time, as returned by
+/// [`Clipboard::wait_for_snapshot`](struct.Clipboard.html#method.wait_for_snapshot).
+#[derive(Clone)]
+pub struct ClipboardSnapshot {
+    // rustdoc-stripper-ignore-next
+    /// The time the current owner took the clipboard, if it tells.
+    pub timestamp: Option<u32>,
+    pub targets: Vec<gdk::Atom>,
+    pub text: Option<glib::GString>,
+    pub image: Option<gdk_pixbuf::Pixbuf>,
+    pub uris: Vec<glib::GString>,
+}
 
 impl Clipboard {
     pub fn set_with_data<F: Fn(&Clipboard, &SelectionData, u32) + 'static>(
@@ -68,6 +86,93 @@ impl Clipboard {
         success
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Fetches the timestamp, the targets, and the text, image and URIs the clipboard holds,
+    /// running a nested main loop until the owner of the clipboard answered.
+    ///
+    /// Only the formats offered by the owner are requested. This suits clipboard managers,
+    /// which save everything from their `owner-change` handler.
+    pub fn wait_for_snapshot(&self) -> ClipboardSnapshot {
+        let timestamp = self
+            .wait_for_contents(&gdk::Atom::intern("TIMESTAMP"))
+            .and_then(|data| {
+                // Format 32 data is stored as C longs on X11.
+                let data = data.get_data();
+                match data.len() {
+                    4 => Some(u32::from_ne_bytes(data[..].try_into().unwrap())),
+                    8 => Some(u64::from_ne_bytes(data[..].try_into().unwrap()) as u32),
+                    _ => None,
+                }
+            });
+
+        let targets = match self.wait_for_contents(&gdk::Atom::intern("TARGETS")) {
+            Some(targets) => targets,
+            None => {
+                return ClipboardSnapshot {
+                    timestamp,
+                    targets: Vec::new(),
+                    text: None,
+                    image: None,
+                    uris: Vec::new(),
+                }
+            }
+        };
+
+        ClipboardSnapshot {
+            timestamp,
+            targets: targets.get_targets().unwrap_or_default(),
+            text: if targets.targets_include_text() {
+                self.wait_for_text()
+            } else {
+                None
+            },
+            image: if targets.targets_include_image(false) {
+                self.wait_for_image()
+            } else {
+                None
+            },
+            uris: if targets.targets_include_uri() {
+                self.wait_for_uris()
+            } else {
+                Vec::new()
+            },
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Connects to `owner-change`, emitted when another application, or this one, takes the
+    /// clipboard.
+    ///
+    /// The event tells the time of the change with `get_selection_time`. It's only emitted when
+    /// the display supports selection notifications, see
+    /// `gdk::Display::supports_selection_notification`.
+    pub fn connect_owner_change<F: Fn(&Clipboard, &gdk::EventOwnerChange) + 'static>(
+        &self,
+        f: F,
+    ) -> SignalHandlerId {
+        unsafe extern "C" fn owner_change_trampoline<
+            F: Fn(&Clipboard, &gdk::EventOwnerChange) + 'static,
+        >(
+            this: *mut ffi::GtkClipboard,
+            event: *mut gdk::ffi::GdkEventOwnerChange,
+            f: gpointer,
+        ) {
+            let f: &F = &*(f as *const F);
+            f(&from_glib_borrow(this), &from_glib_borrow(event))
+        }
+        unsafe {
+            let f: Box_<F> = Box_::new(f);
+            connect_raw(
+                self.as_ptr() as *mut _,
+                b"owner-change\0".as_ptr() as *const _,
+                Some(transmute::<_, unsafe extern "C" fn()>(
+                    owner_change_trampoline::<F> as *const (),
+                )),
+                Box_::into_raw(f),
+            )
+        }
+    }
+
     pub fn request_uris<P: FnOnce(&Clipboard, &[glib::GString]) + 'static>(&self, callback: P) {
         let callback_data: Box_<P> = Box_::new(callback);
         unsafe extern "C" fn callback_func<P: FnOnce(&Clipboard, &[glib::GString]) + 'static>(
@@ -8,9 +8,15 @@ use gdk::RGBA;
 use glib::object::IsA;
 use glib::translate::*;
 use libc::c_int;
+use std::ptr;
 
 pub trait ColorChooserExtManual: 'static {
     fn add_palette(&self, orientation: Orientation, colors_per_line: i32, colors: &[RGBA]);
+
+    // rustdoc-stripper-ignore-next
+    /// Removes all the palettes, including the default one, e.g. before adding the palettes of
+    /// another theme with `add_palette`.
+    fn remove_palettes(&self);
 }
 
 impl<O: IsA<ColorChooser>> ColorChooserExtManual for O {
@@ -25,4 +31,16 @@ impl<O: IsA<ColorChooser>> ColorChooserExtManual for O {
             )
         }
     }
+
+    fn remove_palettes(&self) {
+        unsafe {
+            ffi::gtk_color_chooser_add_palette(
+                self.as_ref().to_glib_none().0,
+                Orientation::Horizontal.to_glib(),
+                0,
+                0,
+                ptr::null_mut(),
+            )
+        }
+    }
 }
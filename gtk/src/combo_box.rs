@@ -2,13 +2,21 @@
 // See the COPYRIGHT file at the top-level directory of this distribution.
 // Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
 
-use crate::ComboBox;
-use glib::object::IsA;
+use crate::{BinExt, ComboBox, ComboBoxExt, Entry};
+use glib::object::{Cast, IsA};
 use glib::translate::*;
 
 pub trait ComboBoxExtManual: 'static {
     fn set_active(&self, index_: Option<u32>);
     fn get_active(&self) -> Option<u32>;
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the entry of a combo box created with `has-entry`, e.g. with
+    /// `ComboBox::with_entry` or `ComboBoxText::with_entry`.
+    ///
+    /// The text of the entry is the one of the `entry-text-column` of the active row, or what
+    /// the user typed if no row is active.
+    fn get_entry(&self) -> Option<Entry>;
 }
 
 impl<O: IsA<ComboBox>> ComboBoxExtManual for O {
@@ -28,4 +36,13 @@ impl<O: IsA<ComboBox>> ComboBoxExtManual for O {
             x => Some(x as _),
         }
     }
+
+    fn get_entry(&self) -> Option<Entry> {
+        if !self.as_ref().get_has_entry() {
+            return None;
+        }
+        self.as_ref()
+            .get_child()
+            .and_then(|child| child.downcast::<Entry>().ok())
+    }
 }
@@ -0,0 +1,95 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::{CssProvider, CssProviderExt, CssSection};
+use gio::FileExt;
+use glib::{IsA, ObjectExt};
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+// rustdoc-stripper-ignore-next
+/// An error found while parsing a stylesheet, with its location.
+///
+/// Lines and columns are counted from 0 like in `CssSection`, but are displayed counted from 1
+/// like in text editors.
+#[derive(Debug)]
+pub struct CssParsingError {
+    pub file: Option<gio::File>,
+    pub line: u32,
+    pub column: u32,
+    pub error: glib::Error,
+}
+
+impl CssParsingError {
+    fn new(section: Option<&CssSection>, error: glib::Error) -> Self {
+        CssParsingError {
+            file: section.and_then(|section| section.get_file()),
+            line: section.map_or(0, |section| section.get_start_line()),
+            column: section.map_or(0, |section| section.get_start_position()),
+            error,
+        }
+    }
+}
+
+impl fmt::Display for CssParsingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.file.as_ref().and_then(|file| file.get_parse_name()) {
+            Some(name) => write!(f, "{}:", name)?,
+            None => write!(f, "<data>:")?,
+        }
+        write!(f, "{}:{}: {}", self.line + 1, self.column + 1, self.error)
+    }
+}
+
+impl std::error::Error for CssParsingError {}
+
+pub trait CssProviderExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Loads `data` like `load_from_data`, returning all the errors found with their location.
+    ///
+    /// GTK keeps parsing after errors, so the valid rules of `data` are loaded even when errors
+    /// are returned.
+    ///
+    /// ```no_run
+    /// use gtk::prelude::*;
+    ///
+    /// # gtk::init().unwrap();
+    /// let provider = gtk::CssProvider::new();
+    /// if let Err(errors) = provider.load_from_data_checked(b"label { colour: red; }") {
+    ///     for error in errors {
+    ///         eprintln!("{}", error);
+    ///     }
+    /// }
+    /// ```
+    fn load_from_data_checked(&self, data: &[u8]) -> Result<(), Vec<CssParsingError>>;
+}
+
+impl<O: IsA<CssProvider>> CssProviderExtManual for O {
+    fn load_from_data_checked(&self, data: &[u8]) -> Result<(), Vec<CssParsingError>> {
+        let provider = self.as_ref();
+        let errors = Rc::new(RefCell::new(Vec::new()));
+        let errors_clone = errors.clone();
+        let handler_id = provider.connect_parsing_error(move |_, section, error| {
+            errors_clone
+                .borrow_mut()
+                .push(CssParsingError::new(Some(section), error.clone()));
+        });
+        let result = provider.load_from_data(data);
+        provider.disconnect(handler_id);
+
+        let mut errors = errors.replace(Vec::new());
+        if let Err(error) = result {
+            // Only reported through the signal by GTK, unless it failed before parsing.
+            if errors.is_empty() {
+                errors.push(CssParsingError::new(None, error));
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
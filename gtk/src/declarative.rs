@@ -0,0 +1,659 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+//! Experimental declarative widget trees.
+//!
+//! A [`Component`] describes its user interface as a tree of [`Node`]s computed from its
+//! state. [`App`] builds the widgets for the first tree and, whenever the component handled
+//! messages, patches the existing widgets to match the new tree:
+//!
+//! * properties are only set when their value changed, and reset to their default value when
+//!   they disappear from the tree,
+//! * children are matched by key, or by position when they have none, so that widgets keep
+//!   their internal state (focus, cursor position, scrolling...) across updates,
+//! * signal handlers are swapped without reconnecting.
+//!
+//! Messages sent from signal handlers are queued and handled together from the main loop, so
+//! several changes in a row result in a single patch.
+//!
+//! This module is only available with the `experimental` feature and its API may change in
+//! any release.
+//!
+//! ```no_run
+//! use gtk::declarative::{App, Component, Node};
+//! use gtk::prelude::*;
+//!
+//! struct Counter(i32);
+//!
+//! impl Component for Counter {
+//!     type Message = i32;
+//!
+//!     fn update(&mut self, delta: i32) {
+//!         self.0 += delta;
+//!     }
+//!
+//!     fn view(&self) -> Node<i32> {
+//!         Node::new::<gtk::Box>()
+//!             .property("orientation", gtk::Orientation::Vertical)
+//!             .child(Node::new::<gtk::Label>().property("label", &self.0.to_string()))
+//!             .child(
+//!                 Node::new::<gtk::Button>()
+//!                     .property("label", "Increment")
+//!                     .on("clicked", |_| Some(1)),
+//!             )
+//!     }
+//! }
+//!
+//! # gtk::init().unwrap();
+//! let window = gtk::Window::new(gtk::WindowType::Toplevel);
+//! let _app = App::mount(&window, Counter(0));
+//! window.show();
+//! ```
+
+use crate::{BoxExt, Container, ContainerExt, Widget, WidgetExt};
+use glib::object::{Cast, IsA, ObjectExt};
+use glib::signal::SignalHandlerId;
+use glib::translate::*;
+use glib::{Continue, StaticType, ToValue, Type, Value};
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::fmt;
+use std::rc::{Rc, Weak};
+
+type Handler<M> = Rc<dyn Fn(&[Value]) -> Option<M>>;
+type Dispatch<M> = Rc<dyn Fn(M)>;
+// Widget type and key, which have to be the same for a node to update a mounted widget
+type Identity<'a> = (Type, Option<&'a str>);
+
+// rustdoc-stripper-ignore-next
+/// Description of a widget: its type, properties, signal handlers and children.
+pub struct Node<M> {
+    type_: Type,
+    key: Option<String>,
+    properties: Vec<(&'static str, Value)>,
+    handlers: Vec<(&'static str, Handler<M>)>,
+    children: Vec<Node<M>>,
+}
+
+impl<M: 'static> Node<M> {
+    pub fn new<T: IsA<Widget>>() -> Self {
+        Self::with_type(T::static_type())
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Creates a node for a widget type only known at runtime.
+    ///
+    /// Panics if `type_` is not a `Widget` type.
+    pub fn with_type(type_: Type) -> Self {
+        assert!(
+            type_.is_a(&Widget::static_type()),
+            "{} is not a widget type",
+            type_
+        );
+        Node {
+            type_,
+            key: None,
+            properties: Vec::new(),
+            handlers: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Sets the key used to match this node with the same child of the previous tree.
+    ///
+    /// Keys only need to be unique among siblings.
+    pub fn key<K: Into<String>>(mut self, key: K) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    pub fn property<V: ToValue>(mut self, name: &'static str, value: V) -> Self {
+        let value = value.to_value();
+        match self.properties.iter_mut().find(|(n, _)| *n == name) {
+            Some(property) => property.1 = value,
+            None => self.properties.push((name, value)),
+        }
+        self
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Connects `f` to `signal`, the message it returns is sent to the component.
+    ///
+    /// Only signals without return value are supported.
+    pub fn on<F: Fn(&[Value]) -> Option<M> + 'static>(
+        mut self,
+        signal: &'static str,
+        f: F,
+    ) -> Self {
+        self.handlers.push((signal, Rc::new(f)));
+        self
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Adds a child, the widget of this node must be a `Container`.
+    pub fn child(mut self, child: Node<M>) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub fn children<I: IntoIterator<Item = Node<M>>>(mut self, children: I) -> Self {
+        self.children.extend(children);
+        self
+    }
+
+    fn identity(&self) -> Identity<'_> {
+        (self.type_, self.key.as_deref())
+    }
+}
+
+impl<M> fmt::Debug for Node<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Node")
+            .field("type_", &self.type_)
+            .field("key", &self.key)
+            .field("properties", &self.properties)
+            .field(
+                "handlers",
+                &self.handlers.iter().map(|(s, _)| s).collect::<Vec<_>>(),
+            )
+            .field("children", &self.children)
+            .finish()
+    }
+}
+
+struct MountedHandler<M> {
+    signal: &'static str,
+    handler: Rc<RefCell<Handler<M>>>,
+    id: SignalHandlerId,
+}
+
+struct Mounted<M> {
+    widget: Widget,
+    type_: Type,
+    key: Option<String>,
+    properties: Vec<(&'static str, Value)>,
+    handlers: Vec<MountedHandler<M>>,
+    children: Vec<Mounted<M>>,
+}
+
+impl<M: 'static> Mounted<M> {
+    fn build(node: Node<M>, dispatch: &Dispatch<M>) -> Self {
+        let widget = glib::Object::new_generic(node.type_, &node.properties)
+            .unwrap_or_else(|err| panic!("Can't create {}: {}", node.type_, err))
+            .downcast::<Widget>()
+            .expect("Node types are widget types");
+
+        let handlers = node
+            .handlers
+            .into_iter()
+            .map(|(signal, handler)| connect(&widget, signal, handler, dispatch))
+            .collect();
+
+        let children = node
+            .children
+            .into_iter()
+            .map(|child| Mounted::build(child, dispatch))
+            .collect::<Vec<_>>();
+        if !children.is_empty() {
+            let container = as_container(&widget);
+            for child in &children {
+                container.add(&child.widget);
+            }
+        }
+
+        widget.show();
+
+        Mounted {
+            widget,
+            type_: node.type_,
+            key: node.key,
+            properties: node.properties,
+            handlers,
+            children,
+        }
+    }
+
+    fn identity(&self) -> Identity<'_> {
+        (self.type_, self.key.as_deref())
+    }
+
+    fn matches(&self, node: &Node<M>) -> bool {
+        self.identity() == node.identity()
+    }
+
+    fn patch(&mut self, node: Node<M>, dispatch: &Dispatch<M>) {
+        debug_assert!(self.matches(&node));
+
+        let widget = &self.widget;
+        let (changed, removed) =
+            diff_properties(&self.properties, &node.properties, |name, old, new| {
+                values_equal(widget, name, old, new)
+            });
+        for (name, value) in changed {
+            set_property(widget, name, value);
+        }
+        for name in removed {
+            if let Some(pspec) = widget.find_property(name) {
+                set_property(widget, name, pspec.get_default_value());
+            }
+        }
+        self.properties = node.properties;
+
+        if self
+            .handlers
+            .iter()
+            .map(|h| h.signal)
+            .eq(node.handlers.iter().map(|(signal, _)| *signal))
+        {
+            for (mounted, (_, handler)) in self.handlers.iter().zip(node.handlers) {
+                *mounted.handler.borrow_mut() = handler;
+            }
+        } else {
+            for mounted in self.handlers.drain(..) {
+                self.widget.disconnect(mounted.id);
+            }
+            self.handlers = node
+                .handlers
+                .into_iter()
+                .map(|(signal, handler)| connect(&self.widget, signal, handler, dispatch))
+                .collect();
+        }
+
+        self.patch_children(node.children, dispatch);
+    }
+
+    fn patch_children(&mut self, nodes: Vec<Node<M>>, dispatch: &Dispatch<M>) {
+        if nodes.is_empty() && self.children.is_empty() {
+            return;
+        }
+
+        let positions = {
+            let old = self
+                .children
+                .iter()
+                .map(Mounted::identity)
+                .collect::<Vec<_>>();
+            let new = nodes.iter().map(Node::identity).collect::<Vec<_>>();
+            match_children(&old, &new)
+        };
+
+        let mut old = self.children.drain(..).map(Some).collect::<Vec<_>>();
+        let children = nodes
+            .into_iter()
+            .zip(positions)
+            .map(
+                |(node, position)| match position.and_then(|position| old[position].take()) {
+                    Some(mut child) => {
+                        child.patch(node, dispatch);
+                        child
+                    }
+                    None => Mounted::build(node, dispatch),
+                },
+            )
+            .collect::<Vec<_>>();
+
+        let container = as_container(&self.widget);
+        for child in old.into_iter().flatten() {
+            container.remove(&child.widget);
+        }
+
+        if let Some(box_) = container.downcast_ref::<crate::Box>() {
+            for (position, child) in children.iter().enumerate() {
+                if child.widget.get_parent().is_none() {
+                    box_.add(&child.widget);
+                }
+                box_.reorder_child(&child.widget, position as i32);
+            }
+        } else {
+            let current = container.get_children();
+            let in_order = current.len() == children.len()
+                && current
+                    .iter()
+                    .zip(&children)
+                    .all(|(widget, child)| *widget == child.widget);
+            if !in_order {
+                for widget in current {
+                    container.remove(&widget);
+                }
+                for child in &children {
+                    container.add(&child.widget);
+                }
+            }
+        }
+
+        self.children = children;
+    }
+}
+
+// Returns the properties of `new` which are missing from `old` or have another value there, and
+// the names of the properties of `old` which are missing from `new`
+fn diff_properties<'a, F>(
+    old: &[(&'static str, Value)],
+    new: &'a [(&'static str, Value)],
+    equal: F,
+) -> (Vec<&'a (&'static str, Value)>, Vec<&'static str>)
+where
+    F: Fn(&str, &Value, &Value) -> bool,
+{
+    let changed = new
+        .iter()
+        .filter(|(name, value)| match old.iter().find(|(n, _)| n == name) {
+            Some((_, old)) => !equal(name, old, value),
+            None => true,
+        })
+        .collect();
+    let removed = old
+        .iter()
+        .map(|(name, _)| *name)
+        .filter(|name| !new.iter().any(|(n, _)| n == name))
+        .collect();
+    (changed, removed)
+}
+
+// Returns, for each new child, the position of the old child it updates, or `None` if a widget
+// has to be built for it. Children with a key can be matched with any old child, the others only
+// with the old child at the same position
+fn match_children(old: &[Identity], new: &[Identity]) -> Vec<Option<usize>> {
+    let mut used = vec![false; old.len()];
+    new.iter()
+        .enumerate()
+        .map(|(index, identity)| {
+            let position = if identity.1.is_some() {
+                (0..old.len()).find(|&position| !used[position] && old[position] == *identity)
+            } else if index < old.len() && !used[index] && old[index] == *identity {
+                Some(index)
+            } else {
+                None
+            };
+            if let Some(position) = position {
+                used[position] = true;
+            }
+            position
+        })
+        .collect()
+}
+
+fn as_container(widget: &Widget) -> &Container {
+    widget
+        .downcast_ref::<Container>()
+        .unwrap_or_else(|| panic!("{} can't have children", widget.get_type()))
+}
+
+fn set_property(widget: &Widget, name: &str, value: &Value) {
+    widget
+        .set_property_generic(name, value)
+        .unwrap_or_else(|err| panic!("Can't set {}:{}: {}", widget.get_type(), name, err));
+}
+
+fn values_equal(widget: &Widget, name: &str, a: &Value, b: &Value) -> bool {
+    if a.type_() != b.type_() {
+        return false;
+    }
+    match widget.find_property(name) {
+        Some(pspec) => unsafe {
+            glib::gobject_ffi::g_param_values_cmp(
+                pspec.to_glib_none().0,
+                a.to_glib_none().0,
+                b.to_glib_none().0,
+            ) == 0
+        },
+        None => false,
+    }
+}
+
+fn connect<M: 'static>(
+    widget: &Widget,
+    signal: &'static str,
+    handler: Handler<M>,
+    dispatch: &Dispatch<M>,
+) -> MountedHandler<M> {
+    let handler = Rc::new(RefCell::new(handler));
+    let id = {
+        let handler = handler.clone();
+        let dispatch = dispatch.clone();
+        widget
+            .connect_local(signal, false, move |args| {
+                let handler = handler.borrow().clone();
+                if let Some(message) = handler(args) {
+                    dispatch(message);
+                }
+                None
+            })
+            .unwrap_or_else(|err| panic!("Can't connect to {}: {}", signal, err))
+    };
+    MountedHandler {
+        signal,
+        handler,
+        id,
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// State of a part of the user interface, and how to display it.
+pub trait Component: 'static {
+    type Message: 'static;
+
+    // rustdoc-stripper-ignore-next
+    /// Applies a message sent by a signal handler or with [`App::send`].
+    fn update(&mut self, message: Self::Message);
+
+    // rustdoc-stripper-ignore-next
+    /// Describes the widgets for the current state.
+    fn view(&self) -> Node<Self::Message>;
+}
+
+struct AppInner<C: Component> {
+    component: RefCell<C>,
+    container: Container,
+    root: RefCell<Option<Mounted<C::Message>>>,
+    queue: RefCell<VecDeque<C::Message>>,
+    scheduled: Cell<bool>,
+}
+
+// rustdoc-stripper-ignore-next
+/// A [`Component`] mounted in a container.
+///
+/// The widgets stop being updated once the `App` is dropped.
+pub struct App<C: Component>(Rc<AppInner<C>>);
+
+impl<C: Component> App<C> {
+    pub fn mount<P: IsA<Container>>(container: &P, component: C) -> Self {
+        assert_initialized_main_thread!();
+        let inner = Rc::new(AppInner {
+            component: RefCell::new(component),
+            container: container.clone().upcast(),
+            root: RefCell::new(None),
+            queue: RefCell::new(VecDeque::new()),
+            scheduled: Cell::new(false),
+        });
+
+        let node = inner.component.borrow().view();
+        let root = Mounted::build(node, &dispatcher(&inner));
+        inner.container.add(&root.widget);
+        *inner.root.borrow_mut() = Some(root);
+
+        App(inner)
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Queues a message for the component, it is handled from the main loop.
+    pub fn send(&self, message: C::Message) {
+        push(&self.0, message);
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the widget built for the root node.
+    pub fn get_widget(&self) -> Widget {
+        self.0
+            .root
+            .borrow()
+            .as_ref()
+            .expect("App is mounted")
+            .widget
+            .clone()
+    }
+
+    pub fn with_component<R, F: FnOnce(&C) -> R>(&self, f: F) -> R {
+        f(&self.0.component.borrow())
+    }
+}
+
+impl<C: Component> fmt::Debug for App<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("App")
+            .field("container", &self.0.container)
+            .finish()
+    }
+}
+
+fn dispatcher<C: Component>(inner: &Rc<AppInner<C>>) -> Dispatch<C::Message> {
+    let inner = Rc::downgrade(inner);
+    Rc::new(move |message| {
+        if let Some(inner) = inner.upgrade() {
+            push(&inner, message);
+        }
+    })
+}
+
+fn push<C: Component>(inner: &Rc<AppInner<C>>, message: C::Message) {
+    inner.queue.borrow_mut().push_back(message);
+    if !inner.scheduled.replace(true) {
+        let inner: Weak<AppInner<C>> = Rc::downgrade(inner);
+        glib::idle_add_local(move || {
+            if let Some(inner) = inner.upgrade() {
+                process(&inner);
+            }
+            Continue(false)
+        });
+    }
+}
+
+fn process<C: Component>(inner: &Rc<AppInner<C>>) {
+    inner.scheduled.set(false);
+    let messages = inner.queue.borrow_mut().drain(..).collect::<Vec<_>>();
+    if messages.is_empty() {
+        return;
+    }
+
+    {
+        let mut component = inner.component.borrow_mut();
+        for message in messages {
+            component.update(message);
+        }
+    }
+
+    let node = inner.component.borrow().view();
+    let dispatch = dispatcher(inner);
+    let mut root = inner.root.borrow_mut();
+    let root = root.as_mut().expect("App is mounted");
+    if root.matches(&node) {
+        root.patch(node, &dispatch);
+    } else {
+        let new_root = Mounted::build(node, &dispatch);
+        inner.container.remove(&root.widget);
+        inner.container.add(&new_root.widget);
+        *root = new_root;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn equal(_: &str, old: &Value, new: &Value) -> bool {
+        old.get::<i32>() == new.get::<i32>()
+    }
+
+    #[test]
+    fn diff_properties_unchanged() {
+        let properties = [("width", 1.to_value()), ("height", 2.to_value())];
+        let (changed, removed) = diff_properties(&properties, &properties, equal);
+        assert!(changed.is_empty());
+        assert!(removed.is_empty());
+    }
+
+    #[test]
+    fn diff_properties_changed() {
+        let old = [
+            ("width", 1.to_value()),
+            ("height", 2.to_value()),
+            ("spacing", 3.to_value()),
+        ];
+        let new = [
+            ("spacing", 3.to_value()),
+            ("height", 4.to_value()),
+            ("margin", 5.to_value()),
+        ];
+        let (changed, removed) = diff_properties(&old, &new, equal);
+        assert_eq!(
+            changed.iter().map(|(name, _)| *name).collect::<Vec<_>>(),
+            ["height", "margin"]
+        );
+        assert_eq!(changed[0].1.get::<i32>(), Ok(Some(4)));
+        assert_eq!(removed, ["width"]);
+    }
+
+    #[test]
+    fn diff_properties_empty() {
+        let properties = [("width", 1.to_value())];
+        let (changed, removed) = diff_properties(&[], &properties, equal);
+        assert_eq!(changed.len(), 1);
+        assert!(removed.is_empty());
+        let (changed, removed) = diff_properties(&properties, &[], equal);
+        assert!(changed.is_empty());
+        assert_eq!(removed, ["width"]);
+    }
+
+    // Any types will do as the widget types of the children
+    const A: Type = Type::I32;
+    const B: Type = Type::String;
+
+    #[test]
+    fn match_children_by_position() {
+        assert_eq!(
+            match_children(&[(A, None), (B, None)], &[(A, None), (B, None), (A, None)]),
+            [Some(0), Some(1), None]
+        );
+        // A child with another type at the same position can't be updated
+        assert_eq!(
+            match_children(&[(A, None), (B, None)], &[(B, None), (B, None)]),
+            [None, Some(1)]
+        );
+        // Children without a key don't follow removals
+        assert_eq!(
+            match_children(&[(A, None), (B, None)], &[(B, None)]),
+            [None]
+        );
+    }
+
+    #[test]
+    fn match_children_by_key() {
+        assert_eq!(
+            match_children(
+                &[(A, Some("a")), (A, Some("b")), (A, Some("c"))],
+                &[(A, Some("c")), (A, Some("a")), (A, Some("d"))],
+            ),
+            [Some(2), Some(0), None]
+        );
+        // The type has to match too
+        assert_eq!(match_children(&[(A, Some("a"))], &[(B, Some("a"))]), [None]);
+        // A key is only used for one child
+        assert_eq!(
+            match_children(&[(A, Some("a"))], &[(A, Some("a")), (A, Some("a"))]),
+            [Some(0), None]
+        );
+    }
+
+    #[test]
+    fn match_children_mixed() {
+        assert_eq!(
+            match_children(
+                &[(A, Some("a")), (B, None), (A, None)],
+                &[(B, None), (B, None), (A, Some("a"))],
+            ),
+            [None, Some(1), Some(0)]
+        );
+    }
+}
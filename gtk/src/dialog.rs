@@ -7,11 +7,17 @@ use crate::Dialog;
 use crate::DialogFlags;
 use crate::ResponseType;
 use crate::Widget;
+use crate::WidgetExt;
 use crate::Window;
+use gio::prelude::*;
 use glib::object::Cast;
 use glib::translate::*;
 use glib::IsA;
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
 use std::ptr;
+use std::rc::Rc;
 
 impl Dialog {
     pub fn with_buttons<T: IsA<Window>>(
@@ -38,6 +44,19 @@ impl Dialog {
 
 pub trait DialogExtManual: 'static {
     fn add_buttons(&self, buttons: &[(&str, ResponseType)]);
+
+    // rustdoc-stripper-ignore-next
+    /// Shows the dialog and calls `f` with the first response, without blocking like `run`.
+    ///
+    /// The dialog is left open, `f` usually closes it.
+    fn run_async<F: FnOnce(&Self, ResponseType) + 'static>(&self, f: F);
+
+    // rustdoc-stripper-ignore-next
+    /// Shows the dialog and resolves with the first response, without blocking like `run`.
+    ///
+    /// The dialog is left open once the future resolved, and hidden when the future is dropped
+    /// before a response was given.
+    fn run_future(&self) -> Pin<Box<dyn Future<Output = ResponseType> + 'static>>;
 }
 
 impl<O: IsA<Dialog>> DialogExtManual for O {
@@ -47,4 +66,59 @@ impl<O: IsA<Dialog>> DialogExtManual for O {
             O::add_button(self, text, id);
         }
     }
+
+    fn run_async<F: FnOnce(&Self, ResponseType) + 'static>(&self, f: F) {
+        let f = RefCell::new(Some(f));
+        let handler = Rc::new(RefCell::new(None));
+        let handler_clone = handler.clone();
+        let id = self.connect_response(move |dialog, response| {
+            if let Some(id) = handler_clone.borrow_mut().take() {
+                dialog.disconnect(id);
+            }
+            if let Some(f) = f.borrow_mut().take() {
+                f(dialog, response);
+            }
+        });
+        *handler.borrow_mut() = Some(id);
+
+        self.as_ref().show();
+    }
+
+    fn run_future(&self) -> Pin<Box<dyn Future<Output = ResponseType> + 'static>> {
+        let future = gio::GioFuture::new(self.as_ref(), |dialog: &Dialog, send| {
+            let cancellable = gio::Cancellable::new();
+            let send = ResponseSender(Some(send));
+            dialog.run_async(move |_, response| send.resolve(response));
+
+            let dialog = glib::SendWeakRef::from(dialog.downgrade());
+            cancellable.connect_cancelled_once(move |_| {
+                if let Some(dialog) = dialog.upgrade() {
+                    dialog.hide();
+                }
+            });
+
+            cancellable
+        });
+        Box::pin(async move { future.await.unwrap_or(ResponseType::None) })
+    }
+}
+
+// Resolves the future of `run_future` with `ResponseType::None` when the dialog is destroyed
+// before a response was given, which drops the response handler.
+struct ResponseSender(Option<gio::GioFutureResult<ResponseType, ()>>);
+
+impl ResponseSender {
+    fn resolve(mut self, response: ResponseType) {
+        if let Some(send) = self.0.take() {
+            send.resolve(Ok(response));
+        }
+    }
+}
+
+impl Drop for ResponseSender {
+    fn drop(&mut self) {
+        if let Some(send) = self.0.take() {
+            send.resolve(Err(()));
+        }
+    }
 }
@@ -0,0 +1,49 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::{DrawingArea, WidgetExt};
+use glib::object::ObjectExt;
+use glib::signal::{Inhibit, SignalHandlerId};
+use std::cell::RefCell;
+
+const DRAW_FUNC_KEY: &str = "gtk-rs-draw-func";
+
+impl DrawingArea {
+    // rustdoc-stripper-ignore-next
+    /// Sets the function drawing the content of the area, replacing the previous one, and
+    /// redraws the area.
+    ///
+    /// `draw_func` is called with the Cairo context and the allocated width and height of the
+    /// area each time it needs to be drawn. Call `queue_draw` when the state it depends on
+    /// changes.
+    pub fn set_draw_func<F: FnMut(&DrawingArea, &cairo::Context, i32, i32) + 'static>(
+        &self,
+        draw_func: F,
+    ) {
+        self.unset_draw_func();
+        let draw_func = RefCell::new(draw_func);
+        let id = self.connect_draw(move |area, cr| {
+            (*draw_func.borrow_mut())(
+                area,
+                cr,
+                area.get_allocated_width(),
+                area.get_allocated_height(),
+            );
+            Inhibit(false)
+        });
+        unsafe {
+            self.set_data(DRAW_FUNC_KEY, id);
+        }
+        self.queue_draw();
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Removes the function set with `set_draw_func`, leaving the area blank.
+    pub fn unset_draw_func(&self) {
+        if let Some(id) = unsafe { self.steal_data::<SignalHandlerId>(DRAW_FUNC_KEY) } {
+            self.disconnect(id);
+            self.queue_draw();
+        }
+    }
+}
@@ -0,0 +1,906 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use gdk::{CrossingMode, EventType, ModifierType, NotifyType};
+use glib::object::{IsA, WeakRef};
+use glib::translate::*;
+use std::cell::{Cell, RefCell};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::future::Future;
+use std::io;
+use std::path::Path;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::prelude::*;
+use crate::Window;
+
+// rustdoc-stripper-ignore-next
+/// An input event kept by an `EventRecorder`, with coordinates relative to its window.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputEvent {
+    KeyPress {
+        keyval: gdk::keys::Key,
+        keycode: u16,
+        group: u8,
+        is_modifier: bool,
+        state: ModifierType,
+    },
+    KeyRelease {
+        keyval: gdk::keys::Key,
+        keycode: u16,
+        group: u8,
+        is_modifier: bool,
+        state: ModifierType,
+    },
+    ButtonPress {
+        button: u32,
+        x: f64,
+        y: f64,
+        state: ModifierType,
+    },
+    ButtonRelease {
+        button: u32,
+        x: f64,
+        y: f64,
+        state: ModifierType,
+    },
+    Motion {
+        x: f64,
+        y: f64,
+        state: ModifierType,
+    },
+    Enter {
+        x: f64,
+        y: f64,
+        mode: CrossingMode,
+        detail: NotifyType,
+        focus: bool,
+        state: ModifierType,
+    },
+    Leave {
+        x: f64,
+        y: f64,
+        mode: CrossingMode,
+        detail: NotifyType,
+        focus: bool,
+        state: ModifierType,
+    },
+}
+
+impl InputEvent {
+    fn from_event(event: &gdk::Event) -> Option<InputEvent> {
+        let input_event = match event.get_event_type() {
+            EventType::KeyPress | EventType::KeyRelease => {
+                let key = event.downcast_ref::<gdk::EventKey>()?;
+                let (keyval, keycode, group, is_modifier, state) = (
+                    key.get_keyval(),
+                    key.get_hardware_keycode(),
+                    key.get_group(),
+                    key.get_is_modifier(),
+                    key.get_state(),
+                );
+                if event.get_event_type() == EventType::KeyPress {
+                    InputEvent::KeyPress {
+                        keyval,
+                        keycode,
+                        group,
+                        is_modifier,
+                        state,
+                    }
+                } else {
+                    InputEvent::KeyRelease {
+                        keyval,
+                        keycode,
+                        group,
+                        is_modifier,
+                        state,
+                    }
+                }
+            }
+            // The double and triple presses are synthesized by GDK, and GTK counts the clicks
+            // itself, so they don't need to be replayed.
+            EventType::ButtonPress | EventType::ButtonRelease => {
+                let button = event.downcast_ref::<gdk::EventButton>()?;
+                let (x, y) = button.get_position();
+                let (state, button) = (button.get_state(), button.get_button());
+                if event.get_event_type() == EventType::ButtonPress {
+                    InputEvent::ButtonPress {
+                        button,
+                        x,
+                        y,
+                        state,
+                    }
+                } else {
+                    InputEvent::ButtonRelease {
+                        button,
+                        x,
+                        y,
+                        state,
+                    }
+                }
+            }
+            EventType::MotionNotify => {
+                let motion = event.downcast_ref::<gdk::EventMotion>()?;
+                let (x, y) = motion.get_position();
+                InputEvent::Motion {
+                    x,
+                    y,
+                    state: motion.get_state(),
+                }
+            }
+            EventType::EnterNotify | EventType::LeaveNotify => {
+                let crossing = event.downcast_ref::<gdk::EventCrossing>()?;
+                let (x, y) = crossing.get_position();
+                let (mode, detail, focus, state) = (
+                    crossing.get_mode(),
+                    crossing.get_detail(),
+                    crossing.get_focus(),
+                    crossing.get_state(),
+                );
+                if event.get_event_type() == EventType::EnterNotify {
+                    InputEvent::Enter {
+                        x,
+                        y,
+                        mode,
+                        detail,
+                        focus,
+                        state,
+                    }
+                } else {
+                    InputEvent::Leave {
+                        x,
+                        y,
+                        mode,
+                        detail,
+                        focus,
+                        state,
+                    }
+                }
+            }
+            _ => return None,
+        };
+        Some(input_event)
+    }
+
+    fn to_event(&self, window: &gdk::Window, pointer: &gdk::Device, time: u32) -> gdk::Event {
+        let (_, origin_x, origin_y) = window.get_origin();
+        let (origin_x, origin_y) = (f64::from(origin_x), f64::from(origin_y));
+        let (type_, device) = match *self {
+            InputEvent::KeyPress { .. } => (EventType::KeyPress, pointer.get_associated_device()),
+            InputEvent::KeyRelease { .. } => {
+                (EventType::KeyRelease, pointer.get_associated_device())
+            }
+            InputEvent::ButtonPress { .. } => (EventType::ButtonPress, Some(pointer.clone())),
+            InputEvent::ButtonRelease { .. } => (EventType::ButtonRelease, Some(pointer.clone())),
+            InputEvent::Motion { .. } => (EventType::MotionNotify, Some(pointer.clone())),
+            InputEvent::Enter { .. } => (EventType::EnterNotify, Some(pointer.clone())),
+            InputEvent::Leave { .. } => (EventType::LeaveNotify, Some(pointer.clone())),
+        };
+
+        let mut event = gdk::Event::new(type_);
+        unsafe {
+            let ptr: *mut gdk::ffi::GdkEvent = event.to_glib_none_mut().0;
+            // Released by `gdk_event_free()`.
+            (*ptr).any.window = window.to_glib_full();
+            (*ptr).any.send_event = 0;
+            match *self {
+                InputEvent::KeyPress {
+                    ref keyval,
+                    keycode,
+                    group,
+                    is_modifier,
+                    state,
+                }
+                | InputEvent::KeyRelease {
+                    ref keyval,
+                    keycode,
+                    group,
+                    is_modifier,
+                    state,
+                } => {
+                    let key = &mut (*ptr).key;
+                    key.time = time;
+                    key.state = state.to_glib();
+                    key.keyval = keyval.to_glib();
+                    key.hardware_keycode = keycode;
+                    key.group = group;
+                    key.is_modifier = is_modifier as u32;
+                }
+                InputEvent::ButtonPress {
+                    button,
+                    x,
+                    y,
+                    state,
+                }
+                | InputEvent::ButtonRelease {
+                    button,
+                    x,
+                    y,
+                    state,
+                } => {
+                    let event_button = &mut (*ptr).button;
+                    event_button.time = time;
+                    event_button.x = x;
+                    event_button.y = y;
+                    event_button.x_root = origin_x + x;
+                    event_button.y_root = origin_y + y;
+                    event_button.state = state.to_glib();
+                    event_button.button = button;
+                }
+                InputEvent::Motion { x, y, state } => {
+                    let motion = &mut (*ptr).motion;
+                    motion.time = time;
+                    motion.x = x;
+                    motion.y = y;
+                    motion.x_root = origin_x + x;
+                    motion.y_root = origin_y + y;
+                    motion.state = state.to_glib();
+                }
+                InputEvent::Enter {
+                    x,
+                    y,
+                    mode,
+                    detail,
+                    focus,
+                    state,
+                }
+                | InputEvent::Leave {
+                    x,
+                    y,
+                    mode,
+                    detail,
+                    focus,
+                    state,
+                } => {
+                    let crossing = &mut (*ptr).crossing;
+                    crossing.time = time;
+                    crossing.x = x;
+                    crossing.y = y;
+                    crossing.x_root = origin_x + x;
+                    crossing.y_root = origin_y + y;
+                    crossing.mode = mode.to_glib();
+                    crossing.detail = detail.to_glib();
+                    crossing.focus = focus.to_glib();
+                    crossing.state = state.to_glib();
+                }
+            }
+        }
+        event.set_device(device.as_ref());
+        event.set_source_device(device.as_ref());
+        event
+    }
+}
+
+impl fmt::Display for InputEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            InputEvent::KeyPress {
+                ref keyval,
+                keycode,
+                group,
+                is_modifier,
+                state,
+            } => write!(
+                f,
+                "key-press {:#x} {} {} {} {:#x}",
+                **keyval,
+                keycode,
+                group,
+                is_modifier as u8,
+                state.bits()
+            ),
+            InputEvent::KeyRelease {
+                ref keyval,
+                keycode,
+                group,
+                is_modifier,
+                state,
+            } => write!(
+                f,
+                "key-release {:#x} {} {} {} {:#x}",
+                **keyval,
+                keycode,
+                group,
+                is_modifier as u8,
+                state.bits()
+            ),
+            InputEvent::ButtonPress {
+                button,
+                x,
+                y,
+                state,
+            } => write!(f, "button-press {} {} {} {:#x}", button, x, y, state.bits()),
+            InputEvent::ButtonRelease {
+                button,
+                x,
+                y,
+                state,
+            } => write!(
+                f,
+                "button-release {} {} {} {:#x}",
+                button,
+                x,
+                y,
+                state.bits()
+            ),
+            InputEvent::Motion { x, y, state } => {
+                write!(f, "motion {} {} {:#x}", x, y, state.bits())
+            }
+            InputEvent::Enter {
+                x,
+                y,
+                mode,
+                detail,
+                focus,
+                state,
+            } => write!(
+                f,
+                "enter {} {} {} {} {} {:#x}",
+                x,
+                y,
+                mode.to_glib(),
+                detail.to_glib(),
+                focus as u8,
+                state.bits()
+            ),
+            InputEvent::Leave {
+                x,
+                y,
+                mode,
+                detail,
+                focus,
+                state,
+            } => write!(
+                f,
+                "leave {} {} {} {} {} {:#x}",
+                x,
+                y,
+                mode.to_glib(),
+                detail.to_glib(),
+                focus as u8,
+                state.bits()
+            ),
+        }
+    }
+}
+
+// Parses the whitespace separated fields of a line, `None` meaning that it is invalid.
+struct Fields<'a>(std::str::SplitWhitespace<'a>);
+
+impl<'a> Fields<'a> {
+    fn next_str(&mut self) -> Option<&'a str> {
+        self.0.next()
+    }
+
+    fn next_u32(&mut self) -> Option<u32> {
+        let field = self.0.next()?;
+        if let Some(hex) = field.strip_prefix("0x") {
+            u32::from_str_radix(hex, 16).ok()
+        } else {
+            field.parse().ok()
+        }
+    }
+
+    fn next_i32(&mut self) -> Option<i32> {
+        self.0.next()?.parse().ok()
+    }
+
+    fn next_f64(&mut self) -> Option<f64> {
+        self.0.next()?.parse().ok()
+    }
+
+    fn next_bool(&mut self) -> Option<bool> {
+        match self.0.next()? {
+            "0" => Some(false),
+            "1" => Some(true),
+            _ => None,
+        }
+    }
+
+    fn next_state(&mut self) -> Option<ModifierType> {
+        self.next_u32().map(ModifierType::from_bits_truncate)
+    }
+
+    fn next_input_event(&mut self) -> Option<InputEvent> {
+        let kind = self.next_str()?;
+        let input_event = match kind {
+            "key-press" | "key-release" => {
+                let keyval = gdk::keys::Key::from(self.next_u32()?);
+                let keycode = self.next_u32()? as u16;
+                let group = self.next_u32()? as u8;
+                let is_modifier = self.next_bool()?;
+                let state = self.next_state()?;
+                if kind == "key-press" {
+                    InputEvent::KeyPress {
+                        keyval,
+                        keycode,
+                        group,
+                        is_modifier,
+                        state,
+                    }
+                } else {
+                    InputEvent::KeyRelease {
+                        keyval,
+                        keycode,
+                        group,
+                        is_modifier,
+                        state,
+                    }
+                }
+            }
+            "button-press" | "button-release" => {
+                let button = self.next_u32()?;
+                let x = self.next_f64()?;
+                let y = self.next_f64()?;
+                let state = self.next_state()?;
+                if kind == "button-press" {
+                    InputEvent::ButtonPress {
+                        button,
+                        x,
+                        y,
+                        state,
+                    }
+                } else {
+                    InputEvent::ButtonRelease {
+                        button,
+                        x,
+                        y,
+                        state,
+                    }
+                }
+            }
+            "motion" => InputEvent::Motion {
+                x: self.next_f64()?,
+                y: self.next_f64()?,
+                state: self.next_state()?,
+            },
+            "enter" | "leave" => {
+                let x = self.next_f64()?;
+                let y = self.next_f64()?;
+                let mode = unsafe { from_glib(self.next_i32()?) };
+                let detail = unsafe { from_glib(self.next_i32()?) };
+                let focus = self.next_bool()?;
+                let state = self.next_state()?;
+                if kind == "enter" {
+                    InputEvent::Enter {
+                        x,
+                        y,
+                        mode,
+                        detail,
+                        focus,
+                        state,
+                    }
+                } else {
+                    InputEvent::Leave {
+                        x,
+                        y,
+                        mode,
+                        detail,
+                        focus,
+                        state,
+                    }
+                }
+            }
+            _ => return None,
+        };
+        Some(input_event)
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// An event of a `Recording`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedEvent {
+    // rustdoc-stripper-ignore-next
+    /// The time elapsed since the previous event.
+    pub delay: Duration,
+    // rustdoc-stripper-ignore-next
+    /// The indices of the windows leading from the toplevel to the window of the event, in the
+    /// order of `gdk::Window::get_children`. Empty for the toplevel itself.
+    pub window: Vec<usize>,
+    pub event: InputEvent,
+}
+
+// rustdoc-stripper-ignore-next
+/// A sequence of input events, made by an `EventRecorder` or written by hand.
+///
+/// Recordings are stored as text, one event per line: the delay in milliseconds, the path of
+/// the window (`.` for the toplevel, `0/2/1` for the second child of the third child of its
+/// first child), the kind of event and its fields. Empty lines and lines starting with `#` are
+/// ignored.
+///
+/// ```text
+/// 0 0/1 enter 12.5 8 0 0 0 0x0
+/// 120 0/1 button-press 1 12.5 8 0x0
+/// 80 0/1 button-release 1 12.5 8 0x100
+/// 300 . key-press 0x61 38 0 0 0x0
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Recording {
+    events: Vec<RecordedEvent>,
+}
+
+impl Recording {
+    pub fn get_events(&self) -> &[RecordedEvent] {
+        &self.events
+    }
+
+    pub fn push(&mut self, event: RecordedEvent) {
+        self.events.push(event);
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Recording> {
+        fs::read_to_string(path)?
+            .parse()
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Replays the recorded events into `window`, by passing them to `main_do_event` with the
+    /// same delays between them.
+    ///
+    /// The window must have the same layout as the recorded one: the events are sent to the
+    /// windows found at the same paths, and the ones whose window doesn't exist are dropped.
+    /// The future resolves once all the events were sent, or when `window` is destroyed.
+    pub fn play<W: IsA<Window>>(&self, window: &W) -> Pin<Box<dyn Future<Output = ()> + 'static>> {
+        let events = self.events.clone();
+        let window = window.as_ref().downgrade();
+        Box::pin(async move {
+            // GTK uses the times of the events to detect double clicks, so they are kept
+            // consistent with the recorded delays.
+            let mut time = (glib::get_monotonic_time() / 1000) as u32;
+            for recorded in events {
+                if recorded.delay > Duration::from_millis(0) {
+                    glib::timeout_future(recorded.delay).await;
+                }
+                time = time.wrapping_add(recorded.delay.as_millis() as u32);
+
+                let toplevel = match window.upgrade().and_then(|window| window.get_window()) {
+                    Some(toplevel) => toplevel,
+                    None => return,
+                };
+                let target = match find_window(&toplevel, &recorded.window) {
+                    Some(target) => target,
+                    None => continue,
+                };
+                let pointer = match toplevel
+                    .get_display()
+                    .get_device_manager()
+                    .and_then(|device_manager| device_manager.get_client_pointer())
+                {
+                    Some(pointer) => pointer,
+                    None => return,
+                };
+                let mut event = recorded.event.to_event(&target, &pointer, time);
+                crate::main_do_event(&mut event);
+            }
+        })
+    }
+}
+
+impl fmt::Display for Recording {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for recorded in &self.events {
+            let path = if recorded.window.is_empty() {
+                String::from(".")
+            } else {
+                recorded
+                    .window
+                    .iter()
+                    .map(|index| index.to_string())
+                    .collect::<Vec<_>>()
+                    .join("/")
+            };
+            writeln!(
+                f,
+                "{} {} {}",
+                recorded.delay.as_millis(),
+                path,
+                recorded.event
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Recording {
+    type Err = RecordingParseError;
+
+    fn from_str(s: &str) -> Result<Recording, RecordingParseError> {
+        let mut events = Vec::new();
+        for (index, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let parse_line = || {
+                let mut fields = Fields(line.split_whitespace());
+                let delay = Duration::from_millis(u64::from(fields.next_u32()?));
+                let window = match fields.next_str()? {
+                    "." => Vec::new(),
+                    path => path
+                        .split('/')
+                        .map(|index| index.parse().ok())
+                        .collect::<Option<Vec<usize>>>()?,
+                };
+                let event = fields.next_input_event()?;
+                if fields.next_str().is_some() {
+                    return None;
+                }
+                Some(RecordedEvent {
+                    delay,
+                    window,
+                    event,
+                })
+            };
+            events.push(parse_line().ok_or(RecordingParseError { line: index + 1 })?);
+        }
+        Ok(Recording { events })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecordingParseError {
+    line: usize,
+}
+
+impl RecordingParseError {
+    // rustdoc-stripper-ignore-next
+    /// Returns the number of the invalid line, starting at 1.
+    pub fn get_line(&self) -> usize {
+        self.line
+    }
+}
+
+impl fmt::Display for RecordingParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid event on line {}", self.line)
+    }
+}
+
+impl Error for RecordingParseError {}
+
+fn window_path(toplevel: &gdk::Window, window: &gdk::Window) -> Option<Vec<usize>> {
+    let mut path = Vec::new();
+    let mut window = window.clone();
+    while window != *toplevel {
+        let parent = window.get_parent()?;
+        path.push(
+            parent
+                .get_children()
+                .iter()
+                .position(|child| *child == window)?,
+        );
+        window = parent;
+    }
+    path.reverse();
+    Some(path)
+}
+
+fn find_window(toplevel: &gdk::Window, path: &[usize]) -> Option<gdk::Window> {
+    path.iter().try_fold(toplevel.clone(), |window, &index| {
+        window.get_children().into_iter().nth(index)
+    })
+}
+
+struct RecorderInner {
+    window: WeakRef<Window>,
+    events: RefCell<Vec<RecordedEvent>>,
+    last_time: Cell<Option<u32>>,
+}
+
+impl RecorderInner {
+    fn record(&self, event: &gdk::Event, event_window: Option<&gdk::Window>) {
+        let toplevel = match self.window.upgrade().and_then(|window| window.get_window()) {
+            Some(toplevel) => toplevel,
+            None => return,
+        };
+        let window = match event_window.and_then(|window| window_path(&toplevel, window)) {
+            Some(window) => window,
+            None => return,
+        };
+        let event_time = event.get_time();
+        let input_event = match InputEvent::from_event(event) {
+            Some(input_event) => input_event,
+            None => return,
+        };
+
+        let delay = match self.last_time.replace(Some(event_time)) {
+            Some(last_time) => Duration::from_millis(u64::from(event_time.wrapping_sub(last_time))),
+            None => Duration::from_millis(0),
+        };
+        self.events.borrow_mut().push(RecordedEvent {
+            delay,
+            window,
+            event: input_event,
+        });
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// Records the key, button, motion and crossing events of a window, to replay them later with
+/// `Recording::play`, e.g. in end-to-end tests.
+///
+/// The events are traced with `gdk::set_event_trace_fn`, so only one recorder can be active at
+/// a time. Events going to other toplevels, like menus and dialogs, aren't recorded.
+///
+/// ```no_run
+/// use gtk::extras::{EventRecorder, Recording};
+/// use gtk::prelude::*;
+///
+/// # gtk::init().unwrap();
+/// let window = gtk::Window::new(gtk::WindowType::Toplevel);
+/// let recorder = EventRecorder::start(&window);
+/// // ... interact with the window ...
+/// recorder.stop().save("scenario.txt").unwrap();
+///
+/// // Later, in a test:
+/// let recording = Recording::load("scenario.txt").unwrap();
+/// glib::MainContext::default().spawn_local(recording.play(&window));
+/// ```
+pub struct EventRecorder(Rc<RecorderInner>);
+
+impl EventRecorder {
+    pub fn start<W: IsA<Window>>(window: &W) -> EventRecorder {
+        assert_initialized_main_thread!();
+        let inner = Rc::new(RecorderInner {
+            window: window.as_ref().downgrade(),
+            events: RefCell::new(Vec::new()),
+            last_time: Cell::new(None),
+        });
+        let weak = Rc::downgrade(&inner);
+        gdk::set_event_trace_fn(
+            Some(move |event: &gdk::Event, window: Option<&gdk::Window>| {
+                if let Some(inner) = weak.upgrade() {
+                    inner.record(event, window);
+                }
+            }),
+            crate::main_do_event,
+        );
+        EventRecorder(inner)
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Stops recording and returns the recorded events.
+    pub fn stop(self) -> Recording {
+        Recording {
+            events: self.0.events.replace(Vec::new()),
+        }
+    }
+}
+
+impl Drop for EventRecorder {
+    fn drop(&mut self) {
+        gdk::set_event_trace_fn(
+            None::<fn(&gdk::Event, Option<&gdk::Window>)>,
+            crate::main_do_event,
+        );
+    }
+}
+
+impl fmt::Debug for EventRecorder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("EventRecorder")
+            .field("events", &self.0.events.borrow().len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RECORDING: &str = "\
+# Clicking a button, then typing.
+0 0/1 enter 12.5 8 0 0 0 0x0
+
+120 0/1 button-press 1 12.5 8 0x0
+80 0/1 button-release 1 12.5 8 0x100
+15 0/1 leave 40 8.25 0 3 1 0x0
+300 . key-press 0x61 38 0 0 0x1
+20 . key-release 0x61 38 0 0 0x1
+5 2/0/10 motion -3 4.5 0x4
+";
+
+    #[test]
+    fn round_trip() {
+        let recording = RECORDING.parse::<Recording>().unwrap();
+        let events = recording.get_events();
+        assert_eq!(events.len(), 7);
+        assert_eq!(events[1].delay, Duration::from_millis(120));
+        assert_eq!(events[1].window, vec![0, 1]);
+        assert_eq!(
+            events[1].event,
+            InputEvent::ButtonPress {
+                button: 1,
+                x: 12.5,
+                y: 8.0,
+                state: ModifierType::empty(),
+            }
+        );
+        assert_eq!(
+            events[4].event,
+            InputEvent::KeyPress {
+                keyval: gdk::keys::constants::a,
+                keycode: 38,
+                group: 0,
+                is_modifier: false,
+                state: ModifierType::SHIFT_MASK,
+            }
+        );
+        assert!(events[4].window.is_empty());
+        assert_eq!(events[6].window, vec![2, 0, 10]);
+
+        let saved = recording.to_string();
+        assert_eq!(saved.parse::<Recording>(), Ok(recording));
+        // The comments and empty lines are dropped, the events are kept as written.
+        assert_eq!(saved.lines().count(), 7);
+        assert_eq!(saved.lines().next(), Some("0 0/1 enter 12.5 8 0 0 0 0x0"));
+    }
+
+    #[test]
+    fn empty() {
+        let recording = "\n# Nothing\n\n".parse::<Recording>().unwrap();
+        assert!(recording.get_events().is_empty());
+        assert_eq!(recording.to_string(), "");
+    }
+
+    #[test]
+    fn malformed_lines() {
+        let invalid_line = |s: &str| s.parse::<Recording>().unwrap_err().get_line();
+
+        // An unknown kind of event.
+        assert_eq!(invalid_line("0 . scroll 1 2 0x0"), 1);
+        // A missing field, after a comment and an empty line which still count.
+        assert_eq!(invalid_line("# Comment\n\n0 . motion 1 0x0"), 3);
+        // A field too many.
+        assert_eq!(invalid_line("0 . motion 1 2 0x0\n0 . motion 1 2 0x0 3"), 2);
+        // A negative delay, an invalid boolean and an invalid state.
+        assert_eq!(invalid_line("-1 . motion 1 2 0x0"), 1);
+        assert_eq!(invalid_line("0 . key-press 0x61 38 0 2 0x0"), 1);
+        assert_eq!(invalid_line("0 . motion 1 2 0xz"), 1);
+
+        let err = "0 . motion 1 2 0x0\nclick"
+            .parse::<Recording>()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "invalid event on line 2");
+    }
+
+    #[test]
+    fn window_paths() {
+        let window_of = |path: &str| {
+            format!("0 {} motion 1 2 0x0", path)
+                .parse::<Recording>()
+                .map(|recording| recording.get_events()[0].window.clone())
+        };
+
+        assert_eq!(window_of("."), Ok(vec![]));
+        assert_eq!(window_of("0"), Ok(vec![0]));
+        assert_eq!(window_of("3/0/12"), Ok(vec![3, 0, 12]));
+        // Empty, negative or non-numeric indices, and `.` within a path.
+        assert!(window_of("0/").is_err());
+        assert!(window_of("/0").is_err());
+        assert!(window_of("0//1").is_err());
+        assert!(window_of("0/-1").is_err());
+        assert!(window_of("0/a").is_err());
+        assert!(window_of("./0").is_err());
+        // The path is the second field, so a missing one takes the kind of event as a path.
+        assert_eq!(
+            "0 motion 1 2 0x0"
+                .parse::<Recording>()
+                .unwrap_err()
+                .get_line(),
+            1
+        );
+    }
+}
@@ -0,0 +1,15 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+//! Helpers for structuring applications, built on top of the bindings.
+
+mod event_recorder;
+mod store;
+mod thumbnailer;
+
+pub use self::event_recorder::{
+    EventRecorder, InputEvent, RecordedEvent, Recording, RecordingParseError,
+};
+pub use self::store::{Store, SubscriptionId};
+pub use self::thumbnailer::Thumbnailer;
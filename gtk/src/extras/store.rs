@@ -0,0 +1,138 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use glib::Continue;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::rc::{Rc, Weak};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(u64);
+
+struct StoreInner<T> {
+    state: RefCell<T>,
+    subscribers: RefCell<Vec<(SubscriptionId, Rc<dyn Fn(&T)>)>>,
+    next_id: Cell<u64>,
+    pending: Cell<bool>,
+    sender: glib::Sender<()>,
+}
+
+// rustdoc-stripper-ignore-next
+/// Application state shared between signal handlers.
+///
+/// The state is changed with [`update`](#method.update), and the subscribers are notified
+/// from the main loop once all the updates done in the meantime were applied. Notifications
+/// run at `PRIORITY_HIGH_IDLE`, before GTK redraws, so the widgets changed by subscribers are
+/// drawn in the same frame.
+///
+/// Cloning a `Store` gives another handle to the same state.
+///
+/// ```no_run
+/// use gtk::extras::Store;
+/// use gtk::prelude::*;
+///
+/// # gtk::init().unwrap();
+/// let count = Store::new(0);
+/// let label = gtk::Label::new(None);
+/// let button = gtk::Button::with_label("Increment");
+///
+/// count.subscribe(glib::clone!(@weak label => move |count| {
+///     label.set_text(&count.to_string());
+/// }));
+/// button.connect_clicked(glib::clone!(@strong count => move |_| {
+///     count.update(|count| *count += 1);
+/// }));
+/// ```
+pub struct Store<T>(Rc<StoreInner<T>>);
+
+impl<T: 'static> Store<T> {
+    pub fn new(state: T) -> Self {
+        assert_initialized_main_thread!();
+        let (sender, receiver) = glib::MainContext::channel(glib::PRIORITY_HIGH_IDLE);
+        let inner = Rc::new(StoreInner {
+            state: RefCell::new(state),
+            subscribers: RefCell::new(Vec::new()),
+            next_id: Cell::new(0),
+            pending: Cell::new(false),
+            sender,
+        });
+
+        let weak: Weak<StoreInner<T>> = Rc::downgrade(&inner);
+        receiver.attach(None, move |()| match weak.upgrade() {
+            Some(inner) => {
+                notify(&inner);
+                Continue(true)
+            }
+            None => Continue(false),
+        });
+
+        Store(inner)
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Calls `f` with the current state.
+    pub fn with<R, F: FnOnce(&T) -> R>(&self, f: F) -> R {
+        f(&self.0.state.borrow())
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Changes the state and schedules a notification of the subscribers.
+    ///
+    /// Panics if called from `with` or from a subscriber, as the state is borrowed then.
+    pub fn update<F: FnOnce(&mut T)>(&self, f: F) {
+        f(&mut self.0.state.borrow_mut());
+        if !self.0.pending.replace(true) {
+            self.0
+                .sender
+                .send(())
+                .expect("Store notifications are handled as long as the store exists");
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Adds a function called with the new state after each batch of updates.
+    pub fn subscribe<F: Fn(&T) + 'static>(&self, f: F) -> SubscriptionId {
+        let id = SubscriptionId(self.0.next_id.get());
+        self.0.next_id.set(id.0 + 1);
+        self.0.subscribers.borrow_mut().push((id, Rc::new(f)));
+        id
+    }
+
+    pub fn unsubscribe(&self, id: SubscriptionId) {
+        self.0
+            .subscribers
+            .borrow_mut()
+            .retain(|(subscriber, _)| *subscriber != id);
+    }
+}
+
+fn notify<T>(inner: &StoreInner<T>) {
+    inner.pending.set(false);
+    // Subscribers may subscribe or unsubscribe while being notified.
+    let subscribers = inner
+        .subscribers
+        .borrow()
+        .iter()
+        .map(|(_, f)| f.clone())
+        .collect::<Vec<_>>();
+    let state = inner.state.borrow();
+    for subscriber in subscribers {
+        subscriber(&state);
+    }
+}
+
+impl<T> Clone for Store<T> {
+    fn clone(&self) -> Self {
+        Store(self.0.clone())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Store<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Store")
+            .field("state", &self.0.state)
+            .field("subscribers", &self.0.subscribers.borrow().len())
+            .finish()
+    }
+}
@@ -0,0 +1,239 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use gdk_pixbuf::Pixbuf;
+use gio::prelude::*;
+use glib::Continue;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::path::PathBuf;
+use std::rc::{Rc, Weak};
+
+// A pixbuf decoded on a worker thread is only used there until it is sent whole to the main
+// thread, so it is never shared between threads.
+struct SendPixbuf(Pixbuf);
+
+unsafe impl Send for SendPixbuf {}
+
+type Decoded<K> = (K, gio::Cancellable, Result<SendPixbuf, glib::Error>);
+
+// A request in flight, with the cancellable given by the caller if any.
+struct Request {
+    cancellable: gio::Cancellable,
+    linked: Option<(gio::Cancellable, gio::CancelledHandlerId)>,
+}
+
+impl Drop for Request {
+    fn drop(&mut self) {
+        if let Some((linked, id)) = self.linked.take() {
+            linked.disconnect_cancelled(id);
+        }
+    }
+}
+
+struct ThumbnailerInner<K> {
+    pool: glib::ThreadPool,
+    sender: glib::Sender<Decoded<K>>,
+    size: i32,
+    pending: RefCell<HashMap<K, Request>>,
+    callback: Box<dyn Fn(&K, Result<Pixbuf, glib::Error>)>,
+}
+
+impl<K> Drop for ThumbnailerInner<K> {
+    fn drop(&mut self) {
+        for request in self.pending.get_mut().values() {
+            request.cancellable.cancel();
+        }
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// Loads and scales images on a pool of worker threads, for grids of thumbnails like an
+/// `IconView`.
+///
+/// Every request is identified by a key, usually the id of the model row showing the image. The
+/// callback gets the thumbnail together with its key on the main thread, so it can look up the
+/// row and set the pixbuf there. Requests for rows which are not visible anymore should be
+/// cancelled: they are skipped if they didn't start yet, and their decoding is aborted otherwise.
+///
+/// ```no_run
+/// use gtk::extras::Thumbnailer;
+/// use gtk::prelude::*;
+///
+/// # gtk::init().unwrap();
+/// let store = gtk::ListStore::new(&[gdk_pixbuf::Pixbuf::static_type()]);
+/// let thumbnailer = Thumbnailer::new(
+///     128,
+///     4,
+///     glib::clone!(@weak store => move |row: &u32, thumbnail| {
+///         if let (Ok(thumbnail), Some(iter)) = (thumbnail, store.iter_nth_child(None, *row as i32)) {
+///             store.set_value(&iter, 0, &thumbnail.to_value());
+///         }
+///     }),
+/// );
+/// thumbnailer.request(0, "image.png");
+/// ```
+pub struct Thumbnailer<K>(Rc<ThumbnailerInner<K>>);
+
+impl<K: Clone + Eq + Hash + Send + 'static> Thumbnailer<K> {
+    // rustdoc-stripper-ignore-next
+    /// Creates a thumbnailer scaling the images to fit in `size` x `size` pixels, with up to
+    /// `max_threads` images decoded at the same time.
+    pub fn new<F: Fn(&K, Result<Pixbuf, glib::Error>) + 'static>(
+        size: i32,
+        max_threads: u32,
+        callback: F,
+    ) -> Self {
+        assert_initialized_main_thread!();
+        let pool = glib::ThreadPool::new_exclusive(max_threads)
+            .expect("Failed to create the thread pool of the thumbnailer");
+        let (sender, receiver) = glib::MainContext::channel(glib::PRIORITY_DEFAULT_IDLE);
+        let inner = Rc::new(ThumbnailerInner {
+            pool,
+            sender,
+            size,
+            pending: RefCell::new(HashMap::new()),
+            callback: Box::new(callback),
+        });
+
+        let weak: Weak<ThumbnailerInner<K>> = Rc::downgrade(&inner);
+        receiver.attach(None, move |(key, cancellable, result)| {
+            let inner = match weak.upgrade() {
+                Some(inner) => inner,
+                None => return Continue(false),
+            };
+            let current = {
+                let mut pending = inner.pending.borrow_mut();
+                match pending.get(&key) {
+                    Some(request) if request.cancellable == cancellable => {
+                        pending.remove(&key).is_some()
+                    }
+                    _ => false,
+                }
+            };
+            if current && !cancellable.is_cancelled() {
+                (inner.callback)(&key, result.map(|pixbuf| pixbuf.0));
+            }
+            Continue(true)
+        });
+
+        Thumbnailer(inner)
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Requests the thumbnail of the image at `path`, replacing the request in flight for
+    /// `key` if any.
+    pub fn request<P: Into<PathBuf>>(&self, key: K, path: P) {
+        self.push(key, path.into(), None);
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Like `request`, but the request is also cancelled with `cancellable`.
+    ///
+    /// Sharing a cancellable between the requests of a page of results allows to drop all of
+    /// them at once when the user navigates away. The callback isn't called for the requests
+    /// cancelled this way.
+    pub fn request_with_cancellable<P: Into<PathBuf>>(
+        &self,
+        key: K,
+        path: P,
+        cancellable: &gio::Cancellable,
+    ) {
+        self.push(key, path.into(), Some(cancellable));
+    }
+
+    fn push(&self, key: K, path: PathBuf, linked: Option<&gio::Cancellable>) {
+        self.cancel(&key);
+        // Each request has its own cancellable, as `cancel` mustn't cancel the other requests
+        // sharing the one of the caller.
+        let cancellable = gio::Cancellable::new();
+        let linked = linked.and_then(|linked| {
+            linked
+                .connect_cancelled_once(glib::clone!(@strong cancellable => move |_| {
+                    cancellable.cancel()
+                }))
+                .map(|id| (linked.clone(), id))
+        });
+        self.0.pending.borrow_mut().insert(
+            key.clone(),
+            Request {
+                cancellable: cancellable.clone(),
+                linked,
+            },
+        );
+
+        let size = self.0.size;
+        let sender = self.0.sender.clone();
+        self.0
+            .pool
+            .push(move || {
+                let result = if cancellable.is_cancelled() {
+                    Err(glib::Error::new(
+                        gio::IOErrorEnum::Cancelled,
+                        "Thumbnail request cancelled",
+                    ))
+                } else {
+                    gio::File::new_for_path(&path)
+                        .read(Some(&cancellable))
+                        .and_then(|stream| {
+                            Pixbuf::from_stream_at_scale(
+                                &stream,
+                                size,
+                                size,
+                                true,
+                                Some(&cancellable),
+                            )
+                        })
+                        .map(|pixbuf| {
+                            // Apply the orientation stored in the EXIF data of photos.
+                            pixbuf.apply_embedded_orientation().unwrap_or(pixbuf)
+                        })
+                        .map(SendPixbuf)
+                };
+                // Always sent so that the request stops being pending, even when cancelled
+                // through its cancellable. Fails only once the thumbnailer is gone.
+                let _ = sender.send((key, cancellable, result));
+            })
+            .expect("Failed to queue the thumbnail request");
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Cancels the request in flight for `key`, its callback won't be called.
+    pub fn cancel(&self, key: &K) {
+        let request = self.0.pending.borrow_mut().remove(key);
+        if let Some(request) = request {
+            request.cancellable.cancel();
+        }
+    }
+
+    pub fn cancel_all(&self) {
+        let pending = self.0.pending.replace(HashMap::new());
+        for request in pending.values() {
+            request.cancellable.cancel();
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the number of requests whose callback wasn't called yet.
+    pub fn get_n_pending(&self) -> usize {
+        self.0.pending.borrow().len()
+    }
+}
+
+impl<K> Clone for Thumbnailer<K> {
+    fn clone(&self) -> Self {
+        Thumbnailer(self.0.clone())
+    }
+}
+
+impl<K> fmt::Debug for Thumbnailer<K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Thumbnailer")
+            .field("size", &self.0.size)
+            .field("pending", &self.0.pending.borrow().len())
+            .finish()
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::{FileFilter, FileFilterFlags};
+use glib::translate::*;
+use std::ffi::CStr;
+use std::fmt;
+use std::path::PathBuf;
+
+// rustdoc-stripper-ignore-next
+/// The information about a file given to the custom filters of a `FileFilter`.
+///
+/// Only the fields requested with the `needed` flags of `FileFilter::add_custom` are guaranteed
+/// to be set, see `get_contains`.
+pub struct FileFilterInfo<'a>(&'a ffi::GtkFileFilterInfo);
+
+impl<'a> FileFilterInfo<'a> {
+    unsafe fn str_field(ptr: *const libc::c_char) -> Option<&'a str> {
+        if ptr.is_null() {
+            None
+        } else {
+            CStr::from_ptr(ptr).to_str().ok()
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns which of the fields are set.
+    pub fn get_contains(&self) -> FileFilterFlags {
+        unsafe { from_glib(self.0.contains) }
+    }
+
+    pub fn get_filename(&self) -> Option<PathBuf> {
+        unsafe { from_glib_none(self.0.filename) }
+    }
+
+    pub fn get_uri(&self) -> Option<&'a str> {
+        unsafe { Self::str_field(self.0.uri) }
+    }
+
+    pub fn get_display_name(&self) -> Option<&'a str> {
+        unsafe { Self::str_field(self.0.display_name) }
+    }
+
+    pub fn get_mime_type(&self) -> Option<&'a str> {
+        unsafe { Self::str_field(self.0.mime_type) }
+    }
+}
+
+impl<'a> fmt::Debug for FileFilterInfo<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FileFilterInfo")
+            .field("contains", &self.get_contains())
+            .field("filename", &self.get_filename())
+            .field("uri", &self.get_uri())
+            .field("display_name", &self.get_display_name())
+            .field("mime_type", &self.get_mime_type())
+            .finish()
+    }
+}
+
+impl FileFilter {
+    // rustdoc-stripper-ignore-next
+    /// Adds a rule accepting the files for which `func` returns `true`, e.g. after sniffing
+    /// their content.
+    ///
+    /// `needed` tells which fields of the `FileFilterInfo` the function uses. Requesting only
+    /// the ones that are needed avoids expensive lookups, like the MIME type of remote files.
+    #[doc(alias = "gtk_file_filter_add_custom")]
+    pub fn add_custom<F: Fn(&FileFilterInfo) -> bool + 'static>(
+        &self,
+        needed: FileFilterFlags,
+        func: F,
+    ) {
+        unsafe extern "C" fn func_func<F: Fn(&FileFilterInfo) -> bool + 'static>(
+            filter_info: *const ffi::GtkFileFilterInfo,
+            user_data: glib::ffi::gpointer,
+        ) -> glib::ffi::gboolean {
+            let func: &F = &*(user_data as *mut F);
+            func(&FileFilterInfo(&*filter_info)).to_glib()
+        }
+        unsafe extern "C" fn destroy_func<F: Fn(&FileFilterInfo) -> bool + 'static>(
+            data: glib::ffi::gpointer,
+        ) {
+            let _ = Box::<F>::from_raw(data as *mut _);
+        }
+        let func: Box<F> = Box::new(func);
+        unsafe {
+            ffi::gtk_file_filter_add_custom(
+                self.to_glib_none().0,
+                needed.to_glib(),
+                Some(func_func::<F>),
+                Box::into_raw(func) as *mut _,
+                Some(destroy_func::<F>),
+            );
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Adds a rule for each of the `mime_types`, e.g. `&["image/png", "image/jpeg"]`.
+    pub fn add_mime_types(&self, mime_types: &[&str]) {
+        for mime_type in mime_types {
+            self.add_mime_type(mime_type);
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Adds a rule for each of the shell style glob `patterns`, e.g. `&["*.png", "*.jpg"]`.
+    pub fn add_patterns(&self, patterns: &[&str]) {
+        for pattern in patterns {
+            self.add_pattern(pattern);
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Tests whether a file passes the filter, e.g. to combine filters from a custom one.
+    #[doc(alias = "gtk_file_filter_filter")]
+    pub fn filter(&self, filter_info: &FileFilterInfo) -> bool {
+        unsafe {
+            from_glib(ffi::gtk_file_filter_filter(
+                self.to_glib_none().0,
+                filter_info.0,
+            ))
+        }
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::{IconLookupFlags, IconTheme, IconThemeError, IconThemeExt};
+use glib::object::IsA;
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::Pin;
+
+pub trait IconThemeExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Looks up the icon `icon_name` and loads it at `size` on a worker thread, like
+    /// `load_icon` without blocking the main loop.
+    ///
+    /// The lookup itself is quick, only the loading of the image is done in the background.
+    /// `callback` gets an `IconThemeError::NotFound` error if the theme has no such icon.
+    fn load_icon_async<
+        P: IsA<gio::Cancellable>,
+        Q: FnOnce(Result<gdk_pixbuf::Pixbuf, glib::Error>) + Send + 'static,
+    >(
+        &self,
+        icon_name: &str,
+        size: i32,
+        flags: IconLookupFlags,
+        cancellable: Option<&P>,
+        callback: Q,
+    );
+
+    fn load_icon_async_future(
+        &self,
+        icon_name: &str,
+        size: i32,
+        flags: IconLookupFlags,
+    ) -> Pin<Box<dyn Future<Output = Result<gdk_pixbuf::Pixbuf, glib::Error>> + 'static>>;
+}
+
+impl<O: IsA<IconTheme>> IconThemeExtManual for O {
+    fn load_icon_async<
+        P: IsA<gio::Cancellable>,
+        Q: FnOnce(Result<gdk_pixbuf::Pixbuf, glib::Error>) + Send + 'static,
+    >(
+        &self,
+        icon_name: &str,
+        size: i32,
+        flags: IconLookupFlags,
+        cancellable: Option<&P>,
+        callback: Q,
+    ) {
+        match self.as_ref().lookup_icon(icon_name, size, flags) {
+            Some(icon_info) => icon_info.load_icon_async(cancellable, callback),
+            None => {
+                let error = glib::Error::new(
+                    IconThemeError::NotFound,
+                    &format!("Icon '{}' not present in theme", icon_name),
+                );
+                // Like the other asynchronous functions, the callback is never called
+                // before returning.
+                let callback = Cell::new(Some((callback, error)));
+                glib::idle_add_local(move || {
+                    if let Some((callback, error)) = callback.take() {
+                        callback(Err(error));
+                    }
+                    glib::Continue(false)
+                });
+            }
+        }
+    }
+
+    fn load_icon_async_future(
+        &self,
+        icon_name: &str,
+        size: i32,
+        flags: IconLookupFlags,
+    ) -> Pin<Box<dyn Future<Output = Result<gdk_pixbuf::Pixbuf, glib::Error>> + 'static>> {
+        let icon_name = String::from(icon_name);
+        Box::pin(gio::GioFuture::new(self.as_ref(), move |obj, send| {
+            let cancellable = gio::Cancellable::new();
+            obj.load_icon_async(&icon_name, size, flags, Some(&cancellable), move |res| {
+                send.resolve(res);
+            });
+
+            cancellable
+        }))
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::prelude::*;
+use crate::{Align, InfoBar, Label, MessageType, ResponseType};
+use glib::IsA;
+
+impl InfoBar {
+    pub fn with_buttons(buttons: &[(&str, ResponseType)]) -> InfoBar {
+        assert_initialized_main_thread!();
+        let ret = InfoBar::new();
+        ret.add_buttons(buttons);
+        ret
+    }
+}
+
+pub trait InfoBarExtManual: 'static {
+    fn add_buttons(&self, buttons: &[(&str, ResponseType)]);
+
+    // rustdoc-stripper-ignore-next
+    /// Shows `text` in the info bar, replacing the widgets of its content area with a label,
+    /// and reveals it with `message_type`.
+    ///
+    /// Along with `connect_response`, this allows to notify the user inline, without the modal
+    /// dialog of a `MessageDialog`.
+    fn show_message(&self, message_type: MessageType, text: &str);
+}
+
+impl<O: IsA<InfoBar>> InfoBarExtManual for O {
+    fn add_buttons(&self, buttons: &[(&str, ResponseType)]) {
+        for &(text, id) in buttons {
+            self.add_button(text, id);
+        }
+    }
+
+    fn show_message(&self, message_type: MessageType, text: &str) {
+        let content_area = self.get_content_area();
+        for child in content_area.get_children() {
+            content_area.remove(&child);
+        }
+        let label = Label::new(Some(text));
+        label.set_line_wrap(true);
+        label.set_halign(Align::Start);
+        content_area.add(&label);
+        label.show();
+
+        self.set_message_type(message_type);
+        self.as_ref().show();
+        #[cfg(any(feature = "v3_22_29", feature = "dox"))]
+        self.set_revealed(true);
+    }
+}
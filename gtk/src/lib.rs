@@ -185,9 +185,11 @@ mod rt;
 mod auto;
 
 mod accel_group;
+mod accel_map;
 mod app_chooser;
 mod application;
 mod application_window;
+mod binding_set;
 mod border;
 mod buildable;
 mod builder;
@@ -196,23 +198,29 @@ mod clipboard;
 mod color_button;
 mod color_chooser;
 mod combo_box;
+mod css_provider;
 mod dialog;
 mod drag_context;
+mod drawing_area;
 mod entry;
 mod entry_buffer;
 mod entry_completion;
 mod enums;
 mod file_chooser_dialog;
+mod file_filter;
 mod fixed;
 #[cfg(any(feature = "v3_18", feature = "dox"))]
 mod flow_box;
 #[cfg(any(feature = "v3_24", feature = "dox"))]
 mod gesture_stylus;
+mod icon_theme;
 mod im_context_simple;
+mod info_bar;
 mod invisible;
 #[cfg(any(feature = "v3_16", feature = "dox"))]
 mod list_box;
 mod list_store;
+mod main_loop_guard;
 mod menu;
 mod message_dialog;
 mod notebook;
@@ -221,6 +229,7 @@ mod pad_action_entry;
 #[cfg(any(feature = "v3_22", feature = "dox"))]
 mod pad_controller;
 mod page_range;
+mod print_operation;
 mod print_settings;
 mod radio_button;
 mod radio_menu_item;
@@ -229,6 +238,8 @@ mod recent_chooser_dialog;
 mod recent_data;
 mod requisition;
 mod response_type;
+mod scale_button;
+mod search_bar;
 mod selection_data;
 mod signal;
 mod style_context;
@@ -237,12 +248,15 @@ mod target_entry;
 mod target_list;
 mod text_buffer;
 mod text_iter;
+mod text_view;
 mod tree_model_filter;
 mod tree_path;
 mod tree_row_reference;
 mod tree_sortable;
 mod tree_store;
+mod tree_view_column;
 mod widget;
+mod widget_path;
 mod window;
 
 #[macro_use]
@@ -250,6 +264,12 @@ pub mod subclass;
 
 pub mod prelude;
 
+pub mod extras;
+
+#[cfg(any(feature = "experimental", feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "experimental")))]
+pub mod declarative;
+
 pub use crate::auto::functions::*;
 pub use crate::auto::*;
 pub use crate::rt::*;
@@ -258,15 +278,24 @@ pub use crate::signal::*;
 pub use gdk::Rectangle as Allocation;
 pub use gdk::Rectangle;
 
+pub use crate::accel_group::accelerator_parse_with_keycode;
 pub use crate::app_chooser::AppChooser;
+pub use crate::binding_set::{BindingArg, BindingSet};
 pub use crate::border::Border;
+pub use crate::clipboard::ClipboardSnapshot;
+pub use crate::css_provider::CssParsingError;
 pub use crate::entry_buffer::EntryBuffer;
+pub use crate::file_filter::FileFilterInfo;
+pub use crate::main_loop_guard::MainLoopGuard;
 pub use crate::page_range::PageRange;
 pub use crate::recent_data::RecentData;
 pub use crate::requisition::Requisition;
 pub use crate::response_type::ResponseType;
 pub use crate::target_entry::TargetEntry;
 pub use crate::tree_sortable::SortColumn;
-pub use crate::widget::TickCallbackId;
+pub use crate::tree_store::TreeStoreWalk;
+pub use crate::widget::{CssProviderGuard, TickCallbackId};
+pub use crate::widget_path::{WidgetPathElement, WidgetPathIter};
+pub use crate::window::DeleteConfirmation;
 #[cfg(any(feature = "v3_22", feature = "dox"))]
 pub use pad_action_entry::PadActionEntry;
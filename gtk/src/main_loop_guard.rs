@@ -0,0 +1,69 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::{Widget, WidgetExt};
+use glib::object::IsA;
+use glib::signal::SignalHandlerId;
+use std::cell::Cell;
+use std::rc::Rc;
+
+// rustdoc-stripper-ignore-next
+/// Runs the GTK main loop and quits it at most once.
+///
+/// Calling `main_quit` from every handler that should end the program quits the main loop
+/// several times, or panics when it isn't running yet. Instead, share a `MainLoopGuard`
+/// between these handlers: only its first `quit` has an effect, and quitting before `run`
+/// makes `run` return at once.
+///
+/// Applications using `gtk::Application` should call `quit` on it instead.
+///
+/// ```no_run
+/// use gtk::prelude::*;
+///
+/// gtk::init().unwrap();
+/// let main_loop = gtk::MainLoopGuard::new();
+/// let window = gtk::Window::new(gtk::WindowType::Toplevel);
+/// let button = gtk::Button::with_label("Quit");
+/// main_loop.quit_on_destroy(&window);
+/// button.connect_clicked(glib::clone!(@strong main_loop => move |_| main_loop.quit()));
+/// window.add(&button);
+/// window.show_all();
+/// main_loop.run();
+/// ```
+#[derive(Clone, Debug)]
+pub struct MainLoopGuard(Rc<Cell<bool>>);
+
+impl MainLoopGuard {
+    pub fn new() -> MainLoopGuard {
+        assert_initialized_main_thread!();
+        MainLoopGuard(Rc::new(Cell::new(false)))
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Runs the GTK main loop until `quit` is called, or returns at once if it already was.
+    pub fn run(&self) {
+        if !self.0.get() {
+            crate::main();
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Quits the innermost GTK main loop the first time it's called, does nothing afterwards.
+    pub fn quit(&self) {
+        if !self.0.replace(true) && crate::main_level() > 0 {
+            unsafe { ffi::gtk_main_quit() }
+        }
+    }
+
+    pub fn is_quit(&self) -> bool {
+        self.0.get()
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Quits when `widget`, usually the main window, is destroyed.
+    pub fn quit_on_destroy<W: IsA<Widget>>(&self, widget: &W) -> SignalHandlerId {
+        let guard = self.clone();
+        widget.connect_destroy(move |_| guard.quit())
+    }
+}
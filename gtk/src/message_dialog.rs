@@ -5,6 +5,7 @@
 use crate::ButtonsType;
 use crate::DialogFlags;
 use crate::MessageDialog;
+use crate::MessageDialogExt;
 use crate::MessageType;
 use crate::Widget;
 use crate::Window;
@@ -36,15 +37,87 @@ impl MessageDialog {
             .unsafe_cast()
         }
     }
+
+    // rustdoc-stripper-ignore-next
+    /// Creates a new message dialog whose text is in the [Pango markup
+    /// format](https://developer.gnome.org/pango/stable/pango-Markup.html).
+    ///
+    /// The text of the dialog is set with `set_markup` after the dialog was created, so
+    /// user-provided parts of the message must be escaped with `glib::markup_escape_text`.
+    #[doc(alias = "gtk_message_dialog_new_with_markup")]
+    pub fn with_markup<T: IsA<Window>>(
+        parent: Option<&T>,
+        flags: DialogFlags,
+        type_: MessageType,
+        buttons: ButtonsType,
+        markup: &str,
+    ) -> MessageDialog {
+        let dialog = MessageDialog::new(parent, flags, type_, buttons, "");
+        dialog.set_markup(markup);
+        dialog
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Creates a modal dialog asking `markup` with the `buttons`, e.g. `ButtonsType::YesNo`.
+    ///
+    /// The answer is given by `DialogExtManual::run_future` or `DialogExtManual::run_async`.
+    pub fn question<T: IsA<Window>>(
+        parent: Option<&T>,
+        buttons: ButtonsType,
+        markup: &str,
+    ) -> MessageDialog {
+        Self::standard(parent, MessageType::Question, buttons, markup)
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Creates a modal dialog warning about `markup` with the `buttons`.
+    pub fn warning<T: IsA<Window>>(
+        parent: Option<&T>,
+        buttons: ButtonsType,
+        markup: &str,
+    ) -> MessageDialog {
+        Self::standard(parent, MessageType::Warning, buttons, markup)
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Creates a modal dialog reporting the error `markup` with the `buttons`.
+    pub fn error<T: IsA<Window>>(
+        parent: Option<&T>,
+        buttons: ButtonsType,
+        markup: &str,
+    ) -> MessageDialog {
+        Self::standard(parent, MessageType::Error, buttons, markup)
+    }
+
+    fn standard<T: IsA<Window>>(
+        parent: Option<&T>,
+        type_: MessageType,
+        buttons: ButtonsType,
+        markup: &str,
+    ) -> MessageDialog {
+        Self::with_markup(
+            parent,
+            DialogFlags::MODAL | DialogFlags::DESTROY_WITH_PARENT,
+            type_,
+            buttons,
+            markup,
+        )
+    }
 }
 
-pub trait MessageDialogExt: 'static {
+pub trait MessageDialogExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Sets the secondary text of the dialog in the Pango markup format, or removes it.
+    #[doc(alias = "gtk_message_dialog_format_secondary_markup")]
     fn set_secondary_markup(&self, message: Option<&str>);
 
+    // rustdoc-stripper-ignore-next
+    /// Sets the secondary text of the dialog, or removes it.
+    #[doc(alias = "gtk_message_dialog_format_secondary_text")]
     fn set_secondary_text(&self, message: Option<&str>);
 }
 
-impl<O: IsA<MessageDialog>> MessageDialogExt for O {
+impl<O: IsA<MessageDialog>> MessageDialogExtManual for O {
     fn set_secondary_markup(&self, message: Option<&str>) {
         match message {
             Some(m) => unsafe {
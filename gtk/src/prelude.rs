@@ -17,6 +17,7 @@ pub use crate::cell_renderer_pixbuf::CellRendererPixbufExtManual;
 pub use crate::color_button::ColorButtonExtManual;
 pub use crate::color_chooser::ColorChooserExtManual;
 pub use crate::combo_box::ComboBoxExtManual;
+pub use crate::css_provider::CssProviderExtManual;
 pub use crate::dialog::DialogExtManual;
 pub use crate::drag_context::DragContextExtManual;
 pub use crate::entry::EntryExtManual;
@@ -26,18 +27,26 @@ pub use crate::fixed::FixedExtManual;
 pub use crate::flow_box::FlowBoxExtManual;
 #[cfg(any(feature = "v3_24", feature = "dox"))]
 pub use crate::gesture_stylus::GestureStylusExtManual;
+pub use crate::icon_theme::IconThemeExtManual;
 pub use crate::im_context_simple::IMContextSimpleExtManual;
+pub use crate::info_bar::InfoBarExtManual;
 pub use crate::invisible::InvisibleExtManual;
 #[cfg(any(feature = "v3_16", feature = "dox"))]
 pub use crate::list_box::ListBoxExtManual;
 pub use crate::list_store::GtkListStoreExtManual;
 pub use crate::menu::GtkMenuExtManual;
+pub use crate::message_dialog::MessageDialogExtManual;
 pub use crate::notebook::NotebookExtManual;
+pub use crate::print_operation::PrintOperationExtManual;
+pub use crate::scale_button::ScaleButtonExtManual;
+pub use crate::search_bar::SearchBarExtManual;
 pub use crate::style_context::StyleContextExtManual;
 pub use crate::switch::SwitchExtManual;
 pub use crate::text_buffer::TextBufferExtManual;
+pub use crate::text_view::TextViewExtManual;
 pub use crate::tree_sortable::TreeSortableExtManual;
 pub use crate::tree_store::TreeStoreExtManual;
+pub use crate::tree_view_column::TreeViewColumnExtManual;
 pub use crate::widget::WidgetExtManual;
 pub use crate::window::GtkWindowExtManual;
 
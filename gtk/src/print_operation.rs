@@ -0,0 +1,81 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::{
+    PrintOperation, PrintOperationAction, PrintOperationExt, PrintOperationResult, Window,
+};
+use gio::prelude::*;
+use glib::IsA;
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+pub trait PrintOperationExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Runs the print operation asynchronously, resolving once it is done, i.e. after the
+    /// pages were drawn with the `draw-page` handlers and sent to the printer.
+    ///
+    /// The operation is cancelled when the future is dropped before resolving.
+    fn run_future<P: IsA<Window> + Clone + 'static>(
+        &self,
+        action: PrintOperationAction,
+        parent: Option<&P>,
+    ) -> Pin<Box<dyn Future<Output = Result<PrintOperationResult, glib::Error>> + 'static>>;
+}
+
+impl<O: IsA<PrintOperation>> PrintOperationExtManual for O {
+    fn run_future<P: IsA<Window> + Clone + 'static>(
+        &self,
+        action: PrintOperationAction,
+        parent: Option<&P>,
+    ) -> Pin<Box<dyn Future<Output = Result<PrintOperationResult, glib::Error>> + 'static>> {
+        let parent = parent.map(ToOwned::to_owned);
+        Box::pin(gio::GioFuture::new(self, move |obj, send| {
+            let operation: &PrintOperation = obj.as_ref();
+            let cancellable = gio::Cancellable::new();
+            let send = Rc::new(RefCell::new(Some(send)));
+
+            let handler = Rc::new(RefCell::new(None));
+            let handler_clone = handler.clone();
+            let send_clone = send.clone();
+            let id = operation.connect_done(move |operation, result| {
+                if let Some(id) = handler_clone.borrow_mut().take() {
+                    operation.disconnect(id);
+                }
+                if let Some(send) = send_clone.borrow_mut().take() {
+                    send.resolve(match result {
+                        PrintOperationResult::Error => operation.get_error().map(|_| result),
+                        _ => Ok(result),
+                    });
+                }
+            });
+            *handler.borrow_mut() = Some(id);
+
+            operation.set_allow_async(true);
+            let result = operation.run(action, parent.as_ref());
+            // Where printing asynchronously isn't supported, `done` was already emitted.
+            match result {
+                Ok(PrintOperationResult::InProgress) => (),
+                result => {
+                    if let Some(id) = handler.borrow_mut().take() {
+                        operation.disconnect(id);
+                    }
+                    if let Some(send) = send.borrow_mut().take() {
+                        send.resolve(result);
+                    }
+                }
+            }
+
+            let operation = glib::SendWeakRef::from(operation.downgrade());
+            cancellable.connect_cancelled_once(move |_| {
+                if let Some(operation) = operation.upgrade() {
+                    operation.cancel();
+                }
+            });
+
+            cancellable
+        }))
+    }
+}
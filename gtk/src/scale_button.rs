@@ -0,0 +1,44 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::{AdjustmentExt, ScaleButton, ScaleButtonExt};
+use glib::IsA;
+
+pub trait ScaleButtonExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Returns the step and page increments of the adjustment, see `set_increments`.
+    fn get_increments(&self) -> (f64, f64);
+
+    // rustdoc-stripper-ignore-next
+    /// Sets by how much the value changes with the arrow keys or the scroll wheel (`step`) and
+    /// with the Page Up and Page Down keys (`page`), on the button and in its popup.
+    ///
+    /// The plus and minus buttons of the popup change the value by `page` too. The keys showing
+    /// and hiding the popup are bound to the `popup` and `popdown` signals, and can be changed
+    /// with `BindingSet::by_class::<ScaleButton>()`.
+    fn set_increments(&self, step: f64, page: f64);
+}
+
+impl<O: IsA<ScaleButton>> ScaleButtonExtManual for O {
+    fn get_increments(&self) -> (f64, f64) {
+        let adjustment = self.as_ref().get_adjustment();
+        (
+            adjustment.get_step_increment(),
+            adjustment.get_page_increment(),
+        )
+    }
+
+    fn set_increments(&self, step: f64, page: f64) {
+        let adjustment = self.as_ref().get_adjustment();
+        // Emits `changed` only once.
+        adjustment.configure(
+            adjustment.get_value(),
+            adjustment.get_lower(),
+            adjustment.get_upper(),
+            step,
+            page,
+            adjustment.get_page_size(),
+        );
+    }
+}
@@ -0,0 +1,29 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::{SearchBar, SearchBarExt, Widget, WidgetExt};
+use glib::object::{IsA, ObjectExt};
+use glib::signal::{Inhibit, SignalHandlerId};
+
+pub trait SearchBarExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Forwards the key presses on `widget`, usually the toplevel window, to the search bar so
+    /// typing anywhere starts a search.
+    ///
+    /// The search bar must be packed in `widget` and have an entry set with `connect_entry`.
+    /// Disconnect the returned handler from `widget` to stop forwarding.
+    fn forward_key_presses_from<P: IsA<Widget>>(&self, widget: &P) -> SignalHandlerId;
+}
+
+impl<O: IsA<SearchBar>> SearchBarExtManual for O {
+    fn forward_key_presses_from<P: IsA<Widget>>(&self, widget: &P) -> SignalHandlerId {
+        let search_bar = self.downgrade();
+        widget
+            .as_ref()
+            .connect_key_press_event(move |_, event| match search_bar.upgrade() {
+                Some(search_bar) => Inhibit(search_bar.handle_event(event)),
+                None => Inhibit(false),
+            })
+    }
+}
@@ -5,6 +5,7 @@
 use crate::SelectionData;
 use glib::translate::*;
 use std::mem;
+use std::path::PathBuf;
 
 impl SelectionData {
     pub fn get_data(&self) -> Vec<u8> {
@@ -19,4 +20,16 @@ impl SelectionData {
             )
         }
     }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the local paths of the URIs contained in the selection data.
+    ///
+    /// URIs which don't point to a local file are skipped.
+    pub fn get_paths(&self) -> Vec<PathBuf> {
+        self.get_uris()
+            .iter()
+            .filter_map(|uri| glib::filename_from_uri(uri).ok())
+            .map(|(path, _)| path)
+            .collect()
+    }
 }
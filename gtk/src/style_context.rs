@@ -10,6 +10,13 @@ use pango::FontDescription;
 
 pub trait StyleContextExtManual: 'static {
     fn get_font(&self, state: StateFlags) -> FontDescription;
+
+    // rustdoc-stripper-ignore-next
+    /// Adds the style class `class_name` if `enabled` is `true`, removes it otherwise.
+    ///
+    /// This is handy to reflect a state in the style, e.g. the `error` class of an invalid
+    /// entry.
+    fn set_class(&self, class_name: &str, enabled: bool);
 }
 
 impl<O: IsA<StyleContext>> StyleContextExtManual for O {
@@ -19,4 +26,12 @@ impl<O: IsA<StyleContext>> StyleContextExtManual for O {
             .expect("font property is not pango::FontDescription")
             .expect("font property is empty")
     }
+
+    fn set_class(&self, class_name: &str, enabled: bool) {
+        if enabled {
+            self.add_class(class_name);
+        } else {
+            self.remove_class(class_name);
+        }
+    }
 }
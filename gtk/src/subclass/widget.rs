@@ -8,6 +8,7 @@ use glib::subclass::prelude::*;
 use glib::{Cast, Object};
 
 use crate::Allocation;
+use crate::BindingSet;
 use crate::DragResult;
 use crate::Inhibit;
 use crate::Orientation;
@@ -1612,6 +1613,13 @@ pub unsafe trait WidgetClassSubclassExt: ClassStruct {
         }
     }
 
+    // rustdoc-stripper-ignore-next
+    /// Returns the binding set of the class, to add key bindings emitting its keybinding
+    /// signals.
+    fn binding_set(&mut self) -> BindingSet {
+        unsafe { BindingSet::by_class_ptr(self as *mut _ as glib::ffi::gpointer) }
+    }
+
     #[allow(clippy::missing_safety_doc)]
     unsafe fn bind_template_child_with_offset<T>(
         &mut self,
@@ -1,4 +1,5 @@
 use crate::TargetFlags;
+use crate::TargetList;
 use glib::translate::*;
 use libc::c_char;
 use std::ffi::CStr;
@@ -32,6 +33,36 @@ impl TargetEntry {
     pub fn get_info(&self) -> u32 {
         self.info
     }
+
+    // rustdoc-stripper-ignore-next
+    /// All the targets GTK knows for exchanging text, each with the given `info`.
+    pub fn text_targets(info: u32) -> Vec<TargetEntry> {
+        assert_initialized_main_thread!();
+        let list = TargetList::new(&[]);
+        list.add_text_targets(info);
+        list.get_target_entries()
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// All the targets GTK knows for exchanging URI lists (e.g. dropped files), each with the
+    /// given `info`.
+    pub fn uri_targets(info: u32) -> Vec<TargetEntry> {
+        assert_initialized_main_thread!();
+        let list = TargetList::new(&[]);
+        list.add_uri_targets(info);
+        list.get_target_entries()
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// All the image targets supported by gdk-pixbuf, each with the given `info`.
+    ///
+    /// If `writable` is `true`, only formats which can be saved are included.
+    pub fn image_targets(info: u32, writable: bool) -> Vec<TargetEntry> {
+        assert_initialized_main_thread!();
+        let list = TargetList::new(&[]);
+        list.add_image_targets(info, writable);
+        list.get_target_entries()
+    }
 }
 
 #[doc(hidden)]
@@ -1,6 +1,7 @@
 use crate::TargetEntry;
 use crate::TargetList;
 use glib::translate::*;
+use std::mem;
 use std::ptr;
 
 impl TargetList {
@@ -15,4 +16,20 @@ impl TargetList {
         };
         unsafe { from_glib_full(ffi::gtk_target_list_new(t_ptr, t.len() as u32)) }
     }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the targets of the list, e.g. to pass them to `drag_dest_set`.
+    pub fn get_target_entries(&self) -> Vec<TargetEntry> {
+        unsafe {
+            let mut n_targets = mem::MaybeUninit::uninit();
+            let table =
+                ffi::gtk_target_table_new_from_list(self.to_glib_none().0, n_targets.as_mut_ptr());
+            let n_targets = n_targets.assume_init();
+            let entries = (0..n_targets as usize)
+                .map(|i| from_glib_none(table.add(i) as *const ffi::GtkTargetEntry))
+                .collect();
+            ffi::gtk_target_table_free(table, n_targets);
+            entries
+        }
+    }
 }
@@ -0,0 +1,69 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::{TextView, TextViewExt, TextWindowType, WidgetExt};
+use glib::object::{Cast, IsA};
+use glib::signal::{Inhibit, SignalHandlerId};
+
+pub trait TextViewExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Connects to `draw` to draw in the border window `win`, e.g. line numbers or breakpoints
+    /// in a gutter on the left.
+    ///
+    /// The border window only exists once it has a size, set with `set_border_window_size`.
+    /// `f` is called with the context transformed to the coordinates of the border window:
+    /// buffer positions are converted to these with `buffer_to_window_coords` and `win`.
+    ///
+    /// ```no_run
+    /// use gtk::prelude::*;
+    ///
+    /// # gtk::init().unwrap();
+    /// let text_view = gtk::TextView::new();
+    /// text_view.set_border_window_size(gtk::TextWindowType::Left, 40);
+    /// text_view.connect_draw_border_window(gtk::TextWindowType::Left, |text_view, cr| {
+    ///     let visible = text_view.get_visible_rect();
+    ///     let (mut iter, _) = text_view.get_line_at_y(visible.y);
+    ///     loop {
+    ///         let (y, height) = text_view.get_line_yrange(&iter);
+    ///         if y > visible.y + visible.height {
+    ///             break;
+    ///         }
+    ///         let (_, y) =
+    ///             text_view.buffer_to_window_coords(gtk::TextWindowType::Left, 0, y + height);
+    ///         cr.move_to(4., f64::from(y));
+    ///         cr.show_text(&(iter.get_line() + 1).to_string());
+    ///         if !iter.forward_line() {
+    ///             break;
+    ///         }
+    ///     }
+    /// });
+    /// ```
+    fn connect_draw_border_window<F: Fn(&Self, &cairo::Context) + 'static>(
+        &self,
+        win: TextWindowType,
+        f: F,
+    ) -> SignalHandlerId;
+}
+
+impl<O: IsA<TextView>> TextViewExtManual for O {
+    fn connect_draw_border_window<F: Fn(&Self, &cairo::Context) + 'static>(
+        &self,
+        win: TextWindowType,
+        f: F,
+    ) -> SignalHandlerId {
+        // `draw` is emitted once per window of the widget, with the clip set to the area of
+        // the one being drawn.
+        self.as_ref().connect_draw(move |text_view, cr| {
+            if let Some(window) = TextViewExt::get_window(text_view, win) {
+                if crate::cairo_should_draw_window(cr, &window) {
+                    cr.save();
+                    crate::cairo_transform_to_window(cr, text_view, &window);
+                    unsafe { f(text_view.unsafe_cast_ref(), cr) };
+                    cr.restore();
+                }
+            }
+            Inhibit(false)
+        })
+    }
+}
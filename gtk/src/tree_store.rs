@@ -4,6 +4,7 @@
 
 use crate::TreeIter;
 use crate::TreeModel;
+use crate::TreeModelExt;
 use crate::TreeStore;
 use glib::object::{Cast, IsA};
 use glib::translate::*;
@@ -37,6 +38,14 @@ pub trait TreeStoreExtManual: 'static {
     fn set(&self, iter: &TreeIter, columns: &[u32], values: &[&dyn ToValue]);
 
     fn set_value(&self, iter: &TreeIter, column: u32, value: &Value);
+
+    // rustdoc-stripper-ignore-next
+    /// Iterates depth-first over the descendants of `parent`, or over all the rows if `parent`
+    /// is `None`.
+    ///
+    /// Parents are visited before their children. The store must not be modified while
+    /// walking it.
+    fn walk(&self, parent: Option<&TreeIter>) -> TreeStoreWalk;
 }
 
 impl<O: IsA<TreeStore>> TreeStoreExtManual for O {
@@ -153,4 +162,49 @@ impl<O: IsA<TreeStore>> TreeStoreExtManual for O {
             );
         }
     }
+
+    fn walk(&self, parent: Option<&TreeIter>) -> TreeStoreWalk {
+        let model = self.as_ref().clone().upcast::<TreeModel>();
+        let next = model.iter_children(parent).map(|iter| (iter, 1));
+        TreeStoreWalk { model, next }
+    }
+}
+
+#[derive(Debug)]
+pub struct TreeStoreWalk {
+    model: TreeModel,
+    // The next row and its depth relative to the walked parent.
+    next: Option<(TreeIter, usize)>,
+}
+
+impl Iterator for TreeStoreWalk {
+    type Item = TreeIter;
+
+    fn next(&mut self) -> Option<TreeIter> {
+        let (iter, depth) = self.next.take()?;
+
+        self.next = match self.model.iter_children(Some(&iter)) {
+            Some(child) => Some((child, depth + 1)),
+            None => {
+                let mut ancestor = iter.clone();
+                let mut depth = depth;
+                loop {
+                    let sibling = ancestor.clone();
+                    if self.model.iter_next(&sibling) {
+                        break Some((sibling, depth));
+                    }
+                    if depth == 1 {
+                        break None;
+                    }
+                    match self.model.iter_parent(&ancestor) {
+                        Some(parent) => ancestor = parent,
+                        None => break None,
+                    }
+                    depth -= 1;
+                }
+            }
+        };
+
+        Some(iter)
+    }
 }
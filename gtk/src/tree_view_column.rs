@@ -0,0 +1,36 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::prelude::*;
+use crate::{SortColumn, TreeIter, TreeModel, TreeSortable, TreeViewColumn};
+use std::cmp::Ordering;
+
+pub trait TreeViewColumnExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Makes clicking the header of the column sort `sortable` with `sort_func`, e.g. to sort
+    /// file sizes shown as text by their value.
+    ///
+    /// `sort_column_id` identifies the sort in `sortable`, it doesn't have to be a column of the
+    /// model. Like with `set_sort_column_id`, the header becomes clickable and shows a sort
+    /// indicator while `sortable` is sorted with it.
+    fn set_sort_func<P, F>(&self, sortable: &P, sort_column_id: u32, sort_func: F)
+    where
+        P: IsA<TreeSortable>,
+        F: Fn(&TreeModel, &TreeIter, &TreeIter) -> Ordering + 'static;
+}
+
+impl<O: IsA<TreeViewColumn>> TreeViewColumnExtManual for O {
+    fn set_sort_func<P, F>(&self, sortable: &P, sort_column_id: u32, sort_func: F)
+    where
+        P: IsA<TreeSortable>,
+        F: Fn(&TreeModel, &TreeIter, &TreeIter) -> Ordering + 'static,
+    {
+        TreeSortableExtManual::set_sort_func(
+            sortable,
+            SortColumn::Index(sort_column_id),
+            sort_func,
+        );
+        self.as_ref().set_sort_column_id(sort_column_id as i32);
+    }
+}
@@ -8,10 +8,15 @@ use glib::object::{Cast, IsA, WeakRef};
 use glib::signal::{connect_raw, Inhibit, SignalHandlerId};
 use glib::translate::*;
 use glib::ObjectExt;
+use std::cell::RefCell;
 use std::mem::transmute;
 use std::ptr;
+use std::rc::Rc;
 
-use crate::{DestDefaults, Rectangle, TargetEntry, Widget};
+use crate::{
+    CssProvider, CssProviderExt, DestDefaults, EventController, Rectangle, Settings, StyleContext,
+    StyleContextExt, TargetEntry, Widget, WidgetExt,
+};
 use glib::Continue;
 
 pub struct TickCallbackId {
@@ -29,6 +34,30 @@ impl TickCallbackId {
     }
 }
 
+// rustdoc-stripper-ignore-next
+/// Keeps a CSS provider added with `WidgetExtManual::add_css` attached to the widget.
+///
+/// The provider is removed from the widget's style context when the guard is dropped.
+#[must_use = "the CSS is removed from the widget when the guard is dropped"]
+pub struct CssProviderGuard {
+    provider: CssProvider,
+    style_context: WeakRef<StyleContext>,
+}
+
+impl CssProviderGuard {
+    pub fn get_provider(&self) -> &CssProvider {
+        &self.provider
+    }
+}
+
+impl Drop for CssProviderGuard {
+    fn drop(&mut self) {
+        if let Some(style_context) = self.style_context.upgrade() {
+            style_context.remove_provider(&self.provider);
+        }
+    }
+}
+
 pub trait WidgetExtManual: 'static {
     fn drag_dest_set(&self, flags: DestDefaults, targets: &[TargetEntry], actions: DragAction);
 
@@ -69,6 +98,120 @@ pub trait WidgetExtManual: 'static {
     unsafe fn destroy(&self);
 
     fn hide_on_delete(&self) -> Inhibit;
+
+    // rustdoc-stripper-ignore-next
+    /// Translates the coordinates of `event`, which are relative to the event's window, into
+    /// coordinates relative to `dest_widget`.
+    ///
+    /// `self` must be the widget that received the event (or one of its ancestors). Returns
+    /// `None` if the event doesn't carry coordinates, if the event window isn't inside `self`,
+    /// or if both widgets don't share a common toplevel.
+    fn translate_event_coords<P: IsA<Widget>>(
+        &self,
+        event: &Event,
+        dest_widget: &P,
+    ) -> Option<(f64, f64)>;
+
+    // rustdoc-stripper-ignore-next
+    /// Styles this widget only with the given `css`, at `STYLE_PROVIDER_PRIORITY_APPLICATION`.
+    ///
+    /// The CSS stays applied as long as the returned guard is alive. Selectors match the widget
+    /// itself, not its children, e.g. `entry { border-color: red; }`.
+    fn add_css(&self, css: &str) -> Result<CssProviderGuard, glib::Error>;
+
+    // rustdoc-stripper-ignore-next
+    /// Keeps this widget visible as long as the boolean `property` of `source` is `true`, or
+    /// `false` if `invert` is set.
+    ///
+    /// The binding goes one way and is synced right away. It is removed when either object is
+    /// finalized, or when calling `unbind` on the returned binding.
+    fn bind_visible_to<P: IsA<glib::Object>>(
+        &self,
+        source: &P,
+        property: &str,
+        invert: bool,
+    ) -> Option<glib::Binding>;
+
+    // rustdoc-stripper-ignore-next
+    /// Keeps this widget sensitive as long as the boolean `property` of `source` is `true`, or
+    /// `false` if `invert` is set.
+    ///
+    /// See `bind_visible_to` for the binding's lifetime.
+    fn bind_sensitive_to<P: IsA<glib::Object>>(
+        &self,
+        source: &P,
+        property: &str,
+        invert: bool,
+    ) -> Option<glib::Binding>;
+
+    // rustdoc-stripper-ignore-next
+    /// Renders the widget into a new image surface of the size of its allocation, e.g. for drag
+    /// icons, previews or comparing screenshots in tests.
+    ///
+    /// The widget must be allocated, usually by showing it in a window. Widgets which shouldn't
+    /// appear on screen can be put in an `OffscreenWindow` instead, whose `get_pixbuf` gives the
+    /// same result as a pixbuf. The surface is in device pixels, so it is larger than the
+    /// allocation on high resolution screens.
+    fn snapshot_to_surface(&self) -> Result<cairo::ImageSurface, cairo::Error>;
+
+    // rustdoc-stripper-ignore-next
+    /// Calls `f` when the setting `property`, e.g. `gtk-xft-dpi`, changes in the settings of the
+    /// widget's screen.
+    ///
+    /// When the widget moves to another screen, which can have other settings, `f` is called
+    /// with the new settings and follows them from then on. The callback is disconnected when
+    /// the widget is destroyed.
+    fn connect_settings_notify<F: Fn(&Self, &Settings) + 'static>(&self, property: &str, f: F);
+
+    // rustdoc-stripper-ignore-next
+    /// Keeps `controller`, e.g. a gesture created for this widget, alive as long as the widget.
+    ///
+    /// Widgets don't hold a reference on their event controllers in GTK 3, so a gesture stops
+    /// handling events as soon as it is dropped unless it is stored somewhere else.
+    fn keep_controller<P: IsA<EventController>>(&self, controller: &P);
+}
+
+const CONTROLLERS_KEY: &str = "gtk-rs-event-controllers";
+
+type SettingsHandler = RefCell<Option<(Settings, SignalHandlerId)>>;
+
+fn connect_current_settings<O: IsA<Widget>, F: Fn(&O, &Settings) + 'static>(
+    widget: &O,
+    property: &str,
+    f: &Rc<F>,
+    handler: &SettingsHandler,
+) {
+    if let Some((settings, id)) = handler.borrow_mut().take() {
+        settings.disconnect(id);
+    }
+    if let Some(settings) = widget.as_ref().get_settings() {
+        let widget = widget.downgrade();
+        let f = f.clone();
+        let id = settings.connect_notify_local(Some(property), move |settings, _| {
+            if let Some(widget) = widget.upgrade() {
+                f(&widget, settings);
+            }
+        });
+        *handler.borrow_mut() = Some((settings, id));
+    }
+}
+
+fn bind_boolean<P: IsA<glib::Object>>(
+    source: &P,
+    property: &str,
+    target: &Widget,
+    target_property: &str,
+    invert: bool,
+) -> Option<glib::Binding> {
+    let mut flags = glib::BindingFlags::SYNC_CREATE;
+    if invert {
+        flags |= glib::BindingFlags::INVERT_BOOLEAN;
+    }
+    source
+        .as_ref()
+        .bind_property(property, target, target_property)
+        .flags(flags)
+        .build()
 }
 
 impl<O: IsA<Widget>> WidgetExtManual for O {
@@ -260,4 +403,104 @@ impl<O: IsA<Widget>> WidgetExtManual for O {
             )))
         }
     }
+
+    fn translate_event_coords<P: IsA<Widget>>(
+        &self,
+        event: &Event,
+        dest_widget: &P,
+    ) -> Option<(f64, f64)> {
+        let widget = self.as_ref();
+        let (mut x, mut y) = event.get_coords()?;
+        let widget_window = widget.get_window()?;
+        let mut window = event.get_window()?;
+        while window != widget_window {
+            let (parent_x, parent_y) = window.coords_to_parent(x, y);
+            x = parent_x;
+            y = parent_y;
+            window = window.get_parent()?;
+        }
+        if !widget.get_has_window() {
+            let allocation = widget.get_allocation();
+            x -= f64::from(allocation.x);
+            y -= f64::from(allocation.y);
+        }
+        let (dx, dy) = widget.translate_coordinates(dest_widget, 0, 0)?;
+        Some((x + f64::from(dx), y + f64::from(dy)))
+    }
+
+    fn add_css(&self, css: &str) -> Result<CssProviderGuard, glib::Error> {
+        let provider = CssProvider::new();
+        provider.load_from_data(css.as_bytes())?;
+
+        let style_context = self.as_ref().get_style_context();
+        style_context.add_provider(&provider, crate::STYLE_PROVIDER_PRIORITY_APPLICATION);
+
+        Ok(CssProviderGuard {
+            provider,
+            style_context: style_context.downgrade(),
+        })
+    }
+
+    fn bind_visible_to<P: IsA<glib::Object>>(
+        &self,
+        source: &P,
+        property: &str,
+        invert: bool,
+    ) -> Option<glib::Binding> {
+        bind_boolean(source, property, self.as_ref(), "visible", invert)
+    }
+
+    fn bind_sensitive_to<P: IsA<glib::Object>>(
+        &self,
+        source: &P,
+        property: &str,
+        invert: bool,
+    ) -> Option<glib::Binding> {
+        bind_boolean(source, property, self.as_ref(), "sensitive", invert)
+    }
+
+    fn snapshot_to_surface(&self) -> Result<cairo::ImageSurface, cairo::Error> {
+        let widget = self.as_ref();
+        let scale = widget.get_scale_factor();
+        let surface = cairo::ImageSurface::create(
+            cairo::Format::ARgb32,
+            widget.get_allocated_width() * scale,
+            widget.get_allocated_height() * scale,
+        )?;
+        let cr = cairo::Context::new(&surface);
+        cr.scale(f64::from(scale), f64::from(scale));
+        widget.draw(&cr);
+        surface.flush();
+        Ok(surface)
+    }
+
+    fn connect_settings_notify<F: Fn(&Self, &Settings) + 'static>(&self, property: &str, f: F) {
+        let f = Rc::new(f);
+        let handler: Rc<SettingsHandler> = Rc::new(RefCell::new(None));
+        connect_current_settings(self, property, &f, &handler);
+
+        let property = property.to_owned();
+        let handler_clone = handler.clone();
+        self.connect_screen_changed(move |widget, _| {
+            connect_current_settings(widget, &property, &f, &handler_clone);
+            if let Some(settings) = widget.as_ref().get_settings() {
+                f(widget, &settings);
+            }
+        });
+        self.connect_destroy(move |_| {
+            if let Some((settings, id)) = handler.borrow_mut().take() {
+                settings.disconnect(id);
+            }
+        });
+    }
+
+    fn keep_controller<P: IsA<EventController>>(&self, controller: &P) {
+        let controller = controller.as_ref().clone();
+        unsafe {
+            match self.get_data::<RefCell<Vec<EventController>>>(CONTROLLERS_KEY) {
+                Some(controllers) => controllers.borrow_mut().push(controller),
+                None => self.set_data(CONTROLLERS_KEY, RefCell::new(vec![controller])),
+            }
+        }
+    }
 }
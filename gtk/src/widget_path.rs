@@ -0,0 +1,119 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::{StateFlags, WidgetPath};
+use glib::GString;
+use std::iter::{DoubleEndedIterator, ExactSizeIterator, Iterator};
+
+impl WidgetPath {
+    // rustdoc-stripper-ignore-next
+    /// Iterates over the elements of the path, from the toplevel down to the widget itself.
+    pub fn iter(&self) -> WidgetPathIter<'_> {
+        WidgetPathIter {
+            path: self,
+            front: 0,
+            back: self.length(),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a WidgetPath {
+    type Item = WidgetPathElement<'a>;
+    type IntoIter = WidgetPathIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// One element of a `WidgetPath`, as matched by CSS selectors.
+#[derive(Debug, Clone, Copy)]
+pub struct WidgetPathElement<'a> {
+    path: &'a WidgetPath,
+    pos: i32,
+}
+
+impl<'a> WidgetPathElement<'a> {
+    pub fn get_position(&self) -> i32 {
+        self.pos
+    }
+
+    pub fn get_object_type(&self) -> glib::Type {
+        self.path.iter_get_object_type(self.pos)
+    }
+
+    #[cfg(any(feature = "v3_20", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v3_20")))]
+    pub fn get_object_name(&self) -> Option<GString> {
+        self.path.iter_get_object_name(self.pos)
+    }
+
+    pub fn get_name(&self) -> Option<GString> {
+        self.path.iter_get_name(self.pos)
+    }
+
+    pub fn get_classes(&self) -> Vec<GString> {
+        self.path.iter_list_classes(self.pos)
+    }
+
+    pub fn has_class(&self, name: &str) -> bool {
+        self.path.iter_has_class(self.pos, name)
+    }
+
+    pub fn get_state(&self) -> StateFlags {
+        self.path.iter_get_state(self.pos)
+    }
+
+    pub fn get_siblings(&self) -> Option<WidgetPath> {
+        self.path.iter_get_siblings(self.pos)
+    }
+
+    pub fn get_sibling_index(&self) -> u32 {
+        self.path.iter_get_sibling_index(self.pos)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WidgetPathIter<'a> {
+    path: &'a WidgetPath,
+    front: i32,
+    back: i32,
+}
+
+impl<'a> Iterator for WidgetPathIter<'a> {
+    type Item = WidgetPathElement<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let pos = self.front;
+        self.front += 1;
+        Some(WidgetPathElement {
+            path: self.path,
+            pos,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front).max(0) as usize;
+        (len, Some(len))
+    }
+}
+
+impl<'a> DoubleEndedIterator for WidgetPathIter<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(WidgetPathElement {
+            path: self.path,
+            pos: self.back,
+        })
+    }
+}
+
+impl<'a> ExactSizeIterator for WidgetPathIter<'a> {}
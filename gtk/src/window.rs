@@ -2,12 +2,128 @@
 // See the COPYRIGHT file at the top-level directory of this distribution.
 // Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
 
-use crate::Window;
-use glib::object::IsA;
+use crate::{GtkWindowExt, WidgetExt, Window};
+use glib::object::{Cast, IsA};
+use glib::signal::{Inhibit, SignalHandlerId};
 use glib::translate::*;
+use glib::ObjectExt;
+use std::cell::Cell;
+use std::rc::Rc;
 
 pub trait GtkWindowExtManual: 'static {
     fn present(&self);
+
+    // rustdoc-stripper-ignore-next
+    /// Connects to `key-press-event` to get the key presses which neither mnemonics,
+    /// accelerators nor the focus widget handled.
+    ///
+    /// This is the usual way to forward typing to a search entry: the focus widget keeps
+    /// priority, and only otherwise ignored keys reach `f`. `f` returns whether it handled the
+    /// key, the key bindings of the window get it otherwise.
+    fn connect_key_unhandled<F: Fn(&Self, &gdk::EventKey) -> Inhibit + 'static>(
+        &self,
+        f: F,
+    ) -> SignalHandlerId;
+
+    // rustdoc-stripper-ignore-next
+    /// Sets the urgency hint of the window until the user activates it, which usually makes
+    /// its taskbar entry flash.
+    ///
+    /// Does nothing if the window is already active.
+    fn request_attention(&self);
+
+    // rustdoc-stripper-ignore-next
+    /// Starts moving the window with the pointer, from the button press `event`.
+    ///
+    /// This is what custom titlebars do on button presses: the window manager or the compositor
+    /// moves the window until the button is released, which also works on Wayland where
+    /// applications can't position their windows themselves.
+    fn begin_move_drag_for_event(&self, event: &gdk::EventButton);
+
+    // rustdoc-stripper-ignore-next
+    /// Starts resizing the window from its `edge` with the pointer, from the button press
+    /// `event`, like borderless windows do on presses on their borders.
+    fn begin_resize_drag_for_event(&self, edge: gdk::WindowEdge, event: &gdk::EventButton);
+
+    // rustdoc-stripper-ignore-next
+    /// Connects to `delete-event` to ask for a confirmation before the window is closed, e.g.
+    /// with a dialog offering to save the changes.
+    ///
+    /// `f` is called when the user tries to close the window, which stays open until the
+    /// confirmation is resolved with `DeleteConfirmation::resolve(true)`: the window is then
+    /// closed. Resolving it with `false` or dropping it keeps the window open. Attempts to
+    /// close the window while a confirmation is pending are ignored, so only one dialog shows.
+    ///
+    /// ```no_run
+    /// use gtk::prelude::*;
+    ///
+    /// # gtk::init().unwrap();
+    /// let window = gtk::Window::new(gtk::WindowType::Toplevel);
+    /// window.connect_delete_event_confirm(|window, confirmation| {
+    ///     let dialog = gtk::MessageDialog::new(
+    ///         Some(window),
+    ///         gtk::DialogFlags::MODAL | gtk::DialogFlags::DESTROY_WITH_PARENT,
+    ///         gtk::MessageType::Question,
+    ///         gtk::ButtonsType::YesNo,
+    ///         "Close without saving?",
+    ///     );
+    ///     // The confirmation is resolved by the first response only.
+    ///     let confirmation = std::cell::RefCell::new(Some(confirmation));
+    ///     dialog.connect_response(move |dialog, response| {
+    ///         if let Some(confirmation) = confirmation.borrow_mut().take() {
+    ///             confirmation.resolve(response == gtk::ResponseType::Yes);
+    ///         }
+    ///         dialog.close();
+    ///     });
+    ///     dialog.show_all();
+    /// });
+    /// ```
+    fn connect_delete_event_confirm<F: Fn(&Self, DeleteConfirmation) + 'static>(
+        &self,
+        f: F,
+    ) -> SignalHandlerId;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DeleteState {
+    Idle,
+    Pending,
+    Confirmed,
+}
+
+// rustdoc-stripper-ignore-next
+/// A pending request to close a window, see
+/// [`GtkWindowExtManual::connect_delete_event_confirm`](prelude/trait.GtkWindowExtManual.html#tymethod.connect_delete_event_confirm).
+///
+/// Dropping it without calling `resolve` keeps the window open.
+#[must_use = "the window can't be closed again until the confirmation is resolved or dropped"]
+#[derive(Debug)]
+pub struct DeleteConfirmation {
+    window: glib::WeakRef<Window>,
+    state: Rc<Cell<DeleteState>>,
+}
+
+impl DeleteConfirmation {
+    // rustdoc-stripper-ignore-next
+    /// Closes the window if `close` is `true`, keeps it open otherwise.
+    pub fn resolve(self, close: bool) {
+        if !close {
+            return;
+        }
+        if let Some(window) = self.window.upgrade() {
+            // `close` emits `delete-event` from an idle callback, which lets it through.
+            self.state.set(DeleteState::Confirmed);
+            window.close();
+        }
+    }
+}
+
+impl Drop for DeleteConfirmation {
+    fn drop(&mut self) {
+        if self.state.get() == DeleteState::Pending {
+            self.state.set(DeleteState::Idle);
+        }
+    }
 }
 
 #[cfg(target_os = "macos")]
@@ -26,4 +142,110 @@ impl<O: IsA<Window>> GtkWindowExtManual for O {
             macos_force_foreground_level();
         }
     }
+
+    fn connect_key_unhandled<F: Fn(&Self, &gdk::EventKey) -> Inhibit + 'static>(
+        &self,
+        f: F,
+    ) -> SignalHandlerId {
+        // The key is dispatched here instead of by the class handler of `GtkWindow`, which
+        // would dispatch it a second time, so the event is always stopped.
+        self.as_ref().connect_key_press_event(move |window, event| {
+            if window.activate_key(event) || window.propagate_key_event(event) {
+                return Inhibit(true);
+            }
+            let Inhibit(handled) = unsafe { f(window.unsafe_cast_ref(), event) };
+            if !handled {
+                crate::bindings_activate_event(window, &mut event.clone());
+            }
+            Inhibit(true)
+        })
+    }
+
+    fn request_attention(&self) {
+        let window = self.as_ref();
+        if window.is_active() || window.get_urgency_hint() {
+            return;
+        }
+        window.set_urgency_hint(true);
+
+        let handler_id = Rc::new(Cell::new(None));
+        let handler_id_clone = handler_id.clone();
+        handler_id.set(Some(window.connect_property_is_active_notify(
+            move |window| {
+                if window.is_active() {
+                    window.set_urgency_hint(false);
+                    if let Some(handler_id) = handler_id_clone.take() {
+                        window.disconnect(handler_id);
+                    }
+                }
+            },
+        )));
+    }
+
+    fn begin_move_drag_for_event(&self, event: &gdk::EventButton) {
+        let (root_x, root_y) = event.get_root();
+        let button = event.get_button() as i32;
+        // Passing the device of the event lets the drag work with several seats.
+        match (self.as_ref().get_window(), event.get_device()) {
+            (Some(window), Some(device)) => window.begin_move_drag_for_device(
+                &device,
+                button,
+                root_x as i32,
+                root_y as i32,
+                event.get_time(),
+            ),
+            _ => self.as_ref().begin_move_drag(
+                button,
+                root_x as i32,
+                root_y as i32,
+                event.get_time(),
+            ),
+        }
+    }
+
+    fn begin_resize_drag_for_event(&self, edge: gdk::WindowEdge, event: &gdk::EventButton) {
+        let (root_x, root_y) = event.get_root();
+        let button = event.get_button() as i32;
+        match (self.as_ref().get_window(), event.get_device()) {
+            (Some(window), Some(device)) => window.begin_resize_drag_for_device(
+                edge,
+                &device,
+                button,
+                root_x as i32,
+                root_y as i32,
+                event.get_time(),
+            ),
+            _ => self.as_ref().begin_resize_drag(
+                edge,
+                button,
+                root_x as i32,
+                root_y as i32,
+                event.get_time(),
+            ),
+        }
+    }
+
+    fn connect_delete_event_confirm<F: Fn(&Self, DeleteConfirmation) + 'static>(
+        &self,
+        f: F,
+    ) -> SignalHandlerId {
+        let state = Rc::new(Cell::new(DeleteState::Idle));
+        self.as_ref()
+            .connect_delete_event(move |window, _| match state.get() {
+                DeleteState::Confirmed => {
+                    state.set(DeleteState::Idle);
+                    Inhibit(false)
+                }
+                DeleteState::Pending => Inhibit(true),
+                DeleteState::Idle => {
+                    state.set(DeleteState::Pending);
+                    let confirmation = DeleteConfirmation {
+                        window: window.downgrade(),
+                        state: state.clone(),
+                    };
+                    unsafe { f(window.unsafe_cast_ref(), confirmation) };
+                    Inhibit(true)
+                }
+            })
+    }
 }
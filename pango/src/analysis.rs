@@ -9,6 +9,7 @@ use crate::Gravity;
 use crate::Language;
 use crate::Script;
 use glib::translate::*;
+use std::fmt;
 
 #[repr(C)]
 pub struct Analysis(ffi::PangoAnalysis);
@@ -51,6 +52,17 @@ impl Analysis {
     }*/
 }
 
+impl fmt::Debug for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Analysis")
+            .field("level", &self.level())
+            .field("gravity", &self.gravity())
+            .field("flags", &self.flags())
+            .field("script", &self.script())
+            .finish()
+    }
+}
+
 #[doc(hidden)]
 impl<'a> ToGlibPtr<'a, *const ffi::PangoAnalysis> for Analysis {
     type Storage = &'a Self;
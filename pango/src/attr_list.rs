@@ -4,8 +4,12 @@
 
 use crate::AttrList;
 use crate::Attribute;
+use crate::Style;
+use crate::Underline;
+use crate::Weight;
 use glib::translate::*;
 use std::mem;
+use std::ops::Range;
 
 impl AttrList {
     pub fn change(&self, attr: Attribute) {
@@ -44,6 +48,93 @@ impl AttrList {
     }
 }
 
+// rustdoc-stripper-ignore-next
+/// Builds an `AttrList` from attributes applied to byte ranges of the text.
+///
+/// ```no_run
+/// let attrs = pango::AttrListBuilder::new()
+///     .weight(0..5, pango::Weight::Bold)
+///     .foreground(6..11, 0xffff, 0, 0)
+///     .build();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AttrListBuilder {
+    attributes: Vec<Attribute>,
+}
+
+impl AttrListBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Adds `attr` for the given byte range, replacing the range it had.
+    pub fn attribute(mut self, range: Range<u32>, mut attr: Attribute) -> Self {
+        attr.set_start_index(range.start);
+        attr.set_end_index(range.end);
+        self.attributes.push(attr);
+        self
+    }
+
+    pub fn background(self, range: Range<u32>, red: u16, green: u16, blue: u16) -> Self {
+        self.typed(range, Attribute::new_background(red, green, blue))
+    }
+
+    pub fn family(self, range: Range<u32>, family: &str) -> Self {
+        self.typed(range, Attribute::new_family(family))
+    }
+
+    pub fn foreground(self, range: Range<u32>, red: u16, green: u16, blue: u16) -> Self {
+        self.typed(range, Attribute::new_foreground(red, green, blue))
+    }
+
+    pub fn letter_spacing(self, range: Range<u32>, letter_spacing: i32) -> Self {
+        self.typed(range, Attribute::new_letter_spacing(letter_spacing))
+    }
+
+    pub fn rise(self, range: Range<u32>, rise: i32) -> Self {
+        self.typed(range, Attribute::new_rise(rise))
+    }
+
+    pub fn scale(self, range: Range<u32>, scale_factor: f64) -> Self {
+        self.typed(range, Attribute::new_scale(scale_factor))
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Sets the font size in Pango units, see [`SCALE`](../constant.SCALE.html).
+    pub fn size(self, range: Range<u32>, size: i32) -> Self {
+        self.typed(range, Attribute::new_size(size))
+    }
+
+    pub fn strikethrough(self, range: Range<u32>, strikethrough: bool) -> Self {
+        self.typed(range, Attribute::new_strikethrough(strikethrough))
+    }
+
+    pub fn style(self, range: Range<u32>, style: Style) -> Self {
+        self.typed(range, Attribute::new_style(style))
+    }
+
+    pub fn underline(self, range: Range<u32>, underline: Underline) -> Self {
+        self.typed(range, Attribute::new_underline(underline))
+    }
+
+    pub fn weight(self, range: Range<u32>, weight: Weight) -> Self {
+        self.typed(range, Attribute::new_weight(weight))
+    }
+
+    fn typed(self, range: Range<u32>, attr: Option<Attribute>) -> Self {
+        self.attribute(range, attr.expect("Couldn't create attribute"))
+    }
+
+    pub fn build(self) -> AttrList {
+        let list = AttrList::new();
+        for attr in self.attributes {
+            list.insert(attr);
+        }
+        list
+    }
+}
+
 #[cfg(any(feature = "v1_46", feature = "dox"))]
 impl PartialEq for AttrList {
     #[inline]
@@ -91,7 +91,7 @@ impl LayoutLine {
     }
 
     #[doc(alias = "pango_layout_line_x_to_index")]
-    pub fn x_to_index(&self, x_pos: i32) -> Option<(i32, i32)> {
+    pub fn x_to_index(&self, x_pos: i32) -> (bool, i32, i32) {
         unsafe {
             let mut index_ = mem::MaybeUninit::uninit();
             let mut trailing = mem::MaybeUninit::uninit();
@@ -103,11 +103,7 @@ impl LayoutLine {
             ));
             let index_ = index_.assume_init();
             let trailing = trailing.assume_init();
-            if ret {
-                Some((index_, trailing))
-            } else {
-                None
-            }
+            (ret, index_, trailing)
         }
     }
 }
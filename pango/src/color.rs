@@ -0,0 +1,27 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::Color;
+use glib::translate::*;
+
+impl Color {
+    // rustdoc-stripper-ignore-next
+    /// Creates a color from its components, each ranging from 0 to 65535.
+    pub fn new(red: u16, green: u16, blue: u16) -> Color {
+        let color = ffi::PangoColor { red, green, blue };
+        unsafe { from_glib_none(&color as *const ffi::PangoColor) }
+    }
+
+    pub fn get_red(&self) -> u16 {
+        unsafe { (*self.to_glib_none().0).red }
+    }
+
+    pub fn get_green(&self) -> u16 {
+        unsafe { (*self.to_glib_none().0).green }
+    }
+
+    pub fn get_blue(&self) -> u16 {
+        unsafe { (*self.to_glib_none().0).blue }
+    }
+}
@@ -0,0 +1,24 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::Font;
+use glib::object::IsA;
+use glib::translate::*;
+
+pub trait FontExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Returns the `hb_font_t` used by Pango to shape text with this font, to be cast to
+    /// `*mut harfbuzz_sys::hb_font_t` e.g. to query its OpenType features.
+    ///
+    /// The HarfBuzz font is owned by `self`. Take a reference with `hb_font_reference` to use it
+    /// once `self` is dropped.
+    #[doc(alias = "pango_font_get_hb_font")]
+    fn get_hb_font_ptr(&self) -> *mut libc::c_void;
+}
+
+impl<O: IsA<Font>> FontExtManual for O {
+    fn get_hb_font_ptr(&self) -> *mut libc::c_void {
+        unsafe { ffi::pango_font_get_hb_font(self.as_ref().to_glib_none().0) as *mut _ }
+    }
+}
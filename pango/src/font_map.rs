@@ -0,0 +1,49 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::{FontFace, FontFamily, FontFamilyExt, FontMap, FontMapExt};
+use glib::object::IsA;
+use std::vec;
+
+pub trait FontMapExtManual: 'static {
+    // rustdoc-stripper-ignore-next
+    /// Iterates over the faces of all the font families of the font map, together with their
+    /// family.
+    ///
+    /// The faces of a family are only listed once the iterator reaches it.
+    fn faces(&self) -> FontFaces;
+}
+
+impl<O: IsA<FontMap>> FontMapExtManual for O {
+    fn faces(&self) -> FontFaces {
+        FontFaces {
+            families: self.list_families().into_iter(),
+            current: None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct FontFaces {
+    families: vec::IntoIter<FontFamily>,
+    current: Option<(FontFamily, vec::IntoIter<FontFace>)>,
+}
+
+impl Iterator for FontFaces {
+    type Item = (FontFamily, FontFace);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((ref family, ref mut faces)) = self.current {
+                if let Some(face) = faces.next() {
+                    return Some((family.clone(), face));
+                }
+            }
+
+            let family = self.families.next()?;
+            let faces = family.list_faces().into_iter();
+            self.current = Some((family, faces));
+        }
+    }
+}
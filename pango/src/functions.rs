@@ -8,8 +8,14 @@ use crate::Item;
 #[cfg(any(feature = "v1_44", feature = "dox"))]
 use crate::ShapeFlags;
 use glib::translate::*;
+use libc::c_char;
 use std::ptr;
 
+// rustdoc-stripper-ignore-next
+/// Escapes `text` so that it can be inserted in markup passed to `parse_markup` or
+/// `Layout::set_markup`.
+pub use glib::markup_escape_text;
+
 pub fn reorder_items(logical_items: &[&Item]) -> Vec<Item> {
     unsafe {
         let stash_vec: Vec<_> = logical_items
@@ -26,23 +32,23 @@ pub fn reorder_items(logical_items: &[&Item]) -> Vec<Item> {
     }
 }
 
+// rustdoc-stripper-ignore-next
+/// Like `shape`, but lets Pango look at the text around the item in `paragraph_text`.
+///
+/// Panics if `item_text` isn't a slice of `paragraph_text`, like the one returned by
+/// `Item::text`.
 pub fn shape_full(
     item_text: &str,
     paragraph_text: Option<&str>,
     analysis: &Analysis,
     glyphs: &mut GlyphString,
 ) {
-    let paragraph_length = match paragraph_text {
-        Some(s) => s.len(),
-        None => 0,
-    } as i32;
-    let paragraph_text = paragraph_text.to_glib_none();
-    let item_length = item_text.len() as i32;
+    let (paragraph_ptr, paragraph_length) = paragraph_ptr(item_text, paragraph_text);
     unsafe {
         ffi::pango_shape_full(
-            item_text.to_glib_none().0,
-            item_length,
-            paragraph_text.0,
+            item_text.as_ptr() as *const c_char,
+            item_text.len() as i32,
+            paragraph_ptr,
             paragraph_length,
             analysis.to_glib_none().0,
             glyphs.to_glib_none_mut().0,
@@ -50,6 +56,11 @@ pub fn shape_full(
     }
 }
 
+// rustdoc-stripper-ignore-next
+/// Like `shape_full`, with `flags` changing the shaping.
+///
+/// Panics if `item_text` isn't a slice of `paragraph_text`, like the one returned by
+/// `Item::text`.
 #[cfg(any(feature = "v1_44", feature = "dox"))]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v1_44")))]
 pub fn shape_with_flags(
@@ -59,13 +70,12 @@ pub fn shape_with_flags(
     glyphs: &mut GlyphString,
     flags: ShapeFlags,
 ) {
-    let item_length = item_text.len() as i32;
-    let paragraph_length = paragraph_text.map(|t| t.len() as i32).unwrap_or_default();
+    let (paragraph_ptr, paragraph_length) = paragraph_ptr(item_text, paragraph_text);
     unsafe {
         ffi::pango_shape_with_flags(
-            item_text.to_glib_none().0,
-            item_length,
-            paragraph_text.to_glib_none().0,
+            item_text.as_ptr() as *const c_char,
+            item_text.len() as i32,
+            paragraph_ptr,
             paragraph_length,
             analysis.to_glib_none().0,
             glyphs.to_glib_none_mut().0,
@@ -73,3 +83,23 @@ pub fn shape_with_flags(
         );
     }
 }
+
+// Pango looks at the text around the item through its position in the paragraph, so the
+// strings are passed without copying them.
+fn paragraph_ptr(item_text: &str, paragraph_text: Option<&str>) -> (*const c_char, i32) {
+    match paragraph_text {
+        Some(paragraph_text) => {
+            let paragraph_range = paragraph_text.as_bytes().as_ptr_range();
+            let item_range = item_text.as_bytes().as_ptr_range();
+            assert!(
+                paragraph_range.start <= item_range.start && item_range.end <= paragraph_range.end,
+                "item_text must be a slice of paragraph_text"
+            );
+            (
+                paragraph_text.as_ptr() as *const c_char,
+                paragraph_text.len() as i32,
+            )
+        }
+        None => (ptr::null(), 0),
+    }
+}
@@ -2,6 +2,8 @@ use crate::GlyphItem;
 use crate::GlyphString;
 use crate::Item;
 use glib::translate::*;
+use std::fmt;
+use std::slice;
 
 impl GlyphString {
     pub fn num_glyphs(&self) -> i32 {
@@ -18,6 +20,56 @@ impl GlyphString {
             FromGlibContainer::from_glib_none_num(glyphs, num_glyphs)
         }
     }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the glyphs as a slice, borrowed from the glyph string.
+    pub fn glyph_entries(&self) -> &[GlyphEntry] {
+        unsafe {
+            let ptr = self.to_glib_none().0;
+            slice_or_empty((*ptr).glyphs as *const GlyphEntry, (*ptr).num_glyphs)
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Gives mutable access to the glyphs, for adjusting their positions after shaping.
+    pub fn glyph_entries_mut(&mut self) -> &mut [GlyphEntry] {
+        unsafe {
+            let ptr = self.to_glib_none_mut().0;
+            slice_or_empty_mut((*ptr).glyphs as *mut GlyphEntry, (*ptr).num_glyphs)
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns, for every glyph, the byte index in the text of the cluster it belongs to.
+    pub fn log_clusters(&self) -> &[i32] {
+        unsafe {
+            let ptr = self.to_glib_none().0;
+            slice_or_empty((*ptr).log_clusters, (*ptr).num_glyphs)
+        }
+    }
+
+    pub fn log_clusters_mut(&mut self) -> &mut [i32] {
+        unsafe {
+            let ptr = self.to_glib_none_mut().0;
+            slice_or_empty_mut((*ptr).log_clusters, (*ptr).num_glyphs)
+        }
+    }
+}
+
+unsafe fn slice_or_empty<'a, T>(ptr: *const T, len: i32) -> &'a [T] {
+    if ptr.is_null() || len <= 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(ptr, len as usize)
+    }
+}
+
+unsafe fn slice_or_empty_mut<'a, T>(ptr: *mut T, len: i32) -> &'a mut [T] {
+    if ptr.is_null() || len <= 0 {
+        &mut []
+    } else {
+        slice::from_raw_parts_mut(ptr, len as usize)
+    }
 }
 
 impl GlyphItem {
@@ -145,6 +197,47 @@ impl FromGlibPtrFull<*const ffi::PangoGlyphInfo> for GlyphInfo {
     }
 }
 
+// rustdoc-stripper-ignore-next
+/// A glyph of a `GlyphString`, laid out like `PangoGlyphInfo` so the glyphs can be borrowed
+/// as a slice with `GlyphString::glyph_entries` and modified in place.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct GlyphEntry(ffi::PangoGlyphInfo);
+
+impl GlyphEntry {
+    pub fn glyph(&self) -> u32 {
+        self.0.glyph
+    }
+
+    pub fn set_glyph(&mut self, glyph: u32) {
+        self.0.glyph = glyph;
+    }
+
+    pub fn geometry(&self) -> &GlyphGeometry {
+        unsafe { &*(&self.0.geometry as *const _ as *const GlyphGeometry) }
+    }
+
+    pub fn geometry_mut(&mut self) -> &mut GlyphGeometry {
+        unsafe { &mut *(&mut self.0.geometry as *mut _ as *mut GlyphGeometry) }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Whether this glyph is the first of its cluster.
+    pub fn is_cluster_start(&self) -> bool {
+        self.0.attr.is_cluster_start & 1 != 0
+    }
+}
+
+impl fmt::Debug for GlyphEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GlyphEntry")
+            .field("glyph", &self.glyph())
+            .field("geometry", self.geometry())
+            .field("is_cluster_start", &self.is_cluster_start())
+            .finish()
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 #[repr(C)]
 pub struct GlyphGeometry(ffi::PangoGlyphGeometry);
@@ -161,6 +254,18 @@ impl GlyphGeometry {
     pub fn y_offset(&self) -> i32 {
         self.0.y_offset
     }
+
+    pub fn set_width(&mut self, width: i32) {
+        self.0.width = width;
+    }
+
+    pub fn set_x_offset(&mut self, x_offset: i32) {
+        self.0.x_offset = x_offset;
+    }
+
+    pub fn set_y_offset(&mut self, y_offset: i32) {
+        self.0.y_offset = y_offset;
+    }
 }
 
 #[doc(hidden)]
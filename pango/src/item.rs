@@ -5,6 +5,7 @@
 use glib::translate::ToGlibPtr;
 
 use crate::Analysis;
+use crate::GlyphString;
 use crate::Item;
 
 impl Item {
@@ -23,4 +24,20 @@ impl Item {
     pub fn analysis(&self) -> &Analysis {
         unsafe { &*(&((*self.to_glib_none().0).analysis) as *const _ as *const Analysis) }
     }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the part of `text` covered by the item, `text` being the string passed to
+    /// `itemize`.
+    pub fn text<'a>(&self, text: &'a str) -> &'a str {
+        let offset = self.offset() as usize;
+        &text[offset..offset + self.length() as usize]
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Shapes the text of the item, `text` being the string passed to `itemize`.
+    pub fn shape(&self, text: &str) -> GlyphString {
+        let mut glyphs = GlyphString::new();
+        crate::shape_full(self.text(text), Some(text), self.analysis(), &mut glyphs);
+        glyphs
+    }
 }
@@ -0,0 +1,58 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::Layout;
+
+// rustdoc-stripper-ignore-next
+/// The position in the text of a layout closest to a point, as returned by
+/// [`Layout::hit_test`](struct.Layout.html#method.hit_test).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HitPosition {
+    // rustdoc-stripper-ignore-next
+    /// Whether the point was inside the text, or was clamped to its closest position.
+    pub is_inside: bool,
+    // rustdoc-stripper-ignore-next
+    /// Byte index of the grapheme under the point.
+    pub index: i32,
+    // rustdoc-stripper-ignore-next
+    /// `0` if the point is on the leading edge of the grapheme, otherwise the number of
+    /// characters in the grapheme.
+    pub trailing: i32,
+}
+
+impl HitPosition {
+    // rustdoc-stripper-ignore-next
+    /// Returns the byte index where a cursor placed at this position goes, which is after the
+    /// grapheme if the point was on its trailing edge.
+    pub fn cursor_index(&self, layout: &Layout) -> i32 {
+        if self.trailing == 0 {
+            return self.index;
+        }
+        let text = match layout.get_text() {
+            Some(text) => text,
+            None => return self.index,
+        };
+        let start = self.index as usize;
+        text.get(start..)
+            .and_then(|rest| rest.char_indices().nth(self.trailing as usize))
+            .map_or(text.len(), |(offset, _)| start + offset) as i32
+    }
+}
+
+impl Layout {
+    // rustdoc-stripper-ignore-next
+    /// Finds the position in the text closest to the point `(x, y)`, in Pango units relative
+    /// to the top-left corner of the layout.
+    ///
+    /// Use `HitPosition::cursor_index` to turn the result into a cursor position, and
+    /// `get_cursor_pos` or `index_to_pos` to get back the rectangle to draw.
+    pub fn hit_test(&self, x: i32, y: i32) -> HitPosition {
+        let (is_inside, index, trailing) = self.xy_to_index(x, y);
+        HitPosition {
+            is_inside,
+            index,
+            trailing,
+        }
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::{HitPosition, Layout, LayoutLine, LayoutRun};
+use glib::translate::*;
+
+impl LayoutLine {
+    pub fn get_layout(&self) -> Option<Layout> {
+        unsafe { from_glib_none((*self.to_glib_none().0).layout) }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Byte index of the start of the line in the text of the layout.
+    pub fn get_start_index(&self) -> i32 {
+        unsafe { (*self.to_glib_none().0).start_index }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Length of the line in bytes.
+    pub fn get_length(&self) -> i32 {
+        unsafe { (*self.to_glib_none().0).length }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the runs of the line in visual order, from left to right.
+    pub fn get_runs(&self) -> Vec<LayoutRun> {
+        unsafe { FromGlibPtrContainer::from_glib_none((*self.to_glib_none().0).runs) }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the ranges covered by the text between `start_index` and `end_index` as
+    /// `(x_start, x_end)` pairs, in Pango units, e.g. to draw a selection.
+    ///
+    /// There can be several ranges when the text is bidirectional.
+    pub fn get_x_range_pairs(&self, start_index: i32, end_index: i32) -> Vec<(i32, i32)> {
+        self.get_x_ranges(start_index, end_index)
+            .chunks_exact(2)
+            .map(|range| (range[0], range[1]))
+            .collect()
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Finds the position in the line closest to `x`, in Pango units relative to the start of
+    /// the line.
+    pub fn hit_test(&self, x: i32) -> HitPosition {
+        let (is_inside, index, trailing) = self.x_to_index(x);
+        HitPosition {
+            is_inside,
+            index,
+            trailing,
+        }
+    }
+}
@@ -41,6 +41,7 @@ pub const SCALE_X_LARGE: f64 = 1.44;
 pub const SCALE_XX_LARGE: f64 = 1.728;
 
 pub mod prelude;
+pub mod subclass;
 
 pub mod analysis;
 pub use crate::analysis::Analysis;
@@ -48,15 +49,25 @@ pub mod attr_class;
 pub use crate::attr_class::AttrClass;
 pub mod attr_iterator;
 pub mod attr_list;
+pub use crate::attr_list::AttrListBuilder;
 pub mod attribute;
+mod color;
+#[cfg(any(feature = "v1_44", feature = "dox"))]
+mod font;
+mod font_map;
+pub use crate::font_map::FontFaces;
 mod functions;
 pub mod item;
 pub mod language;
 pub use crate::language::Language;
+mod layout;
+pub use crate::layout::HitPosition;
+mod layout_line;
+mod matrix;
 pub mod rectangle;
 pub use crate::rectangle::Rectangle;
 pub mod glyph;
-pub use glyph::{GlyphGeometry, GlyphInfo};
+pub use glyph::{GlyphEntry, GlyphGeometry, GlyphInfo};
 
 mod coverage;
 pub use crate::coverage::*;
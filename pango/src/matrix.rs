@@ -0,0 +1,73 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use crate::{Matrix, Rectangle};
+use glib::translate::*;
+
+impl Matrix {
+    // rustdoc-stripper-ignore-next
+    /// Creates the matrix transforming `(x, y)` into
+    /// `(xx * x + xy * y + x0, yx * x + yy * y + y0)`.
+    pub fn new(xx: f64, xy: f64, yx: f64, yy: f64, x0: f64, y0: f64) -> Matrix {
+        let matrix = ffi::PangoMatrix {
+            xx,
+            xy,
+            yx,
+            yy,
+            x0,
+            y0,
+        };
+        unsafe { from_glib_none(&matrix as *const _) }
+    }
+
+    pub fn xx(&self) -> f64 {
+        unsafe { (*self.to_glib_none().0).xx }
+    }
+
+    pub fn xy(&self) -> f64 {
+        unsafe { (*self.to_glib_none().0).xy }
+    }
+
+    pub fn yx(&self) -> f64 {
+        unsafe { (*self.to_glib_none().0).yx }
+    }
+
+    pub fn yy(&self) -> f64 {
+        unsafe { (*self.to_glib_none().0).yy }
+    }
+
+    pub fn x0(&self) -> f64 {
+        unsafe { (*self.to_glib_none().0).x0 }
+    }
+
+    pub fn y0(&self) -> f64 {
+        unsafe { (*self.to_glib_none().0).y0 }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns the bounding box of `rect` once transformed, both in Pango units, e.g. to
+    /// get the extents of rotated text.
+    #[doc(alias = "pango_matrix_transform_rectangle")]
+    pub fn transform_rectangle(&self, rect: &Rectangle) -> Rectangle {
+        let mut rect = *rect;
+        unsafe {
+            ffi::pango_matrix_transform_rectangle(self.to_glib_none().0, rect.to_glib_none_mut().0);
+        }
+        rect
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Like `transform_rectangle`, with `rect` and the result in device units.
+    #[doc(alias = "pango_matrix_transform_pixel_rectangle")]
+    pub fn transform_pixel_rectangle(&self, rect: &Rectangle) -> Rectangle {
+        let mut rect = *rect;
+        unsafe {
+            ffi::pango_matrix_transform_pixel_rectangle(
+                self.to_glib_none().0,
+                rect.to_glib_none_mut().0,
+            );
+        }
+        rect
+    }
+}
@@ -5,5 +5,8 @@
 //! Traits and essential types inteded for blanket imports.
 
 pub use crate::auto::traits::*;
+#[cfg(any(feature = "v1_44", feature = "dox"))]
+pub use crate::font::FontExtManual;
+pub use crate::font_map::FontMapExtManual;
 #[doc(hidden)]
 pub use glib::prelude::*;
@@ -0,0 +1,12 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+mod renderer;
+
+pub use self::prelude::*;
+
+pub mod prelude {
+    pub use super::renderer::{RendererImpl, RendererImplExt};
+    pub use glib::subclass::prelude::*;
+}
@@ -0,0 +1,404 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use glib::subclass::prelude::*;
+use glib::translate::*;
+use glib::Cast;
+
+use crate::{Font, Glyph, GlyphString, RenderPart, Renderer};
+
+// rustdoc-stripper-ignore-next
+/// Implementation of the virtual methods of `Renderer`.
+///
+/// Renderers for a new target usually implement `draw_glyph` and `draw_trapezoid`: the default
+/// implementations of the other drawing methods are based on them. Coordinates passed as `i32`
+/// are in Pango units, the others in device units.
+pub trait RendererImpl: ObjectImpl + RendererImplExt {
+    fn draw_glyphs(
+        &self,
+        renderer: &Self::Type,
+        font: &Font,
+        glyphs: &GlyphString,
+        x: i32,
+        y: i32,
+    ) {
+        self.parent_draw_glyphs(renderer, font, glyphs, x, y)
+    }
+
+    fn draw_rectangle(
+        &self,
+        renderer: &Self::Type,
+        part: RenderPart,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) {
+        self.parent_draw_rectangle(renderer, part, x, y, width, height)
+    }
+
+    fn draw_error_underline(&self, renderer: &Self::Type, x: i32, y: i32, width: i32, height: i32) {
+        self.parent_draw_error_underline(renderer, x, y, width, height)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn draw_trapezoid(
+        &self,
+        renderer: &Self::Type,
+        part: RenderPart,
+        y1: f64,
+        x11: f64,
+        x21: f64,
+        y2: f64,
+        x12: f64,
+        x22: f64,
+    ) {
+        self.parent_draw_trapezoid(renderer, part, y1, x11, x21, y2, x12, x22)
+    }
+
+    fn draw_glyph(&self, renderer: &Self::Type, font: &Font, glyph: Glyph, x: f64, y: f64) {
+        self.parent_draw_glyph(renderer, font, glyph, x, y)
+    }
+
+    fn part_changed(&self, renderer: &Self::Type, part: RenderPart) {
+        self.parent_part_changed(renderer, part)
+    }
+
+    fn begin(&self, renderer: &Self::Type) {
+        self.parent_begin(renderer)
+    }
+
+    fn end(&self, renderer: &Self::Type) {
+        self.parent_end(renderer)
+    }
+}
+
+pub trait RendererImplExt: ObjectSubclass {
+    fn parent_draw_glyphs(
+        &self,
+        renderer: &Self::Type,
+        font: &Font,
+        glyphs: &GlyphString,
+        x: i32,
+        y: i32,
+    );
+    fn parent_draw_rectangle(
+        &self,
+        renderer: &Self::Type,
+        part: RenderPart,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    );
+    fn parent_draw_error_underline(
+        &self,
+        renderer: &Self::Type,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    );
+    #[allow(clippy::too_many_arguments)]
+    fn parent_draw_trapezoid(
+        &self,
+        renderer: &Self::Type,
+        part: RenderPart,
+        y1: f64,
+        x11: f64,
+        x21: f64,
+        y2: f64,
+        x12: f64,
+        x22: f64,
+    );
+    fn parent_draw_glyph(&self, renderer: &Self::Type, font: &Font, glyph: Glyph, x: f64, y: f64);
+    fn parent_part_changed(&self, renderer: &Self::Type, part: RenderPart);
+    fn parent_begin(&self, renderer: &Self::Type);
+    fn parent_end(&self, renderer: &Self::Type);
+}
+
+impl<T: RendererImpl> RendererImplExt for T {
+    fn parent_draw_glyphs(
+        &self,
+        renderer: &Self::Type,
+        font: &Font,
+        glyphs: &GlyphString,
+        x: i32,
+        y: i32,
+    ) {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::PangoRendererClass;
+            if let Some(f) = (*parent_class).draw_glyphs {
+                f(
+                    renderer.unsafe_cast_ref::<Renderer>().to_glib_none().0,
+                    font.to_glib_none().0,
+                    mut_override(glyphs.to_glib_none().0),
+                    x,
+                    y,
+                );
+            }
+        }
+    }
+
+    fn parent_draw_rectangle(
+        &self,
+        renderer: &Self::Type,
+        part: RenderPart,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::PangoRendererClass;
+            if let Some(f) = (*parent_class).draw_rectangle {
+                f(
+                    renderer.unsafe_cast_ref::<Renderer>().to_glib_none().0,
+                    part.to_glib(),
+                    x,
+                    y,
+                    width,
+                    height,
+                );
+            }
+        }
+    }
+
+    fn parent_draw_error_underline(
+        &self,
+        renderer: &Self::Type,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::PangoRendererClass;
+            if let Some(f) = (*parent_class).draw_error_underline {
+                f(
+                    renderer.unsafe_cast_ref::<Renderer>().to_glib_none().0,
+                    x,
+                    y,
+                    width,
+                    height,
+                );
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn parent_draw_trapezoid(
+        &self,
+        renderer: &Self::Type,
+        part: RenderPart,
+        y1: f64,
+        x11: f64,
+        x21: f64,
+        y2: f64,
+        x12: f64,
+        x22: f64,
+    ) {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::PangoRendererClass;
+            if let Some(f) = (*parent_class).draw_trapezoid {
+                f(
+                    renderer.unsafe_cast_ref::<Renderer>().to_glib_none().0,
+                    part.to_glib(),
+                    y1,
+                    x11,
+                    x21,
+                    y2,
+                    x12,
+                    x22,
+                );
+            }
+        }
+    }
+
+    fn parent_draw_glyph(&self, renderer: &Self::Type, font: &Font, glyph: Glyph, x: f64, y: f64) {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::PangoRendererClass;
+            if let Some(f) = (*parent_class).draw_glyph {
+                f(
+                    renderer.unsafe_cast_ref::<Renderer>().to_glib_none().0,
+                    font.to_glib_none().0,
+                    glyph,
+                    x,
+                    y,
+                );
+            }
+        }
+    }
+
+    fn parent_part_changed(&self, renderer: &Self::Type, part: RenderPart) {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::PangoRendererClass;
+            if let Some(f) = (*parent_class).part_changed {
+                f(
+                    renderer.unsafe_cast_ref::<Renderer>().to_glib_none().0,
+                    part.to_glib(),
+                );
+            }
+        }
+    }
+
+    fn parent_begin(&self, renderer: &Self::Type) {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::PangoRendererClass;
+            if let Some(f) = (*parent_class).begin {
+                f(renderer.unsafe_cast_ref::<Renderer>().to_glib_none().0);
+            }
+        }
+    }
+
+    fn parent_end(&self, renderer: &Self::Type) {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().get_parent_class() as *mut ffi::PangoRendererClass;
+            if let Some(f) = (*parent_class).end {
+                f(renderer.unsafe_cast_ref::<Renderer>().to_glib_none().0);
+            }
+        }
+    }
+}
+
+unsafe impl<T: RendererImpl> IsSubclassable<T> for Renderer {
+    fn override_vfuncs(class: &mut glib::Class<Self>) {
+        <glib::Object as IsSubclassable<T>>::override_vfuncs(class);
+
+        let klass = class.as_mut();
+        klass.draw_glyphs = Some(renderer_draw_glyphs::<T>);
+        klass.draw_rectangle = Some(renderer_draw_rectangle::<T>);
+        klass.draw_error_underline = Some(renderer_draw_error_underline::<T>);
+        klass.draw_trapezoid = Some(renderer_draw_trapezoid::<T>);
+        klass.draw_glyph = Some(renderer_draw_glyph::<T>);
+        klass.part_changed = Some(renderer_part_changed::<T>);
+        klass.begin = Some(renderer_begin::<T>);
+        klass.end = Some(renderer_end::<T>);
+    }
+}
+
+unsafe extern "C" fn renderer_draw_glyphs<T: RendererImpl>(
+    ptr: *mut ffi::PangoRenderer,
+    font: *mut ffi::PangoFont,
+    glyphs: *mut ffi::PangoGlyphString,
+    x: i32,
+    y: i32,
+) {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Renderer> = from_glib_borrow(ptr);
+
+    imp.draw_glyphs(
+        wrap.unsafe_cast_ref(),
+        &from_glib_borrow(font),
+        &from_glib_borrow(glyphs),
+        x,
+        y,
+    )
+}
+
+unsafe extern "C" fn renderer_draw_rectangle<T: RendererImpl>(
+    ptr: *mut ffi::PangoRenderer,
+    part: ffi::PangoRenderPart,
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+) {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Renderer> = from_glib_borrow(ptr);
+
+    imp.draw_rectangle(wrap.unsafe_cast_ref(), from_glib(part), x, y, width, height)
+}
+
+unsafe extern "C" fn renderer_draw_error_underline<T: RendererImpl>(
+    ptr: *mut ffi::PangoRenderer,
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+) {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Renderer> = from_glib_borrow(ptr);
+
+    imp.draw_error_underline(wrap.unsafe_cast_ref(), x, y, width, height)
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "C" fn renderer_draw_trapezoid<T: RendererImpl>(
+    ptr: *mut ffi::PangoRenderer,
+    part: ffi::PangoRenderPart,
+    y1: f64,
+    x11: f64,
+    x21: f64,
+    y2: f64,
+    x12: f64,
+    x22: f64,
+) {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Renderer> = from_glib_borrow(ptr);
+
+    imp.draw_trapezoid(
+        wrap.unsafe_cast_ref(),
+        from_glib(part),
+        y1,
+        x11,
+        x21,
+        y2,
+        x12,
+        x22,
+    )
+}
+
+unsafe extern "C" fn renderer_draw_glyph<T: RendererImpl>(
+    ptr: *mut ffi::PangoRenderer,
+    font: *mut ffi::PangoFont,
+    glyph: ffi::PangoGlyph,
+    x: f64,
+    y: f64,
+) {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Renderer> = from_glib_borrow(ptr);
+
+    imp.draw_glyph(wrap.unsafe_cast_ref(), &from_glib_borrow(font), glyph, x, y)
+}
+
+unsafe extern "C" fn renderer_part_changed<T: RendererImpl>(
+    ptr: *mut ffi::PangoRenderer,
+    part: ffi::PangoRenderPart,
+) {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Renderer> = from_glib_borrow(ptr);
+
+    imp.part_changed(wrap.unsafe_cast_ref(), from_glib(part))
+}
+
+unsafe extern "C" fn renderer_begin<T: RendererImpl>(ptr: *mut ffi::PangoRenderer) {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Renderer> = from_glib_borrow(ptr);
+
+    imp.begin(wrap.unsafe_cast_ref())
+}
+
+unsafe extern "C" fn renderer_end<T: RendererImpl>(ptr: *mut ffi::PangoRenderer) {
+    let instance = &*(ptr as *mut T::Instance);
+    let imp = instance.get_impl();
+    let wrap: Borrowed<Renderer> = from_glib_borrow(ptr);
+
+    imp.end(wrap.unsafe_cast_ref())
+}
@@ -0,0 +1,16 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+use glib::translate::*;
+
+// rustdoc-stripper-ignore-next
+/// Returns the font options set with `context_set_font_options`, if any.
+#[doc(alias = "pango_cairo_context_get_font_options")]
+pub fn context_get_font_options(context: &pango::Context) -> Option<cairo::FontOptions> {
+    unsafe {
+        from_glib_none(ffi::pango_cairo_context_get_font_options(
+            context.to_glib_none().0,
+        ))
+    }
+}
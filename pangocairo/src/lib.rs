@@ -13,6 +13,10 @@ mod auto;
 
 pub use crate::auto::functions::*;
 pub use crate::auto::*;
+pub use crate::context::*;
+pub use crate::matrix::*;
 pub mod prelude;
 
+mod context;
 mod font_map;
+mod matrix;
@@ -0,0 +1,24 @@
+// Copyright 2020, The Gtk-rs Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <https://opensource.org/licenses/MIT>
+
+// rustdoc-stripper-ignore-next
+/// Converts a Pango matrix, e.g. the one of a `pango::Context`, to the equivalent cairo matrix.
+pub fn matrix_to_cairo(matrix: &pango::Matrix) -> cairo::Matrix {
+    cairo::Matrix::new(
+        matrix.xx(),
+        matrix.yx(),
+        matrix.xy(),
+        matrix.yy(),
+        matrix.x0(),
+        matrix.y0(),
+    )
+}
+
+// rustdoc-stripper-ignore-next
+/// Converts a cairo matrix, e.g. the one of a `cairo::Context`, to the equivalent Pango matrix.
+pub fn matrix_from_cairo(matrix: &cairo::Matrix) -> pango::Matrix {
+    pango::Matrix::new(
+        matrix.xx, matrix.xy, matrix.yx, matrix.yy, matrix.x0, matrix.y0,
+    )
+}